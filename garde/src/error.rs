@@ -8,10 +8,10 @@
 //! An important highlight is the [`Errors::flatten`] function, which may be used to print readable errors.
 
 use std::borrow::Cow;
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashMap};
 
 /// This type encapsulates a single validation error.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Error {
     /// The error message.
     ///
@@ -20,6 +20,8 @@ pub struct Error {
     /// field_name: {message}
     /// ```
     pub message: Cow<'static, str>,
+    /// A stable, machine-readable identifier of the error, such as `length.min`.
+    code: Option<&'static str>,
 }
 
 impl Error {
@@ -27,8 +29,28 @@ impl Error {
     pub fn new(message: impl Into<Cow<'static, str>>) -> Self {
         Self {
             message: message.into(),
+            code: None,
         }
     }
+
+    /// Create an error from an error message, along with a machine-readable `code`.
+    ///
+    /// Unlike the message, the code is not meant to be displayed, and should only change
+    /// if the meaning of the error changes. This makes it suitable for e.g. translating errors.
+    pub fn with_code(code: &'static str, message: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            message: message.into(),
+            code: Some(code),
+        }
+    }
+
+    /// The machine-readable code of the error, if any.
+    ///
+    /// All built-in rules set a code, which is the name of the rule, e.g. `email`.
+    /// Rules which may fail in more than one way add a suffix, e.g. `length.min` and `length.max`.
+    pub fn code(&self) -> Option<&'static str> {
+        self.code
+    }
 }
 
 impl std::fmt::Display for Error {
@@ -39,6 +61,14 @@ impl std::fmt::Display for Error {
 
 impl std::error::Error for Error {}
 
+/// Serializes the error as its message.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Error {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.message)
+    }
+}
+
 /// This type encapsulates a set of (potentially nested) validation errors.
 #[derive(Clone, Debug)]
 pub enum Errors {
@@ -56,8 +86,12 @@ pub enum Errors {
     List(Vec<Errors>),
     /// A map of field names to errors.
     ///
-    /// For example, `#[garde(dive)]` on a `HashMap<T: Validate>` field.
+    /// For example, the fields of a struct which derives `Validate`.
     Fields(BTreeMap<Cow<'static, str>, Errors>),
+    /// A map of keys to errors, where each key is the `Display` form of a key of a map.
+    ///
+    /// For example, `#[garde(dive)]` or `#[garde(keys(...))]` on a `HashMap<K, V: Validate>` field.
+    Map(BTreeMap<String, Errors>),
 }
 
 impl From<Result<(), Errors>> for Errors {
@@ -86,14 +120,16 @@ impl Errors {
 
     /// If the error is empty, returns true.
     ///
-    /// - For [`Errors::Simple`] and [`Errors::List`] the inner list must be empty.
-    /// - For [`Errors::Fields`] the inner map must be empty.
+    /// - For [`Errors::Simple`] the inner list must be empty.
+    /// - For [`Errors::List`] every item in the inner list must be empty.
+    /// - For [`Errors::Fields`] and [`Errors::Map`] the inner map must be empty.
     /// - For [`Errors::Nested`] both the list of errors *and* the nested error must be empty.
     pub fn is_empty(&self) -> bool {
         match self {
             Errors::Simple(v) => v.is_empty(),
-            Errors::List(v) => v.is_empty(),
+            Errors::List(v) => v.iter().all(Errors::is_empty),
             Errors::Fields(v) => v.is_empty(),
+            Errors::Map(v) => v.is_empty(),
             Errors::Nested(outer, inner) => outer.is_empty() && inner.is_empty(),
         }
     }
@@ -103,6 +139,7 @@ impl Errors {
     /// `path` is generated by appending a suffix to the path each time the traversal function recurses:
     /// - For [`Errors::List`], it appends `[{index}]` to each item.
     /// - For [`Errors::Fields`], it appends `.{key}` for each key-value pair.
+    /// - For [`Errors::Map`], it appends `[{key}]` for each key-value pair.
     /// - For [`Errors::Nested`], it does not append anything.
     /// - For [`Errors::Simple`], it does not append anything.
     ///
@@ -130,17 +167,45 @@ impl Errors {
     /// value.a[0]: not alphanumeric
     /// value.b.c: not a valid url
     /// ```
+    ///
+    /// The order is deterministic: fields and map entries are yielded in the order of their keys, list items in the order of their indices,
+    /// and errors at the same path in the order in which they were reported. Note that the indices of unordered collections
+    /// such as `HashSet` follow their iteration order.
     pub fn flatten(&self) -> Vec<(String, Error)> {
-        fn flatten_inner(out: &mut Vec<(String, Error)>, current_path: String, errors: &Errors) {
+        self.iter()
+            .map(|(path, error)| (path, error.clone()))
+            .collect()
+    }
+
+    /// Returns the same `(path, error)` pairs as [`Errors::flatten`], sorted by their path as a string.
+    ///
+    /// The sort is stable, so errors at the same path stay in the order in which they were reported.
+    /// This only differs from [`Errors::flatten`] where the structural order is not the order of the strings,
+    /// e.g. `value[10]` is placed before `value[2]`, and `value.a.b` before `value.a[0]`.
+    pub fn sort_by_path(&self) -> Vec<(String, Error)> {
+        let mut errors = self.flatten();
+        errors.sort_by(|(a, _), (b, _)| a.cmp(b));
+        errors
+    }
+
+    /// Returns an iterator over `(path, error)`, without cloning the errors.
+    ///
+    /// The paths and their order are the same as those of [`Errors::flatten`].
+    pub fn iter(&self) -> impl Iterator<Item = (String, &Error)> {
+        fn flatten_inner<'a>(
+            out: &mut Vec<(String, &'a Error)>,
+            current_path: String,
+            errors: &'a Errors,
+        ) {
             match errors {
                 Errors::Simple(errors) => {
                     for error in errors {
-                        out.push((current_path.clone(), error.clone()));
+                        out.push((current_path.clone(), error));
                     }
                 }
                 Errors::Nested(outer, inner) => {
                     for error in outer {
-                        out.push((current_path.clone(), error.clone()));
+                        out.push((current_path.clone(), error));
                     }
                     flatten_inner(out, current_path, inner);
                 }
@@ -154,12 +219,52 @@ impl Errors {
                         flatten_inner(out, format!("{current_path}.{key}"), errors);
                     }
                 }
+                Errors::Map(errors) => {
+                    for (key, errors) in errors.iter() {
+                        flatten_inner(out, format!("{current_path}[{key}]"), errors);
+                    }
+                }
             }
         }
 
         let mut errors = vec![];
         flatten_inner(&mut errors, "value".to_string(), self);
-        errors
+        errors.into_iter()
+    }
+
+    /// Returns the number of errors, which is the number of items yielded by [`Errors::iter`].
+    ///
+    /// Unlike [`Errors::iter`], this does not build any paths.
+    pub fn len(&self) -> usize {
+        match self {
+            Errors::Simple(v) => v.len(),
+            Errors::Nested(outer, inner) => outer.len() + inner.len(),
+            Errors::List(v) => v.iter().map(Errors::len).sum(),
+            Errors::Fields(v) => v.values().map(Errors::len).sum(),
+            Errors::Map(v) => v.values().map(Errors::len).sum(),
+        }
+    }
+
+    /// Flattens the error into a map of paths to messages.
+    ///
+    /// The paths are the same as those produced by [`Errors::flatten`].
+    /// If there are multiple errors for a path, their messages are in the order in which [`Errors::flatten`] yields them.
+    ///
+    /// For the example in [`Errors::flatten`], this would produce:
+    /// ```text,ignore
+    /// {
+    ///     "value.a[0]": ["length is lower than 15", "not alphanumeric"],
+    ///     "value.b.c": ["not a valid url"]
+    /// }
+    /// ```
+    pub fn to_field_map(&self) -> HashMap<String, Vec<String>> {
+        let mut map = HashMap::<String, Vec<String>>::new();
+        for (path, error) in self.flatten() {
+            map.entry(path)
+                .or_default()
+                .push(error.message.into_owned());
+        }
+        map
     }
 
     /// Creates an empty list of errors.
@@ -188,6 +293,172 @@ impl Errors {
         Errors::Nested(builder.inner, inner)
     }
 
+    /// Combines two sets of errors for the same value.
+    ///
+    /// At every path, the errors of `self` are placed before those of `other`, and duplicates are kept.
+    /// Lists are merged by index, and fields and maps by key.
+    ///
+    /// As with any [`Errors`], [`Errors::flatten`] yields the errors of a value before those nested under it,
+    /// so e.g. an error of `other` at `value` is still yielded before an error of `self` at `value.a`.
+    ///
+    /// If the nested errors of `self` and `other` are of different kinds, such as a list and the fields of a struct,
+    /// both are kept as a map: list items are keyed by their index, so their paths are unchanged, while struct fields
+    /// are keyed by their name, so e.g. an error at `value.a` is then reported at `value[a]`.
+    pub fn merge(self, other: Errors) -> Errors {
+        if self.is_empty() {
+            return other;
+        }
+        if other.is_empty() {
+            return self;
+        }
+        let (mut outer, inner) = self.split_outer();
+        let (other_outer, other_inner) = other.split_outer();
+        outer.extend(other_outer);
+        let inner = match (inner, other_inner) {
+            (Some(inner), Some(other)) => Some(inner.merge_inner(other)),
+            (inner, None) => inner,
+            (None, other) => other,
+        };
+        match inner {
+            Some(inner) => Errors::Nested(outer, Box::new(inner)),
+            None => Errors::Simple(outer),
+        }
+    }
+
+    /// Splits `self` into the errors of the value itself, and the errors nested under it, if any.
+    fn split_outer(self) -> (Vec<Error>, Option<Errors>) {
+        match self {
+            Errors::Simple(outer) => (outer, None),
+            Errors::Nested(mut outer, inner) => {
+                let (inner_outer, inner) = inner.split_outer();
+                outer.extend(inner_outer);
+                (outer, inner)
+            }
+            // e.g. a list whose items are all empty
+            inner if inner.is_empty() => (Vec::new(), None),
+            inner => (Vec::new(), Some(inner)),
+        }
+    }
+
+    /// Merges two sets of nested errors, neither of which is [`Errors::Simple`] or [`Errors::Nested`].
+    fn merge_inner(self, other: Errors) -> Errors {
+        fn merge_keys<K: Ord>(
+            mut a: BTreeMap<K, Errors>,
+            b: BTreeMap<K, Errors>,
+        ) -> BTreeMap<K, Errors> {
+            for (key, b) in b {
+                let merged = match a.remove(&key) {
+                    Some(a) => a.merge(b),
+                    None => b,
+                };
+                a.insert(key, merged);
+            }
+            a
+        }
+
+        match (self, other) {
+            (Errors::List(a), Errors::List(b)) => {
+                let mut b = b.into_iter();
+                let mut list = a
+                    .into_iter()
+                    .map(|a| match b.next() {
+                        Some(b) => a.merge(b),
+                        None => a,
+                    })
+                    .collect::<Vec<_>>();
+                list.extend(b);
+                Errors::List(list)
+            }
+            (Errors::Fields(a), Errors::Fields(b)) => Errors::Fields(merge_keys(a, b)),
+            (Errors::Map(a), Errors::Map(b)) => Errors::Map(merge_keys(a, b)),
+            (a, b) => Errors::Map(merge_keys(a.into_map(), b.into_map())),
+        }
+    }
+
+    /// Converts nested errors which are not [`Errors::Simple`] or [`Errors::Nested`] into the entries of a map.
+    fn into_map(self) -> BTreeMap<String, Errors> {
+        match self {
+            Errors::List(list) => list
+                .into_iter()
+                .enumerate()
+                .filter(|(_, errors)| !errors.is_empty())
+                .map(|(index, errors)| (index.to_string(), errors))
+                .collect(),
+            Errors::Fields(fields) => fields
+                .into_iter()
+                .map(|(key, errors)| (key.into_owned(), errors))
+                .collect(),
+            Errors::Map(map) => map,
+            errors @ (Errors::Simple(_) | Errors::Nested(..)) => {
+                unreachable!("{errors:?} is not nested")
+            }
+        }
+    }
+
+    /// Combines `self` with `other` nested under `field`, as if `other` was reported for a field of the value.
+    ///
+    /// This is useful when combining the results of separate `validate` calls into one set of errors,
+    /// e.g. `errors.merge_at("billing", billing.validate(&()).into())`, where an error at `value.street` in `other`
+    /// is reported at `value.billing.street`.
+    ///
+    /// This does not place `other` after the existing errors: like all fields, `field` is ordered by name
+    /// in [`Errors::flatten`], so `errors.merge_at("b", ..).merge_at("a", ..)` yields the errors at `value.a` first.
+    /// Only errors at the same path are kept in the order they were merged, as described in [`Errors::merge`].
+    ///
+    /// This never fails. If `self` holds the errors of a list or map, such as the result of validating a `Vec`,
+    /// both are kept as described in [`Errors::merge`], and `other` is reported at `value[field]` instead.
+    pub fn merge_at(self, field: impl Into<Cow<'static, str>>, other: Errors) -> Errors {
+        if other.is_empty() {
+            return self;
+        }
+        let mut fields = BTreeMap::new();
+        fields.insert(field.into(), other);
+        self.merge(Errors::Fields(fields))
+    }
+
+    /// Removes errors which are identical to an earlier error at the same path.
+    ///
+    /// Two errors are identical if both their message and code are equal. This is useful after [`Errors::merge`]
+    /// when the same rule may have been checked more than once. The order of the remaining errors is unchanged.
+    pub fn dedup(&mut self) {
+        fn dedup_inner(errors: &mut Errors, seen: &mut Vec<Error>) {
+            fn retain(errors: &mut Vec<Error>, seen: &mut Vec<Error>) {
+                errors.retain(|error| {
+                    if seen.contains(error) {
+                        return false;
+                    }
+                    seen.push(error.clone());
+                    true
+                });
+            }
+
+            match errors {
+                Errors::Simple(errors) => retain(errors, seen),
+                Errors::Nested(outer, inner) => {
+                    retain(outer, seen);
+                    dedup_inner(inner, seen);
+                }
+                Errors::List(errors) => {
+                    for errors in errors {
+                        dedup_inner(errors, &mut Vec::new());
+                    }
+                }
+                Errors::Fields(errors) => {
+                    for errors in errors.values_mut() {
+                        dedup_inner(errors, &mut Vec::new());
+                    }
+                }
+                Errors::Map(errors) => {
+                    for errors in errors.values_mut() {
+                        dedup_inner(errors, &mut Vec::new());
+                    }
+                }
+            }
+        }
+
+        dedup_inner(self, &mut Vec::new());
+    }
+
     /// Creates a list of [`Errors`] constructed via `f`.
     pub fn list<F>(f: F) -> Errors
     where
@@ -203,6 +474,25 @@ impl Errors {
     {
         FieldsErrorBuilder::dive(f)
     }
+
+    /// Creates a map of keys to [`Errors`] constructed via `f`.
+    pub fn map<F>(f: F) -> Errors
+    where
+        F: FnMut(&mut MapErrorBuilder),
+    {
+        MapErrorBuilder::dive(f)
+    }
+}
+
+/// Nests a `path` produced by [`Errors::flatten`] under `prefix`.
+///
+/// Paths produced by [`Errors::flatten`] are rooted at `value`, so `nest_path("value.a", "value[0]")` is `value.a[0]`.
+#[doc(hidden)]
+pub fn nest_path(prefix: &str, path: &str) -> String {
+    match path.strip_prefix("value") {
+        Some(rest) => format!("{prefix}{rest}"),
+        None => format!("{prefix}.{path}"),
+    }
 }
 
 // TODO: remove rename, change rules to not require field_name
@@ -243,13 +533,8 @@ impl ListErrorBuilder {
     }
 
     pub fn push(&mut self, entry: impl Into<Errors>) {
-        let entry = entry.into();
-
-        if entry.is_empty() {
-            return;
-        }
-
-        self.inner.push(entry);
+        // empty entries are kept, so that each entry stays at the index of its item
+        self.inner.push(entry.into());
     }
 }
 
@@ -285,6 +570,40 @@ impl FieldsErrorBuilder {
     }
 }
 
+#[doc(hidden)]
+pub struct MapErrorBuilder {
+    inner: BTreeMap<String, Errors>,
+}
+
+impl MapErrorBuilder {
+    fn dive<F>(mut f: F) -> Errors
+    where
+        F: FnMut(&mut MapErrorBuilder),
+    {
+        let mut builder = MapErrorBuilder {
+            inner: BTreeMap::new(),
+        };
+        f(&mut builder);
+        Errors::Map(builder.inner)
+    }
+
+    pub fn insert(&mut self, key: impl std::fmt::Display, entry: impl Into<Errors>) {
+        let entry = entry.into();
+
+        if entry.is_empty() {
+            return;
+        }
+
+        // distinct keys may have the same `Display` form, in which case their errors are combined
+        let key = key.to_string();
+        let entry = match self.inner.remove(&key) {
+            Some(existing) => existing.merge(entry),
+            None => entry,
+        };
+        self.inner.insert(key, entry);
+    }
+}
+
 impl std::fmt::Display for Errors {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let errors = self.flatten();
@@ -300,3 +619,33 @@ impl std::fmt::Display for Errors {
 }
 
 impl std::error::Error for Errors {}
+
+/// Serializes the error as a map of paths to lists of error messages.
+///
+/// The paths are the same as the ones produced by [`Errors::flatten`], and appear in the same order:
+/// ```text,ignore
+/// {
+///     "value.a[0]": ["length is lower than 15", "not alphanumeric"],
+///     "value.b.c": ["not a valid url"]
+/// }
+/// ```
+#[cfg(feature = "serde")]
+impl serde::Serialize for Errors {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeMap as _;
+
+        let mut entries: Vec<(String, Vec<Error>)> = Vec::new();
+        for (path, error) in self.flatten() {
+            match entries.iter_mut().find(|(p, _)| *p == path) {
+                Some((_, errors)) => errors.push(error),
+                None => entries.push((path, vec![error])),
+            }
+        }
+
+        let mut map = serializer.serialize_map(Some(entries.len()))?;
+        for (path, errors) in &entries {
+            map.serialize_entry(path, errors)?;
+        }
+        map.end()
+    }
+}
@@ -62,35 +62,104 @@
 //!
 //! ### Available validation rules
 //!
-//! | name         | format                                           | validation                                           | feature flag   |
-//! |--------------|--------------------------------------------------|------------------------------------------------------|----------------|
-//! | ascii        | `#[garde(ascii)]`                                | only contains ASCII                                  | -              |
-//! | alphanumeric | `#[garde(alphanumeric)]`                         | only letters and digits                              | -              |
-//! | email        | `#[garde(email)]`                                | an email according to the HTML5 spec[^1]             | `email`        |
-//! | url          | `#[garde(url)]`                                  | a URL                                                | `url`          |
-//! | ip           | `#[garde(ip)]`                                   | an IP address (either IPv4 or IPv6)                  | -              |
-//! | ipv4         | `#[garde(ipv4)]`                                 | an IPv4 address                                      | -              |
-//! | ipv6         | `#[garde(ipv6)]`                                 | an IPv6 address                                      | -              |
-//! | credit card  | `#[garde(credit_card)]`                          | a credit card number                                 | `credit-card`  |
-//! | phone number | `#[garde(phone_number)]`                         | a phone number                                       | `phone-number` |
-//! | length       | `#[garde(length(min=<usize>, max=<usize>)]`      | a container with length in `min..=max`               | -              |
-//! | byte_length  | `#[garde(byte_length(min=<usize>, max=<usize>)]` | a byte sequence with length in `min..=max`           | -              |
-//! | range        | `#[garde(range(min=<expr>, max=<expr>))]`        | a number in the range `min..=max`                    | -              |
-//! | contains     | `#[garde(contains(<string>))]`                   | a string-like value containing a substring           | -              |
-//! | prefix       | `#[garde(prefix(<string>))]`                     | a string-like value prefixed by some string          | -              |
-//! | suffix       | `#[garde(suffix(<string>))]`                     | a string-like value suffixed by some string          | -              |
-//! | pattern      | `#[garde(pattern(<regex>))]`                     | a string-like value matching some regular expression | `pattern`      |
-//! | dive         | `#[garde(dive)]`                                 | nested validation, calls `validate` on the value     | -              |
-//! | skip         | `#[garde(skip)]`                                 | skip validation                                      | -              |
-//! | custom       | `#[garde(custom(<function or closure>))]`        | a custom validator                                   | -              |
+//! | name          | format                                           | validation                                           | feature flag   |
+//! |---------------|--------------------------------------------------|------------------------------------------------------|----------------|
+//! | required      | `#[garde(required)]`                             | an `Option` which is `Some`                          | -              |
+//! | ascii         | `#[garde(ascii)]`                                | only contains ASCII                                  | -              |
+//! | alphanumeric  | `#[garde(alphanumeric)]`                         | only letters and digits                              | -              |
+//! | trimmed       | `#[garde(trimmed)]`                              | no leading or trailing whitespace                    | -              |
+//! | non_control   | `#[garde(non_control)]`                          | no control characters                                | -              |
+//! | lowercase     | `#[garde(lowercase)]`                            | only lowercase letters                               | -              |
+//! | uppercase     | `#[garde(uppercase)]`                            | only uppercase letters                               | -              |
+//! | email         | `#[garde(email)]`                                | an email according to the HTML5 spec[^1]             | `email`        |
+//! | url           | `#[garde(url)]`                                  | a URL                                                | `url`          |
+//! | ip            | `#[garde(ip)]`                                   | an IP address (either IPv4 or IPv6)                  | -              |
+//! | ipv4          | `#[garde(ipv4)]`                                 | an IPv4 address                                      | -              |
+//! | ipv6          | `#[garde(ipv6)]`                                 | an IPv6 address                                      | -              |
+//! | port          | `#[garde(port)]`                                 | a network port in `1..=65535`                        | -              |
+//! | credit card   | `#[garde(credit_card)]`                          | a credit card number                                 | `credit-card`  |
+//! | luhn          | `#[garde(luhn)]`                                 | a number passing the Luhn checksum                   | -              |
+//! | iban          | `#[garde(iban)]`                                 | an IBAN                                              | `iban`         |
+//! | isbn          | `#[garde(isbn)]`                                 | an ISBN-10 or ISBN-13                                | -              |
+//! | json          | `#[garde(json)]`                                 | a string containing valid JSON                       | `json`         |
+//! | uuid          | `#[garde(uuid)]`                                 | a UUID, optionally of a specific version             | `uuid`         |
+//! | semver        | `#[garde(semver)]`                               | a semantic version, or a version requirement         | `semver`       |
+//! | base64        | `#[garde(base64)]`                               | a base64-encoded string                              | `base64`       |
+//! | hex_color     | `#[garde(hex_color)]`                            | a hex color such as `#RGB` or `#RRGGBB`              | -              |
+//! | phone number  | `#[garde(phone_number)]`                         | a phone number                                       | `phone-number` |
+//! | date          | `#[garde(date)]`                                 | a date in the `%Y-%m-%d` format                      | `chrono`       |
+//! | datetime      | `#[garde(datetime)]`                             | an RFC 3339 date and time                            | `chrono`       |
+//! | length        | `#[garde(length(min=<usize>, max=<usize>)]`      | a container with length in `min..=max`               | -              |
+//! | byte_length   | `#[garde(byte_length(min=<usize>, max=<usize>)]` | a byte sequence with length in `min..=max`           | -              |
+//! | length        | `#[garde(length(graphemes, min=.., max=..)]`     | a string with grapheme count in `min..=max`          | `graphemes`    |
+//! | range         | `#[garde(range(min=<expr>, max=<expr>))]`        | a number in the range `min..=max`                    | -              |
+//! | multiple_of   | `#[garde(multiple_of(<expr>))]`                  | an integer divisible by some number                  | -              |
+//! | greater_than  | `#[garde(greater_than(<field>))]`                | a value greater than another field                   | -              |
+//! | less_than     | `#[garde(less_than(<field>))]`                   | a value lower than another field                     | -              |
+//! | one_of        | `#[garde(one_of(<string or integer>...))]`       | a string or integer equal to one of the given values | -              |
+//! | contains      | `#[garde(contains(<string>))]`                   | a string-like value containing a substring           | -              |
+//! | not_contains  | `#[garde(not_contains(<string>...))]`            | a string-like value without any of the substrings    | -              |
+//! | prefix        | `#[garde(prefix(<string>))]`                     | a string-like value prefixed by some string          | -              |
+//! | suffix        | `#[garde(suffix(<string>))]`                     | a string-like value suffixed by some string          | -              |
+//! | pattern       | `#[garde(pattern(<regex>))]`                     | a string-like value matching some regular expression | `pattern`      |
+//! | dive          | `#[garde(dive)]`                                 | nested validation, calls `validate` on the value     | -              |
+//! | keys          | `#[garde(keys(<rules>))]`                        | a map whose keys pass the given rules                | -              |
+//! | inner         | `#[garde(inner(<rules>))]`                       | a container whose items pass the given rules         | -              |
+//! | split         | `#[garde(split(<string>, inner(<rules>)))]`      | a delimited string whose segments pass the rules     | -              |
+//! | skip          | `#[garde(skip)]`                                 | skip validation                                      | -              |
+//! | skip_if       | `#[garde(skip_if(<function or closure>))]`       | skip validation if the function returns `true`       | -              |
+//! | unless        | `#[garde(skip, unless = <function or closure>)]` | validate a skipped field if the function is `true`   | -              |
+//! | when          | `#[garde(when = <field>)]`                       | validate only if a boolean field is `true`           | -              |
+//! | custom        | `#[garde(custom(<function or closure>))]`        | a custom validator                                   | -              |
+//! | custom_async  | `#[garde(custom_async(<function or closure>))]`  | an asynchronous custom validator[^2]                 | `async`        |
+//! | custom_errors | `#[garde(custom_errors(<function or closure>))]` | a custom validator which returns nested errors       | -              |
 //!
 //!
 //! Additional notes:
 //! - For `length` and `range`, either `min` or `max` may be omitted, but not both.
 //! - `length` and `range` use an *inclusive* upper bound (`min..=max`).
+//! - `length` and `byte_length` also accept `equal` in place of `min` and `max`, e.g. `length(equal = 6)`, which requires the length to be exactly `6`.
+//! - `range` also accepts `exclusive_min` and `exclusive_max`, which may be used in place of `min` and `max` respectively, e.g. `range(min=0.0, exclusive_max=1.0)`.
+//! - `range` works with any type implementing `PartialOrd` and `Display`, such as `rust_decimal::Decimal`, as long as the bounds have the same type as the value.
+//! - `ascii(bytes)` validates byte containers such as `Vec<u8>` and `&[u8]` instead of strings, in which case the error reports the index of the first non-ASCII byte.
 //! - `length` uses `.chars().count()` for UTF-8 strings instead of `.len()`.
+//! - The unit of `length` may be given explicitly as `length(chars, ...)`, which is the default, or `length(bytes, ...)`, which is the same rule as `byte_length(...)` and produces the same errors.
+//! - `length(graphemes, ...)` counts extended grapheme clusters instead, so that e.g. an emoji made of multiple characters is counted once.
 //! - For `contains`, `prefix`, and `suffix`, the pattern must be a string literal, because the `Pattern` API [is currently unstable](https://github.com/rust-lang/rust/issues/27721).
+//! - `prefix` and `suffix` accept several alternatives, e.g. `prefix("tel:", "sms:")`, in which case the value must match at least one of them.
+//! - `pattern` also accepts the path to a function returning a compiled regex, e.g. `pattern(username_regex)` with `fn username_regex() -> &'static Regex`, for regexes which are built at runtime.
+//! - `one_of` accepts either string literals or integer literals, e.g. `one_of("draft", "published")` or `one_of(1, 2, 3)`, and reports the allowed values in its error. Strings may be compared case-insensitively via `one_of("draft", "published", insensitive)`.
+//! - `contains` may be made case-insensitive via `contains("foo", insensitive)`. Both the value and the pattern are lowercased, which works for all of Unicode.
+//! - `not_contains` rejects values containing any of the given substrings, e.g. `not_contains("admin", "root")`, and reports the first one found. Like `contains`, it may be made case-insensitive via `not_contains("admin", insensitive)`.
+//! - `non_control` rejects any character for which `char::is_control` is true, and reports the code point of the first one. Whitespace control characters such as `\n` and `\t` may be allowed via `non_control(allow_whitespace)`.
+//! - `url` may be restricted to specific schemes via `url(schemes("https"))`.
+//! - `url` may be restricted to specific hosts via `url(host("example.com"))`. Hosts are compared case-insensitively and must match exactly, and the list may not be empty.
+//! - `credit_card` may be restricted to specific card brands via `credit_card(types("visa", "mastercard"))`.
+//! - `phone_number` may be restricted to a specific region via `phone_number(region = "FR")`.
+//! - `isbn` may be restricted to ISBN-10 or ISBN-13 via `isbn(version = 13)`.
+//! - `uuid` may be restricted to a specific version via `uuid(version = 4)`.
+//! - `semver` requires an exact version such as `1.2.3`, so `1.2` and `v1.0.0` are rejected. A version requirement such as `>=1.2, <2` may be validated via `semver(req)` instead.
+//! - `required` only checks that an `Option` is `Some`. Empty values such as `Some("")`, or an empty `String`, `Vec`, or map, may also be rejected via `required(non_empty)`. The message may be customized via `required(message = "...")`, which only applies to this rule, unlike `message(...)`. A missing value is reported before the errors of any other rule.
+//! - `alphanumeric` accepts letters and digits from any script by default. Only ASCII letters and digits may be accepted via `alphanumeric(ascii)`.
+//! - `ip`, `ipv4`, and `ipv6` accept any address which parses by default. The address may be restricted via `ip(public)`, `ip(private)`, or `ip(loopback)`, e.g. `#[garde(ipv4(public))]`, in which case private, loopback, link-local, documentation, and other special-purpose addresses are not public.
+//! - `base64` uses the standard alphabet with padding by default. The URL-safe alphabet, with optional padding, may be selected via `base64(url_safe)`.
+//! - `port` accepts integers and strings of ASCII digits. Ports below 1024 may be rejected via `port(privileged = false)`.
+//! - `hex_color` accepts `#` followed by 3, 4, 6, or 8 hex digits, in any case. The variants with an alpha channel, `#RGBA` and `#RRGGBBAA`, may be rejected via `hex_color(alpha = false)`.
+//! - `date` and `datetime` may be given an explicit [`chrono` format](https://docs.rs/chrono/latest/chrono/format/strftime/index.html) via `date(format = "%d.%m.%Y")` or `datetime(format = "%Y/%m/%d %H:%M")`.
+//! - `when` names a sibling `bool` field, or a field nested within one, e.g. `when = options.enabled`. The field is validated only if that field is `true`, and otherwise produces no errors.
+//! - A field may be given a different name in error paths via `rename = "..."`, e.g. `#[garde(dive, rename = "homeAddress")]` on `home_address` reports `value.homeAddress.street`. This is useful to match serde's `rename`, which garde does not read. `rename("...")` is accepted as well. Errors are ordered by the new name.
+//! - The errors produced by a field's rules may be given a custom message via `message("...")`, e.g. `#[garde(length(min=3), message("name too short"))]`. The field then reports this message once, even if several of its rules fail, with the code of the first rule which failed. This does not affect errors produced by `dive`.
+//! - Errors produced by built-in rules carry a machine-readable code, available via `Error::code`, such as `email` or `length.min`. A custom message does not change the code. Custom rules may set a code via `Error::with_code("password.weak", "...")`.
+//! - The function passed to `custom_errors` returns `Result<(), garde::Errors>` instead of `garde::Result`, so that it may report errors at paths nested under the field, e.g. `value.ranges[1].end`. They are merged with the errors of the field's other rules, including `dive`, via `Errors::merge`, which never fails: if e.g. `dive` reports a list and `custom_errors` the fields of a struct, both are kept, with the fields reported as `value.field[name]`. `message(...)` does not apply to them.
 //! - Nested validation using `dive` may not be combined with any other rule.
+//! - `greater_than` and `less_than` compare the field to a sibling field of the same type, e.g. `#[garde(greater_than(start_date))]`. The sibling may be skipped. If either of them is `None`, the comparison is skipped. Errors refer to the sibling by its `rename` alias, if it has one.
+//! - The function passed to `skip_if` receives the context, e.g. `fn is_update(ctx: &Context) -> bool`. If it returns `true`, all rules on the field are skipped, including `dive`.
+//! - `unless` may only be combined with `skip`, e.g. `#[garde(skip, unless = is_strict, length(min = 8))]`. The function receives the context like `skip_if`, and the field's other rules only run if it returns `true`. Without `unless`, `skip` may not be combined with other rules.
+//! - `keys` may be combined with `dive` to validate both the keys and the values of a map. Errors of a map entry are reported at the path of its key, e.g. `value.ids[42]`, with the errors of the key before those of the value.
+//! - `inner` applies its rules to each item of a container such as `Vec<T>`, with errors reported at each item's index. Any rule supported by the item type may be used, e.g. `#[garde(inner(range(max = 100)))]` on a `Vec<u8>`. `Option<T>` is treated as a container with zero or one items, so `#[garde(inner(length(min = 1)))]` validates an `Option<String>` only if it is `Some`, with errors reported at the field itself. Likewise, `dive` on an `Option<T>` only validates the value if it is `Some`. `inner` may not be combined with `dive`.
+//! - `split` splits a string on a delimiter and applies the rules in `inner(...)` to each segment, with errors reported at each segment's index, e.g. `#[garde(split(",", inner(length(max = 20))))]` reports `value.tags[1]`. Empty segments are validated like the others by default, and may be skipped via `split(",", skip_empty, inner(...))` or rejected via `split(",", deny_empty, inner(...))`. `split` may not be combined with `dive` or `inner`.
+//! - With the `rayon` feature, `dive(par)` validates the items of a slice-like field, such as a `Vec<T>`, in parallel if there are more than 1024 of them. Errors are still reported in index order. The items and the context must be `Sync`. `par` may only be used with `#[derive(Validate)]`, as `AsyncValidate` and `ValidateMut` always validate the items one after another.
+//! - Tuples of up to 12 elements may be validated via `dive` if all of their elements use the same context. Errors are reported at the index of each element, e.g. `value.pair[1]`.
 //!
 //! ### Custom validation
 //!
@@ -129,6 +198,95 @@
 //! The validator function may accept the value as a reference to any type which it derefs to.
 //! In the above example, it is possible to use `&str`, because `password` is a `String`, and `String` derefs to `&str`.
 //!
+//! Checks which involve more than one field may be attached to the struct or enum itself via `#[garde(validate = check_fn)]`.
+//! The function is called as `check_fn(&value, &context)` and returns a `garde::Result`, in the same way as a `custom` validator.
+//! It runs after the field rules, and its error is reported at the root of the value, before any field errors.
+//! The attribute may be repeated, in which case every function is called in order.
+//!
+//! ```rust,ignore
+//! #[derive(garde::Validate)]
+//! #[garde(validate = check_range)]
+//! struct Range {
+//!     #[garde(range(max = 100))]
+//!     start: u32,
+//!     #[garde(range(max = 100))]
+//!     end: u32,
+//! }
+//!
+//! fn check_range(value: &Range, _: &()) -> garde::Result {
+//!     if value.start > value.end {
+//!         return Err(garde::Error::new("`start` is after `end`"));
+//!     }
+//!     Ok(())
+//! }
+//! ```
+//!
+//! If the context implements `Default`, the value may also be validated via `garde::ValidateDefault::validate_default`, which creates the context on demand.
+//! For other contexts, a function which creates it may be given via `#[garde(context(PasswordContext, default = make_password_context))]`.
+//! The same context is used when deserializing a `Valid<T>`, which is only possible if one of the two is available, so this is checked at compile time.
+//!
+//! To validate a list of values which each need a different context, use `garde::validate_each(&items, &contexts)`,
+//! which returns the result of each item separately, or a `LengthMismatch` error if there is not exactly one context per item.
+//!
+//! The context is always passed by shared reference, including to nested values validated via `dive`,
+//! which receive the same context as their parent. Validators which need to record side effects,
+//! such as which optional fields were present, may use interior mutability:
+//!
+//! ```rust
+//! use std::sync::Mutex;
+//!
+//! #[derive(garde::Validate)]
+//! #[garde(context(Seen))]
+//! struct Settings {
+//!     #[garde(custom(record_theme))]
+//!     theme: Option<String>,
+//!     #[garde(dive)]
+//!     profiles: Vec<Profile>,
+//! }
+//!
+//! #[derive(garde::Validate)]
+//! #[garde(context(Seen))]
+//! struct Profile {
+//!     #[garde(custom(record_avatar))]
+//!     avatar: Option<String>,
+//! }
+//!
+//! #[derive(Default)]
+//! struct Seen(Mutex<Vec<&'static str>>);
+//!
+//! impl Seen {
+//!     fn record(&self, name: &'static str, value: &Option<String>) -> garde::Result {
+//!         if value.is_some() {
+//!             self.0.lock().unwrap().push(name);
+//!         }
+//!         Ok(())
+//!     }
+//! }
+//!
+//! fn record_theme(value: &Option<String>, seen: &Seen) -> garde::Result {
+//!     seen.record("theme", value)
+//! }
+//!
+//! fn record_avatar(value: &Option<String>, seen: &Seen) -> garde::Result {
+//!     seen.record("avatar", value)
+//! }
+//!
+//! let seen = Seen::default();
+//! let settings = Settings {
+//!     theme: Some("dark".into()),
+//!     profiles: vec![Profile { avatar: None }, Profile { avatar: Some("cat.png".into()) }],
+//! };
+//! garde::Validate::validate(&settings, &seen).unwrap();
+//! let seen = seen.0.into_inner().unwrap();
+//! assert!(seen.contains(&"theme") && seen.contains(&"avatar"));
+//! ```
+//!
+//! A `Cell` or `RefCell` works as well, unless a field is validated in parallel with `dive(par)`, which requires the context to be `Sync`.
+//!
+//! Alternatively, `#[derive(garde::ValidateMut)]` implements the `ValidateMut` trait, whose `validate_mut` method receives the context as `&mut Self::Context`.
+//! It accepts the same rules as `Validate`, passes the mutable context to `custom` rules, and to nested values via `dive`, which must implement `ValidateMut` as well.
+//! See [`validate_mut`](https://docs.rs/garde/latest/garde/validate_mut/index.html) for an example.
+//!
 //! ### Implementing rules
 //!
 //! Say you want to implement length checking for a custom string-like type.
@@ -154,6 +312,47 @@
 //! Each rule comes with its own trait that may be implemented by custom types in your code.
 //! They are all available under [`rules`].
 //!
+//! ### Newtypes
+//!
+//! A struct with exactly one field may be marked `#[garde(transparent)]`, in which case it is validated as if it were its only field.
+//! Its errors are reported at its own path, without the name or index of the field:
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! #[garde(transparent)]
+//! struct Username(#[garde(length(min = 3))] String);
+//!
+//! #[derive(garde::Validate)]
+//! struct User {
+//!     // errors are reported at `value.username`, not `value.username[0]`
+//!     #[garde(dive)]
+//!     username: Username,
+//! }
+//! ```
+//!
+//! ### Recursive structures
+//!
+//! Validating a recursive structure with `#[garde(dive)]` recurses once per level of nesting, so deeply nested input may overflow the stack.
+//! To guard against this, run the validation inside `garde::validate::with_max_depth`, which fails any `dive` nested deeper than the given limit
+//! with the error `maximum validation depth exceeded`:
+//!
+//! ```rust
+//! use garde::Validate;
+//!
+//! #[derive(garde::Validate)]
+//! struct Node {
+//!     #[garde(dive)]
+//!     children: Vec<Node>,
+//! }
+//!
+//! let node = Node { children: vec![] };
+//! let result = garde::validate::with_max_depth(64, || node.validate(&()));
+//! ```
+//!
+//! By default, there is no limit. The limit is not part of the validation context, as the context is a user-defined type which is passed unchanged to every nested value,
+//! so it only applies to validation performed on the current thread inside `with_max_depth`. For `AsyncValidate`, wrap the future in `garde::validate_async::with_max_depth` instead,
+//! which carries the limit with the future even if it resumes on a different thread.
+//!
 //! ### Implementing `Validate`
 //!
 //! In case you have a container type for which you'd like to support nested validation (using the `#[garde(dive)]` rule),
@@ -191,10 +390,15 @@
 //!
 //! To make implementing the trait easier, the [`error::Errors`] type supports a nesting builders.
 //! - For list-like or tuple-like data structures, use `Errors::list`, and its `.push` method to attach nested `Errors`.
-//! - For map-like data structures, use `Errors::fields`, and its `.insert` method to attach nested `Errors`.
+//! - For struct-like data structures, use `Errors::fields`, and its `.insert` method to attach nested `Errors`.
+//! - For map-like data structures, use `Errors::map`, and its `.insert` method to attach nested `Errors` at the path of each key, e.g. `value[key]`.
 //! - For a "flat" error list, use `Errors::simple`, and its `.push` method to attach individual errors.
 //!
-//! The `ListErrorBuilder::push` and `ListErrorBuilder::insert` methods will ignore any errors which are empty (via `Errors::is_empty`).
+//! The `FieldsErrorBuilder::insert` and `MapErrorBuilder::insert` methods will ignore any errors which are empty (via `Errors::is_empty`). The `ListErrorBuilder::push` method keeps them, so that the index of each item in the error path matches its position in the list. Empty entries do not appear in `Errors::flatten`. To inspect the errors without cloning them, such as for custom rendering, `Errors::iter` yields the same `(path, error)` pairs as `Errors::flatten`, and `Errors::len` counts them.
+//!
+//! To combine the results of several `validate` calls into one set of errors, use `Errors::merge` for errors of the same value, or `Errors::merge_at` to nest the other errors under a field. For example, `Errors::from(account.validate(&())).merge_at("billing", billing.validate(&()).into())` reports an error at `value.street` of `billing` at `value.billing.street`. Errors at the same path are kept in the order they were merged, but `Errors::flatten` still yields the errors of a value before those nested under it, and fields in order of their name, regardless of the order in which they were merged. Duplicates are only removed if you call `Errors::dedup`. To compare errors against a list sorted by path, such as in snapshot tests, `Errors::sort_by_path` returns the pairs of `Errors::flatten` sorted by their path string.
+//!
+//! `Validate::validate_first_error` has a default implementation based on `validate`. You may override it to stop at the first error, like the derive macro does.
 //!
 //! ### Feature flags
 //!
@@ -202,21 +406,43 @@
 //! | name                     | description                                                                                                                       | extra dependencies                                                                           |
 //! |--------------------------|-----------------------------------------------------------------------------------------------------------------------------------|----------------------------------------------------------------------------------------------|
 //! | `derive`                 | Enables the usage of the `derive(Validate)` macro                                                                                 | [`garde_derive`](https://crates.io/crates/garde_derive)                                      |
+//! | `serde`                  | `Deserialize` for `Unvalidated<T>` and `Valid<T>`, and `Serialize` for errors as a map of paths to messages                       | [`serde`](https://crates.io/crates/serde)                                                    |
 //! | `url`                    | Validation of URLs via the `url` crate.                                                                                           | [`url`](https://crates.io/crates/url)                                                        |
 //! | `email`                  | Validation of emails according to [HTML5](https://html.spec.whatwg.org/multipage/forms.html#valid-e-mail-address)                 | [`regex`](https://crates.io/crates/regex), [`once_cell`](https://crates.io/crates/once_cell) |
 //! | `email-idna`             | Support for [Internationalizing Domain Names for Applications](https://url.spec.whatwg.org/#idna) in email addresses              | [`idna`](https://crates.io/crates/idna)                                                      |
 //! | `pattern`                | Validation using regular expressions via the `regex` crate                                                                        | [`regex`](https://crates.io/crates/regex), [`once_cell`](https://crates.io/crates/once_cell) |
 //! | `credit-card`            | Validation of credit card numbers via the `card-validate` crate                                                                   | [`card-validate`](https://crates.io/crates/card-validate)                                    |
+//! | `iban`                   | Validation of IBANs (country code, length and mod-97 checksum)                                                                    | -                                                                                            |
+//! | `json`                   | Validation of JSON strings via the `serde_json` crate                                                                             | [`serde_json`](https://crates.io/crates/serde_json)                                          |
+//! | `uuid`                   | Validation of UUIDs via the `uuid` crate                                                                                          | [`uuid`](https://crates.io/crates/uuid)                                                      |
+//! | `semver`                 | Validation of semantic versions and version requirements via the `semver` crate                                                   | [`semver`](https://crates.io/crates/semver)                                                  |
+//! | `base64`                 | Validation of base64 strings via the `base64` crate                                                                               | [`base64`](https://crates.io/crates/base64)                                                  |
+//! | `graphemes`              | Validation of string length in grapheme clusters via the `unicode-segmentation` crate                                             | [`unicode-segmentation`](https://crates.io/crates/unicode-segmentation)                      |
 //! | `phone-number`           | Validation of phone numbers via the `phonenumber` crate                                                                           | [`phonenumber`](https://crates.io/crates/phonenumber)                                        |
+//! | `async`                  | The `AsyncValidate` trait and derive macro, which support asynchronous custom validators                                          | -                                                                                            |
+//! | `chrono`                 | The `date` and `datetime` rules, and comparison of `chrono` types with `greater_than` and `less_than`                             | [`chrono`](https://crates.io/crates/chrono)                                                  |
+//! | `rayon`                  | Parallel validation of slices with more than 1024 items via `dive(par)`. Requires the items and their context to be `Sync`        | [`rayon`](https://crates.io/crates/rayon)                                                    |
 //! | `nightly-error-messages` | Enables usage of `rustc_on_unimplemented` for better error messages. This is an unstable feature and requires a nightly compiler. | -                                                                                            |
+//!
+//! [^1]: [HTML5 forms - valid email address](https://html.spec.whatwg.org/multipage/forms.html#valid-e-mail-address)
+
+//! [^2]: Only supported by `#[derive(AsyncValidate)]`, see [`validate_async`](https://docs.rs/garde/latest/garde/validate_async/index.html).
 
 pub mod error;
 pub mod rules;
 pub mod validate;
+#[cfg(feature = "async")]
+pub mod validate_async;
+pub mod validate_mut;
 
 pub use error::{Error, Errors};
+#[cfg(all(feature = "derive", feature = "async"))]
+pub use garde_derive::AsyncValidate;
 #[cfg(feature = "derive")]
-pub use garde_derive::Validate;
-pub use validate::{Unvalidated, Valid, Validate};
+pub use garde_derive::{Validate, ValidateMut};
+pub use validate::{validate_each, Unvalidated, Valid, Validate, ValidateDefault};
+#[cfg(feature = "async")]
+pub use validate_async::AsyncValidate;
+pub use validate_mut::ValidateMut;
 
 pub type Result = ::core::result::Result<(), Error>;
@@ -11,12 +11,23 @@
 //! The entrypoint is the [`Alphanumeric`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(alphanumeric)]` rule.
 //!
 //! This trait has a blanket implementation for all `T: AsRef<str>`.
+//!
+//! By default, letters and digits from any script are accepted, according to [`char::is_alphanumeric`],
+//! so `José2` and `١٢٣` are valid. This may also be spelled out as `#[garde(alphanumeric(unicode))]`.
+//! Only ASCII letters and digits may be accepted using `#[garde(alphanumeric(ascii))]`.
+//!
+//! Combining marks are only accepted if they have the Unicode `Alphabetic` property. For example, a decomposed `é`
+//! (`e` followed by U+0301) and words written with a virama, such as `नमस्ते`, are rejected.
 
 use crate::error::Error;
 
-pub fn apply<T: Alphanumeric>(v: &T, _: ()) -> Result<(), Error> {
-    if !v.validate_alphanumeric() {
-        return Err(Error::new("not alphanumeric"));
+pub fn apply<T: Alphanumeric>(v: &T, (kind,): (AlphanumericKind,)) -> Result<(), Error> {
+    if !v.validate_alphanumeric(kind) {
+        let message = match kind {
+            AlphanumericKind::Unicode => "not alphanumeric",
+            AlphanumericKind::Ascii => "not ASCII alphanumeric",
+        };
+        return Err(Error::with_code("alphanumeric", message));
     }
     Ok(())
 }
@@ -29,11 +40,24 @@ pub fn apply<T: Alphanumeric>(v: &T, _: ()) -> Result<(), Error> {
     )
 )]
 pub trait Alphanumeric {
-    fn validate_alphanumeric(&self) -> bool;
+    fn validate_alphanumeric(&self, kind: AlphanumericKind) -> bool;
+}
+
+/// The set of characters considered alphanumeric.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AlphanumericKind {
+    /// Letters and digits from any script.
+    Unicode,
+    /// ASCII letters and digits only.
+    Ascii,
 }
 
 impl<T: AsRef<str>> Alphanumeric for T {
-    fn validate_alphanumeric(&self) -> bool {
-        self.as_ref().chars().all(|c| c.is_alphanumeric())
+    fn validate_alphanumeric(&self, kind: AlphanumericKind) -> bool {
+        let v = self.as_ref();
+        match kind {
+            AlphanumericKind::Unicode => v.chars().all(|c| c.is_alphanumeric()),
+            AlphanumericKind::Ascii => v.chars().all(|c| c.is_ascii_alphanumeric()),
+        }
     }
 }
@@ -11,12 +11,14 @@
 //! The entrypoint is the [`Ascii`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(ascii)]` rule.
 //!
 //! This trait has a blanket implementation for all `T: AsRef<str>`.
+//!
+//! Byte containers are validated using `#[garde(ascii(bytes))]` instead, see [`ascii_bytes`][crate::rules::ascii_bytes].
 
 use crate::error::Error;
 
 pub fn apply<T: Ascii>(v: &T, _: ()) -> Result<(), Error> {
     if !v.validate_ascii() {
-        return Err(Error::new("not ascii"));
+        return Err(Error::with_code("ascii", "not ascii"));
     }
     Ok(())
 }
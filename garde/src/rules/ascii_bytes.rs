@@ -0,0 +1,73 @@
+//! ASCII validation for byte containers.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(ascii(bytes))]
+//!     v: Vec<u8>,
+//! }
+//! ```
+//!
+//! The entrypoint is the [`AsciiBytes`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(ascii(bytes))]` rule.
+//!
+//! This trait is implemented for `[u8]`, `[u8; N]`, `Vec<u8>`, and `Cow<[u8]>`,
+//! as well as for any reference to a type which implements [`AsciiBytes`].
+//!
+//! A byte container is valid if every byte is `<= 0x7F`. When it is not, the error
+//! reports the index of the first non-ASCII byte.
+
+use std::borrow::Cow;
+
+use crate::error::Error;
+
+pub fn apply<T: AsciiBytes>(v: &T, _: ()) -> Result<(), Error> {
+    if let Some(index) = v.find_non_ascii_byte() {
+        return Err(Error::with_code(
+            "ascii",
+            format!("not ascii: non-ascii byte at index {index}"),
+        ));
+    }
+    Ok(())
+}
+
+#[cfg_attr(
+    feature = "nightly-error-messages",
+    rustc_on_unimplemented(
+        message = "`{Self}` does not support ascii byte validation",
+        label = "This type does not support ascii byte validation",
+    )
+)]
+pub trait AsciiBytes {
+    /// Returns the index of the first byte which is not ASCII, if any.
+    fn find_non_ascii_byte(&self) -> Option<usize>;
+}
+
+impl<T: AsciiBytes + ?Sized> AsciiBytes for &T {
+    fn find_non_ascii_byte(&self) -> Option<usize> {
+        T::find_non_ascii_byte(self)
+    }
+}
+
+impl AsciiBytes for [u8] {
+    fn find_non_ascii_byte(&self) -> Option<usize> {
+        self.iter().position(|b| !b.is_ascii())
+    }
+}
+
+impl<const N: usize> AsciiBytes for [u8; N] {
+    fn find_non_ascii_byte(&self) -> Option<usize> {
+        self[..].find_non_ascii_byte()
+    }
+}
+
+impl AsciiBytes for Vec<u8> {
+    fn find_non_ascii_byte(&self) -> Option<usize> {
+        self[..].find_non_ascii_byte()
+    }
+}
+
+impl AsciiBytes for Cow<'_, [u8]> {
+    fn find_non_ascii_byte(&self) -> Option<usize> {
+        self[..].find_non_ascii_byte()
+    }
+}
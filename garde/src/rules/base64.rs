@@ -0,0 +1,108 @@
+//! Base64 validation using the [`base64`] crate.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(base64)]
+//!     v: String,
+//! }
+//! ```
+//!
+//! The entrypoint is the [`Base64`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(base64)]` rule.
+//!
+//! This trait has a blanket implementation for all `T: AsRef<str>`.
+//!
+//! By default, the value must use the standard alphabet (`+` and `/`), and be padded with `=`.
+//! The URL-safe alphabet (`-` and `_`) may be selected using `#[garde(base64(url_safe))]`,
+//! in which case padding is optional, as it is commonly omitted in URLs.
+//!
+//! The empty string is valid, as it decodes to an empty byte sequence.
+
+use std::fmt::Display;
+
+use base64::engine::general_purpose::{GeneralPurpose, GeneralPurposeConfig, STANDARD};
+use base64::engine::DecodePaddingMode;
+use base64::Engine as _;
+
+use crate::error::Error;
+
+pub fn apply<T: Base64>(v: &T, (kind,): (Base64Kind,)) -> Result<(), Error> {
+    if let Err(e) = v.validate_base64(kind) {
+        return Err(Error::with_code("base64", format!("not valid base64: {e}")));
+    }
+    Ok(())
+}
+
+#[cfg_attr(
+    feature = "nightly-error-messages",
+    rustc_on_unimplemented(
+        message = "`{Self}` does not support base64 validation",
+        label = "This type does not support base64 validation",
+    )
+)]
+pub trait Base64 {
+    type Error: Display;
+
+    fn validate_base64(&self, kind: Base64Kind) -> Result<(), Self::Error>;
+}
+
+/// The alphabet a base64 value is encoded with.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Base64Kind {
+    /// The standard alphabet, with required padding.
+    Standard,
+    /// The URL-safe alphabet, with optional padding.
+    UrlSafe,
+}
+
+const URL_SAFE: GeneralPurpose = GeneralPurpose::new(
+    &base64::alphabet::URL_SAFE,
+    GeneralPurposeConfig::new().with_decode_padding_mode(DecodePaddingMode::Indifferent),
+);
+
+impl<T: AsRef<str>> Base64 for T {
+    type Error = InvalidBase64;
+
+    fn validate_base64(&self, kind: Base64Kind) -> Result<(), Self::Error> {
+        let v = self.as_ref();
+        let result = match kind {
+            Base64Kind::Standard => STANDARD.decode(v),
+            Base64Kind::UrlSafe => URL_SAFE.decode(v),
+        };
+        match result {
+            Ok(_) => Ok(()),
+            Err(
+                base64::DecodeError::InvalidByte(offset, byte)
+                | base64::DecodeError::InvalidLastSymbol(offset, byte),
+            ) => Err(InvalidBase64::InvalidCharacter {
+                offset,
+                // the offset points at the first byte of a non-ASCII character
+                character: v
+                    .get(offset..)
+                    .and_then(|rest| rest.chars().next())
+                    .unwrap_or(char::from(byte)),
+            }),
+            Err(base64::DecodeError::InvalidLength(_)) => Err(InvalidBase64::InvalidLength),
+            Err(base64::DecodeError::InvalidPadding) => Err(InvalidBase64::InvalidPadding),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum InvalidBase64 {
+    InvalidCharacter { offset: usize, character: char },
+    InvalidLength,
+    InvalidPadding,
+}
+
+impl Display for InvalidBase64 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InvalidBase64::InvalidCharacter { offset, character } => {
+                write!(f, "invalid character {character:?} at offset {offset}")
+            }
+            InvalidBase64::InvalidLength => write!(f, "invalid length"),
+            InvalidBase64::InvalidPadding => write!(f, "invalid padding"),
+        }
+    }
+}
@@ -8,6 +8,8 @@
 //! }
 //! ```
 //!
+//! `#[garde(length(bytes, min=1, max=100))]` is the same rule.
+//!
 //! The entrypoint is the [`ByteLength`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(byte_length(...))]` rule.
 //!
 //! The [`ByteLength`] has a companion trait [`HasByteLength`], which may be implemented for any container with a known length counted in bytes.
@@ -30,14 +32,28 @@
 
 use crate::error::Error;
 
-pub fn apply<T: ByteLength>(v: &T, (min, max): (usize, usize)) -> Result<(), Error> {
+/// `exact` is set by `equal = ...`, in which case `min` and `max` are the same
+/// and the error reports the exact length which was expected.
+pub fn apply<T: ByteLength>(v: &T, (min, max, exact): (usize, usize, bool)) -> Result<(), Error> {
     if let Err(e) = v.validate_byte_length(min, max) {
+        if exact {
+            return Err(Error::with_code(
+                "byte_length.equal",
+                format!("byte length is not exactly {min}"),
+            ));
+        }
         match e {
             InvalidLength::Min => {
-                return Err(Error::new(format!("byte length is lower than {min}")))
+                return Err(Error::with_code(
+                    "byte_length.min",
+                    format!("byte length is lower than {min}"),
+                ))
             }
             InvalidLength::Max => {
-                return Err(Error::new(format!("byte length is greater than {max}")))
+                return Err(Error::with_code(
+                    "byte_length.max",
+                    format!("byte length is greater than {max}"),
+                ))
             }
         }
     }
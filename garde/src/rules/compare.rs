@@ -0,0 +1,112 @@
+//! Comparison with another field.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(skip)]
+//!     start: u64,
+//!     #[garde(greater_than(start))]
+//!     end: u64,
+//! }
+//! ```
+//!
+//! The field is compared to the sibling field named in `greater_than(...)` or `less_than(...)`, which must have the same type.
+//! Both rules are strict, so the value must not be equal to the other field.
+//!
+//! The entrypoint is the [`Compare`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(greater_than(...))]` and `#[garde(less_than(...))]` rules.
+//!
+//! This trait is implemented for all primitive integer and floating point types, `char`, `bool`, `String`, `str`,
+//! `Duration`, `SystemTime`, `Instant`, and for `Option<T>` where `T: Compare`.
+//! If the `chrono` feature is enabled, it is also implemented for `NaiveDate`, `NaiveTime`, `NaiveDateTime`, and `DateTime<Tz>`.
+//!
+//! If either value is `None`, the comparison is skipped and the rule passes.
+//! Values which cannot be compared, such as floating point `NaN`, fail validation.
+
+use std::cmp::Ordering;
+
+use crate::error::Error;
+
+pub fn apply<T: Compare + ?Sized>(
+    v: &T,
+    (other, name, ordering): (&T, &str, Ordering),
+) -> Result<(), Error> {
+    if !v.validate_compare(other, ordering) {
+        let (code, message) = match ordering {
+            Ordering::Greater => ("greater_than", format!("not greater than `{name}`")),
+            Ordering::Less => ("less_than", format!("not lower than `{name}`")),
+            Ordering::Equal => ("equal_to", format!("not equal to `{name}`")),
+        };
+        return Err(Error::with_code(code, message));
+    }
+    Ok(())
+}
+
+#[cfg_attr(
+    feature = "nightly-error-messages",
+    rustc_on_unimplemented(
+        message = "`{Self}` does not support comparison with another field",
+        label = "This type does not support comparison with another field",
+        note = "try implementing `garde::rules::compare::Compare` for `{Self}`"
+    )
+)]
+pub trait Compare {
+    /// Checks that comparing `self` to `other` results in `ordering`.
+    ///
+    /// Returns `true` if the comparison should be skipped, such as when either value is `None`.
+    fn validate_compare(&self, other: &Self, ordering: Ordering) -> bool;
+}
+
+macro_rules! impl_via_partial_ord {
+    ($($T:ty),*) => {
+        $(
+            impl Compare for $T {
+                fn validate_compare(&self, other: &Self, ordering: Ordering) -> bool {
+                    self.partial_cmp(other) == Some(ordering)
+                }
+            }
+        )*
+    };
+}
+
+impl_via_partial_ord!(
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    usize,
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    isize,
+    f32,
+    f64,
+    char,
+    bool,
+    String,
+    str,
+    std::time::Duration,
+    std::time::SystemTime,
+    std::time::Instant
+);
+
+#[cfg(feature = "chrono")]
+impl_via_partial_ord!(chrono::NaiveDate, chrono::NaiveTime, chrono::NaiveDateTime);
+
+#[cfg(feature = "chrono")]
+impl<Tz: chrono::TimeZone> Compare for chrono::DateTime<Tz> {
+    fn validate_compare(&self, other: &Self, ordering: Ordering) -> bool {
+        self.partial_cmp(other) == Some(ordering)
+    }
+}
+
+impl<T: Compare> Compare for Option<T> {
+    fn validate_compare(&self, other: &Self, ordering: Ordering) -> bool {
+        match (self, other) {
+            (Some(v), Some(other)) => v.validate_compare(other, ordering),
+            _ => true,
+        }
+    }
+}
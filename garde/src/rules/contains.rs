@@ -11,12 +11,33 @@
 //! The entrypoint is the [`Contains`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(contains)]` rule.
 //!
 //! This trait has a blanket implementation for all `T: AsRef<str>`.
+//!
+//! The match is case-sensitive by default. It may be made case-insensitive using `insensitive`:
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(contains("test", insensitive))]
+//!     v: String,
+//! }
+//! ```
+//!
+//! Case-insensitive matching lowercases both the value and the pattern using [`str::to_lowercase`], so it works for all of Unicode.
+//! It does not perform full case folding, which means that e.g. `ß` does not match `SS`.
 
 use crate::error::Error;
 
-pub fn apply<T: Contains>(v: &T, (pat,): (&str,)) -> Result<(), Error> {
-    if !v.validate_contains(pat) {
-        return Err(Error::new(format!("does not contain \"{pat}\"")));
+pub fn apply<T: Contains>(v: &T, (pat, insensitive): (&str, bool)) -> Result<(), Error> {
+    let valid = if insensitive {
+        v.validate_contains_insensitive(pat)
+    } else {
+        v.validate_contains(pat)
+    };
+    if !valid {
+        return Err(Error::with_code(
+            "contains",
+            format!("does not contain \"{pat}\""),
+        ));
     }
     Ok(())
 }
@@ -30,10 +51,23 @@ pub fn apply<T: Contains>(v: &T, (pat,): (&str,)) -> Result<(), Error> {
 )]
 pub trait Contains {
     fn validate_contains(&self, pat: &str) -> bool;
+
+    /// Used by `contains(..., insensitive)`.
+    ///
+    /// The default implementation falls back to the case-sensitive [`Contains::validate_contains`].
+    fn validate_contains_insensitive(&self, pat: &str) -> bool {
+        self.validate_contains(pat)
+    }
 }
 
 impl<T: AsRef<str>> Contains for T {
     fn validate_contains(&self, pat: &str) -> bool {
         self.as_ref().contains(pat)
     }
+
+    fn validate_contains_insensitive(&self, pat: &str) -> bool {
+        self.as_ref()
+            .to_lowercase()
+            .contains(pat.to_lowercase().as_str())
+    }
 }
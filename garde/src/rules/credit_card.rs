@@ -11,14 +11,39 @@
 //! The entrypoint is the [`CreditCard`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(credit_card)]` rule.
 //!
 //! This trait has a blanket implementation for all `T: AsRef<str>`.
+//!
+//! The accepted card brands may be restricted using the `types` argument:
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(credit_card(types("visa", "mastercard")))]
+//!     v: String,
+//! }
+//! ```
+//!
+//! Brand names are the ones reported by [`CardBrand::name`].
+//! Note that debit variants are detected as separate brands, so `"visa"` does not accept `"visaelectron"` cards.
+//! If `types` is empty or omitted, any recognized brand is accepted.
+//!
+//! After validation, the detected brand may be retrieved using [`CreditCard::detect_credit_card`]:
+//!
+//! ```rust
+//! use garde::rules::credit_card::{CardBrand, CreditCard};
+//!
+//! assert_eq!("4539571147647251".detect_credit_card().ok(), Some(CardBrand::Visa));
+//! ```
 
 use std::fmt::Display;
 
 use crate::error::Error;
 
-pub fn apply<T: CreditCard>(v: &T, _: ()) -> Result<(), Error> {
-    if let Err(e) = v.validate_credit_card() {
-        return Err(Error::new(format!("not a valid credit card number: {e}")));
+pub fn apply<T: CreditCard>(v: &T, (types,): (&[&str],)) -> Result<(), Error> {
+    if let Err(e) = v.validate_credit_card(types) {
+        return Err(Error::with_code(
+            "credit_card",
+            format!("not a valid credit card number: {e}"),
+        ));
     }
     Ok(())
 }
@@ -33,33 +58,122 @@ pub fn apply<T: CreditCard>(v: &T, _: ()) -> Result<(), Error> {
 pub trait CreditCard {
     type Error: Display;
 
-    fn validate_credit_card(&self) -> Result<(), Self::Error>;
+    /// Validates the card number, returning the detected brand.
+    fn detect_credit_card(&self) -> Result<CardBrand, Self::Error>;
+
+    fn validate_credit_card(&self, types: &[&str]) -> Result<(), Self::Error>;
 }
 
 impl<T: AsRef<str>> CreditCard for T {
     type Error = InvalidCard;
 
-    fn validate_credit_card(&self) -> Result<(), Self::Error> {
-        let _ = card_validate::Validate::from(self.as_ref())?;
+    fn detect_credit_card(&self) -> Result<CardBrand, Self::Error> {
+        let card = card_validate::Validate::from(self.as_ref())?;
+        CardBrand::from_type(card.card_type).ok_or(InvalidCard::Invalid(
+            card_validate::ValidateError::UnknownType,
+        ))
+    }
+
+    fn validate_credit_card(&self, types: &[&str]) -> Result<(), Self::Error> {
+        let brand = self.detect_credit_card()?;
+        if !types.is_empty() && !types.contains(&brand.name()) {
+            return Err(InvalidCard::Brand(brand));
+        }
         Ok(())
     }
 }
 
-pub struct InvalidCard(card_validate::ValidateError);
+/// A card brand, as detected by [`card_validate`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CardBrand {
+    VisaElectron,
+    Maestro,
+    Forbrugsforeningen,
+    Dankort,
+    Visa,
+    Mir,
+    MasterCard,
+    Amex,
+    DinersClub,
+    Discover,
+    UnionPay,
+    Jcb,
+}
+
+impl CardBrand {
+    /// The lowercase name of the brand, as used in `#[garde(credit_card(types(...)))]`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            CardBrand::VisaElectron => "visaelectron",
+            CardBrand::Maestro => "maestro",
+            CardBrand::Forbrugsforeningen => "forbrugsforeningen",
+            CardBrand::Dankort => "dankort",
+            CardBrand::Visa => "visa",
+            CardBrand::Mir => "mir",
+            CardBrand::MasterCard => "mastercard",
+            CardBrand::Amex => "amex",
+            CardBrand::DinersClub => "dinersclub",
+            CardBrand::Discover => "discover",
+            CardBrand::UnionPay => "unionpay",
+            CardBrand::Jcb => "jcb",
+        }
+    }
+
+    fn from_type(ty: card_validate::Type) -> Option<Self> {
+        let brand = match ty {
+            card_validate::Type::VisaElectron => CardBrand::VisaElectron,
+            card_validate::Type::Maestro => CardBrand::Maestro,
+            card_validate::Type::Forbrugsforeningen => CardBrand::Forbrugsforeningen,
+            card_validate::Type::Dankort => CardBrand::Dankort,
+            card_validate::Type::Visa => CardBrand::Visa,
+            card_validate::Type::MIR => CardBrand::Mir,
+            card_validate::Type::MasterCard => CardBrand::MasterCard,
+            card_validate::Type::Amex => CardBrand::Amex,
+            card_validate::Type::DinersClub => CardBrand::DinersClub,
+            card_validate::Type::Discover => CardBrand::Discover,
+            card_validate::Type::UnionPay => CardBrand::UnionPay,
+            card_validate::Type::JCB => CardBrand::Jcb,
+            _ => return None,
+        };
+        Some(brand)
+    }
+}
+
+impl Display for CardBrand {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+pub enum InvalidCard {
+    Invalid(card_validate::ValidateError),
+    Brand(CardBrand),
+}
+
 impl Display for InvalidCard {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &self.0 {
-            card_validate::ValidateError::InvalidFormat => write!(f, "invalid format"),
-            card_validate::ValidateError::InvalidLength => write!(f, "invalid length"),
-            card_validate::ValidateError::InvalidLuhn => write!(f, "invalid luhn"),
-            card_validate::ValidateError::UnknownType => write!(f, "unknown type"),
-            _ => write!(f, "unknown error"),
+        match self {
+            InvalidCard::Invalid(card_validate::ValidateError::InvalidFormat) => {
+                write!(f, "invalid format")
+            }
+            InvalidCard::Invalid(card_validate::ValidateError::InvalidLength) => {
+                write!(f, "invalid length")
+            }
+            InvalidCard::Invalid(card_validate::ValidateError::InvalidLuhn) => {
+                write!(f, "invalid luhn")
+            }
+            InvalidCard::Invalid(card_validate::ValidateError::UnknownType) => {
+                write!(f, "unknown type")
+            }
+            InvalidCard::Invalid(_) => write!(f, "unknown error"),
+            InvalidCard::Brand(brand) => write!(f, "card brand `{brand}` is not accepted"),
         }
     }
 }
 
 impl From<card_validate::ValidateError> for InvalidCard {
     fn from(value: card_validate::ValidateError) -> Self {
-        Self(value)
+        Self::Invalid(value)
     }
 }
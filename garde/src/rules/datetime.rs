@@ -0,0 +1,118 @@
+//! Date and time string validation using the [`chrono`] crate.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(date)]
+//!     date: String,
+//!     #[garde(datetime)]
+//!     datetime: String,
+//! }
+//! ```
+//!
+//! The entrypoint is the [`DateTime`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(date)]` and `#[garde(datetime)]` rules.
+//!
+//! This trait has a blanket implementation for all `T: AsRef<str>`.
+//!
+//! By default, `date` expects a date in the `%Y-%m-%d` format, such as `2023-08-21`,
+//! and `datetime` expects an [RFC 3339](https://www.rfc-editor.org/rfc/rfc3339) date and time with an offset, such as `2023-08-21T12:30:00+02:00`.
+//! Either rule may be given an explicit format instead, using the syntax of [`chrono::format::strftime`]:
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(date(format = "%d.%m.%Y"))]
+//!     date: String,
+//!     #[garde(datetime(format = "%Y/%m/%d %H:%M"))]
+//!     datetime: String,
+//! }
+//! ```
+//!
+//! A `datetime` format must include both a date and a time. If it includes an offset, such as `%z`, the offset is checked but otherwise ignored.
+
+use std::fmt::Display;
+
+use crate::error::Error;
+
+pub fn apply<T: DateTime>(v: &T, (format,): (Format<'_>,)) -> Result<(), Error> {
+    if let Err(e) = v.validate_datetime(format) {
+        let code = match format {
+            Format::Date(_) => "date",
+            Format::DateTime(_) | Format::Rfc3339 => "datetime",
+        };
+        return Err(Error::with_code(code, e.to_string()));
+    }
+    Ok(())
+}
+
+#[cfg_attr(
+    feature = "nightly-error-messages",
+    rustc_on_unimplemented(
+        message = "`{Self}` does not support date and time validation",
+        label = "This type does not support date and time validation",
+    )
+)]
+pub trait DateTime {
+    type Error: Display;
+
+    fn validate_datetime(&self, format: Format<'_>) -> Result<(), Self::Error>;
+}
+
+/// The format a date or time string is expected to be in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format<'a> {
+    /// A date in the given format. Used by `#[garde(date)]`.
+    Date(&'a str),
+    /// A date and time in the given format. Used by `#[garde(datetime(format = "..."))]`.
+    DateTime(&'a str),
+    /// An RFC 3339 date and time. Used by `#[garde(datetime)]`.
+    Rfc3339,
+}
+
+impl<T: AsRef<str>> DateTime for T {
+    type Error = InvalidDateTime;
+
+    fn validate_datetime(&self, format: Format<'_>) -> Result<(), Self::Error> {
+        let v = self.as_ref();
+        let result = match format {
+            Format::Date(format) => chrono::NaiveDate::parse_from_str(v, format).map(|_| ()),
+            Format::DateTime(format) => {
+                chrono::NaiveDateTime::parse_from_str(v, format).map(|_| ())
+            }
+            Format::Rfc3339 => chrono::DateTime::parse_from_rfc3339(v).map(|_| ()),
+        };
+        result.map_err(|error| InvalidDateTime {
+            input: v.to_string(),
+            format: match format {
+                Format::Date(format) | Format::DateTime(format) => format.to_string(),
+                Format::Rfc3339 => String::from("RFC 3339"),
+            },
+            kind: match format {
+                Format::Date(_) => "date",
+                Format::DateTime(_) | Format::Rfc3339 => "datetime",
+            },
+            error,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct InvalidDateTime {
+    /// The value which failed to parse.
+    pub input: String,
+    /// The expected format.
+    pub format: String,
+    /// Either `date` or `datetime`.
+    pub kind: &'static str,
+    pub error: chrono::ParseError,
+}
+
+impl Display for InvalidDateTime {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "`{}` is not a valid {} in the format `{}`: {}",
+            self.input, self.kind, self.format, self.error
+        )
+    }
+}
@@ -11,6 +11,10 @@
 //! The entrypoint is the [`Email`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(email)]` rule.
 //!
 //! This trait has a blanket implementation for all `T: AsRef<str>`.
+//!
+//! The error message describes which part of the value is invalid, e.g. `not a valid email: user is empty`
+//! or `not a valid email: domain contains unexpected characters`. The user is everything before the first `@`,
+//! and the domain is everything after it. See [`InvalidEmail`] for all possible reasons.
 
 use std::fmt::Display;
 use std::str::FromStr;
@@ -22,7 +26,7 @@ use crate::error::Error;
 
 pub fn apply<T: Email>(v: &T, _: ()) -> Result<(), Error> {
     if let Err(e) = v.validate_email() {
-        return Err(Error::new(format!("not a valid email: {e}")));
+        return Err(Error::with_code("email", format!("not a valid email: {e}")));
     }
     Ok(())
 }
@@ -52,8 +56,10 @@ impl<T: AsRef<str>> Email for T {
 pub enum InvalidEmail {
     Empty,
     MissingAt,
+    EmptyUser,
     UserLengthExceeded,
     InvalidUser,
+    EmptyDomain,
     DomainLengthExceeded,
     InvalidDomain,
 }
@@ -63,10 +69,12 @@ impl Display for InvalidEmail {
         match self {
             InvalidEmail::Empty => write!(f, "value is empty"),
             InvalidEmail::MissingAt => write!(f, "value is missing `@`"),
+            InvalidEmail::EmptyUser => write!(f, "user is empty"),
             InvalidEmail::UserLengthExceeded => {
                 write!(f, "user length exceeded maximum of 64 characters")
             }
             InvalidEmail::InvalidUser => write!(f, "user contains unexpected characters"),
+            InvalidEmail::EmptyDomain => write!(f, "domain is empty"),
             InvalidEmail::DomainLengthExceeded => {
                 write!(f, "domain length exceeded maximum of 255 characters")
             }
@@ -82,6 +90,9 @@ pub fn parse_email(s: &str) -> Result<(), InvalidEmail> {
 
     let (user, domain) = s.split_once('@').ok_or(InvalidEmail::MissingAt)?;
 
+    if user.is_empty() {
+        return Err(InvalidEmail::EmptyUser);
+    }
     if user.len() > 64 {
         return Err(InvalidEmail::UserLengthExceeded);
     }
@@ -91,6 +102,9 @@ pub fn parse_email(s: &str) -> Result<(), InvalidEmail> {
         return Err(InvalidEmail::InvalidUser);
     }
 
+    if domain.is_empty() {
+        return Err(InvalidEmail::EmptyDomain);
+    }
     if domain.len() > 255 {
         return Err(InvalidEmail::DomainLengthExceeded);
     }
@@ -192,7 +206,9 @@ mod tests {
             ),
             ("", Some(InvalidEmail::Empty)),
             ("abc", Some(InvalidEmail::MissingAt)),
-            ("abc@", Some(InvalidEmail::InvalidDomain)),
+            ("abc@", Some(InvalidEmail::EmptyDomain)),
+            ("@example.com", Some(InvalidEmail::EmptyUser)),
+            ("@", Some(InvalidEmail::EmptyUser)),
             ("abc@bar", None),
             ("a @x.cz", Some(InvalidEmail::InvalidUser)),
             ("abc@.com", Some(InvalidEmail::InvalidDomain)),
@@ -0,0 +1,93 @@
+//! Grapheme length validation using the [`unicode_segmentation`] crate.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(length(graphemes, min=1, max=20))]
+//!     v: String,
+//! }
+//! ```
+//!
+//! The entrypoint is the [`GraphemeLength`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(length(graphemes, ...))]` rule.
+//!
+//! The [`GraphemeLength`] has a companion trait [`HasGraphemeLength`], which may be implemented for any string-like type.
+//! [`GraphemeLength`] is implemented for any `T: HasGraphemeLength`, and [`HasGraphemeLength`] has a blanket implementation for all `T: AsRef<str>`.
+//!
+//! The length is the number of extended grapheme clusters, which is closer to what a user perceives as a single character.
+//! For example, `"👨‍👩‍👧‍👦"` is one grapheme, but seven characters.
+//! For validation of length counted in _characters_, see the [`crate::rules::length`] rule.
+
+use unicode_segmentation::UnicodeSegmentation as _;
+
+use crate::error::Error;
+
+/// `exact` is set by `equal = ...`, in which case `min` and `max` are the same
+/// and the error reports the exact length which was expected.
+pub fn apply<T: GraphemeLength>(
+    v: &T,
+    (min, max, exact): (usize, usize, bool),
+) -> Result<(), Error> {
+    if let Err(e) = v.validate_grapheme_length(min, max) {
+        if exact {
+            return Err(Error::with_code(
+                "grapheme_length.equal",
+                format!("length is not exactly {min} graphemes"),
+            ));
+        }
+        match e {
+            InvalidLength::Min => {
+                return Err(Error::with_code(
+                    "grapheme_length.min",
+                    format!("length is lower than {min} graphemes"),
+                ))
+            }
+            InvalidLength::Max => {
+                return Err(Error::with_code(
+                    "grapheme_length.max",
+                    format!("length is greater than {max} graphemes"),
+                ))
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg_attr(
+    feature = "nightly-error-messages",
+    rustc_on_unimplemented(
+        message = "`{Self}` does not support grapheme length validation",
+        label = "This type does not support grapheme length validation",
+        note = "try implementing `garde::rules::grapheme_length::HasGraphemeLength` for `{Self}`"
+    )
+)]
+pub trait GraphemeLength {
+    fn validate_grapheme_length(&self, min: usize, max: usize) -> Result<(), InvalidLength>;
+}
+
+pub enum InvalidLength {
+    Min,
+    Max,
+}
+
+pub trait HasGraphemeLength {
+    fn grapheme_length(&self) -> usize;
+}
+
+impl<T: HasGraphemeLength> GraphemeLength for T {
+    fn validate_grapheme_length(&self, min: usize, max: usize) -> Result<(), InvalidLength> {
+        let len = HasGraphemeLength::grapheme_length(self);
+        if len < min {
+            Err(InvalidLength::Min)
+        } else if len > max {
+            Err(InvalidLength::Max)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl<T: AsRef<str>> HasGraphemeLength for T {
+    fn grapheme_length(&self) -> usize {
+        self.as_ref().graphemes(true).count()
+    }
+}
@@ -0,0 +1,97 @@
+//! Hex color validation.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(hex_color)]
+//!     v: String,
+//! }
+//! ```
+//!
+//! The entrypoint is the [`HexColor`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(hex_color)]` rule.
+//!
+//! This trait has a blanket implementation for all `T: AsRef<str>`.
+//!
+//! The value must be a `#` followed by 3, 4, 6, or 8 hex digits, i.e. `#RGB`, `#RGBA`, `#RRGGBB`, or `#RRGGBBAA`.
+//! Hex digits may be uppercase or lowercase. The variants with an alpha channel may be rejected using
+//! `#[garde(hex_color(alpha = false))]`, in which case only 3 or 6 hex digits are accepted.
+
+use std::fmt::Display;
+
+use crate::error::Error;
+
+pub fn apply<T: HexColor>(v: &T, (alpha,): (bool,)) -> Result<(), Error> {
+    if let Err(e) = v.validate_hex_color(alpha) {
+        return Err(Error::with_code(
+            "hex_color",
+            format!("not a valid hex color: {e}"),
+        ));
+    }
+    Ok(())
+}
+
+#[cfg_attr(
+    feature = "nightly-error-messages",
+    rustc_on_unimplemented(
+        message = "`{Self}` does not support hex color validation",
+        label = "This type does not support hex color validation",
+    )
+)]
+pub trait HexColor {
+    type Error: Display;
+
+    fn validate_hex_color(&self, alpha: bool) -> Result<(), Self::Error>;
+}
+
+impl<T: AsRef<str>> HexColor for T {
+    type Error = InvalidHexColor;
+
+    fn validate_hex_color(&self, alpha: bool) -> Result<(), Self::Error> {
+        let digits = self
+            .as_ref()
+            .strip_prefix('#')
+            .ok_or(InvalidHexColor::MissingHash)?;
+        if let Some((offset, character)) =
+            digits.char_indices().find(|(_, c)| !c.is_ascii_hexdigit())
+        {
+            return Err(InvalidHexColor::InvalidCharacter {
+                // account for the `#`
+                offset: offset + 1,
+                character,
+            });
+        }
+        match digits.len() {
+            3 | 6 => Ok(()),
+            4 | 8 if alpha => Ok(()),
+            length => Err(InvalidHexColor::InvalidLength { length, alpha }),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum InvalidHexColor {
+    MissingHash,
+    InvalidCharacter { offset: usize, character: char },
+    InvalidLength { length: usize, alpha: bool },
+}
+
+impl Display for InvalidHexColor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InvalidHexColor::MissingHash => write!(f, "missing leading `#`"),
+            InvalidHexColor::InvalidCharacter { offset, character } => {
+                write!(f, "invalid character {character:?} at offset {offset}")
+            }
+            InvalidHexColor::InvalidLength {
+                length,
+                alpha: true,
+            } => {
+                write!(f, "expected 3, 4, 6, or 8 hex digits, got {length}")
+            }
+            InvalidHexColor::InvalidLength {
+                length,
+                alpha: false,
+            } => write!(f, "expected 3 or 6 hex digits, got {length}"),
+        }
+    }
+}
@@ -0,0 +1,207 @@
+//! IBAN validation.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(iban)]
+//!     v: String,
+//! }
+//! ```
+//!
+//! The entrypoint is the [`Iban`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(iban)]` rule.
+//!
+//! This trait has a blanket implementation for all `T: AsRef<str>`.
+//!
+//! The value is checked against the country code, the length registered for that country, and the mod-97 checksum.
+//! Whitespace is ignored, and lowercase letters are accepted.
+
+use std::fmt::Display;
+
+use crate::error::Error;
+
+pub fn apply<T: Iban>(v: &T, _: ()) -> Result<(), Error> {
+    if let Err(e) = v.validate_iban() {
+        return Err(Error::with_code("iban", format!("not a valid IBAN: {e}")));
+    }
+    Ok(())
+}
+
+#[cfg_attr(
+    feature = "nightly-error-messages",
+    rustc_on_unimplemented(
+        message = "`{Self}` does not support IBAN validation",
+        label = "This type does not support IBAN validation",
+    )
+)]
+pub trait Iban {
+    type Error: Display;
+
+    fn validate_iban(&self) -> Result<(), Self::Error>;
+}
+
+impl<T: AsRef<str>> Iban for T {
+    type Error = InvalidIban;
+
+    fn validate_iban(&self) -> Result<(), Self::Error> {
+        parse_iban(self.as_ref())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum InvalidIban {
+    InvalidFormat,
+    UnknownCountry,
+    InvalidLength,
+    InvalidChecksum,
+}
+
+impl Display for InvalidIban {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InvalidIban::InvalidFormat => write!(f, "invalid format"),
+            InvalidIban::UnknownCountry => write!(f, "unknown country code"),
+            InvalidIban::InvalidLength => write!(f, "invalid length"),
+            InvalidIban::InvalidChecksum => write!(f, "invalid checksum"),
+        }
+    }
+}
+
+pub fn parse_iban(s: &str) -> Result<(), InvalidIban> {
+    let iban = s
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .map(|c| c.to_ascii_uppercase())
+        .collect::<String>();
+
+    if !iban.bytes().all(|b| b.is_ascii_alphanumeric()) {
+        return Err(InvalidIban::InvalidFormat);
+    }
+
+    let length = iban
+        .get(..2)
+        .and_then(country_length)
+        .ok_or(InvalidIban::UnknownCountry)?;
+    if iban.len() != length {
+        return Err(InvalidIban::InvalidLength);
+    }
+
+    // `iban` is ASCII-only at this point, so slicing by byte offsets is fine.
+    if !iban[2..4].bytes().all(|b| b.is_ascii_digit()) {
+        return Err(InvalidIban::InvalidFormat);
+    }
+
+    // Move the country code and check digits to the end, replace letters with
+    // two-digit numbers (A = 10, ..., Z = 35), and compute the remainder
+    // incrementally so that the number never has to be materialized.
+    let remainder = iban[4..]
+        .bytes()
+        .chain(iban[..4].bytes())
+        .fold(0u32, |acc, b| {
+            if b.is_ascii_digit() {
+                (acc * 10 + u32::from(b - b'0')) % 97
+            } else {
+                (acc * 100 + u32::from(b - b'A' + 10)) % 97
+            }
+        });
+    if remainder != 1 {
+        return Err(InvalidIban::InvalidChecksum);
+    }
+
+    Ok(())
+}
+
+/// IBAN lengths by country, according to the SWIFT IBAN registry.
+fn country_length(country: &str) -> Option<usize> {
+    let length = match country {
+        "AD" => 24,
+        "AE" => 23,
+        "AL" => 28,
+        "AT" => 20,
+        "AZ" => 28,
+        "BA" => 20,
+        "BE" => 16,
+        "BG" => 22,
+        "BH" => 22,
+        "BI" => 27,
+        "BR" => 29,
+        "BY" => 28,
+        "CH" => 21,
+        "CR" => 22,
+        "CY" => 28,
+        "CZ" => 24,
+        "DE" => 22,
+        "DJ" => 27,
+        "DK" => 18,
+        "DO" => 28,
+        "EE" => 20,
+        "EG" => 29,
+        "ES" => 24,
+        "FI" => 18,
+        "FK" => 18,
+        "FO" => 18,
+        "FR" => 27,
+        "GB" => 22,
+        "GE" => 22,
+        "GI" => 23,
+        "GL" => 18,
+        "GR" => 27,
+        "GT" => 28,
+        "HR" => 21,
+        "HU" => 28,
+        "IE" => 22,
+        "IL" => 23,
+        "IQ" => 23,
+        "IS" => 26,
+        "IT" => 27,
+        "JO" => 30,
+        "KW" => 30,
+        "KZ" => 20,
+        "LB" => 28,
+        "LC" => 32,
+        "LI" => 21,
+        "LT" => 20,
+        "LU" => 20,
+        "LV" => 21,
+        "LY" => 25,
+        "MC" => 27,
+        "MD" => 24,
+        "ME" => 22,
+        "MK" => 19,
+        "MN" => 20,
+        "MR" => 27,
+        "MT" => 31,
+        "MU" => 30,
+        "NI" => 28,
+        "NL" => 18,
+        "NO" => 15,
+        "OM" => 23,
+        "PK" => 24,
+        "PL" => 28,
+        "PS" => 29,
+        "PT" => 25,
+        "QA" => 29,
+        "RO" => 24,
+        "RS" => 22,
+        "RU" => 33,
+        "SA" => 24,
+        "SC" => 31,
+        "SD" => 18,
+        "SE" => 24,
+        "SI" => 19,
+        "SK" => 24,
+        "SM" => 27,
+        "SO" => 23,
+        "ST" => 25,
+        "SV" => 28,
+        "TL" => 23,
+        "TN" => 24,
+        "TR" => 26,
+        "UA" => 29,
+        "VA" => 22,
+        "VG" => 24,
+        "XK" => 20,
+        "YE" => 30,
+        _ => return None,
+    };
+    Some(length)
+}
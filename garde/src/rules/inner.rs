@@ -0,0 +1,156 @@
+//! Validation of the items of a container.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(inner(length(min = 1)))]
+//!     name: Option<String>,
+//!     #[garde(inner(ascii, length(max = 32)))]
+//!     tags: Vec<String>,
+//! }
+//! ```
+//!
+//! The rules inside `inner(...)` are applied to each item of the container. They may be combined with other rules,
+//! which are applied to the container itself.
+//!
+//! An `Option<T>` is treated as a container with zero or one items, so `inner(...)` rules only run if the value is `Some`,
+//! and their errors are reported at the path of the field itself. For all other containers, errors are reported
+//! at the index of the item they were produced for, such as `value.tags[1]`.
+//!
+//! The entrypoint is the [`Inner`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(inner(...))]` rule.
+//!
+//! Any rule may be used inside `inner(...)`, as long as the item type supports it. For example, numeric rules such as
+//! `range` and `multiple_of` may be applied to each item of a `Vec<u8>`:
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(length(max = 10), inner(range(max = 100), multiple_of(5)))]
+//!     scores: Vec<u8>,
+//! }
+//! ```
+//!
+//! An empty container is always valid, as there are no items to validate.
+//!
+//! This trait is implemented for `Option<T>`, `Vec<T>`, `VecDeque<T>`, `LinkedList<T>`, `BinaryHeap<T>`, `BTreeSet<T>`,
+//! `HashSet<T, S>`, `[T]`, and `[T; N]`. Like with `dive`, the indices of items in a set follow its iteration order.
+
+use crate::error::{Errors, SimpleErrorBuilder};
+
+/// Collects the errors produced by `f` for each item of `v`.
+#[doc(hidden)]
+pub fn errors<T, F>(v: &T, mut f: F) -> Errors
+where
+    T: Inner + ?Sized,
+    F: FnMut(&T::Item, &mut SimpleErrorBuilder),
+{
+    if T::INDEXED {
+        Errors::list(|errors| {
+            for item in v.items() {
+                errors.push(Errors::simple(|errors| f(item, errors)));
+            }
+        })
+    } else {
+        Errors::simple(|errors| {
+            for item in v.items() {
+                f(item, errors);
+            }
+        })
+    }
+}
+
+/// Returns the path of the item at `index` in `v`, which is itself at `path`.
+#[doc(hidden)]
+pub fn path<T: Inner + ?Sized>(_: &T, path: &str, index: usize) -> String {
+    if T::INDEXED {
+        format!("{path}[{index}]")
+    } else {
+        String::from(path)
+    }
+}
+
+#[cfg_attr(
+    feature = "nightly-error-messages",
+    rustc_on_unimplemented(
+        message = "`{Self}` does not support inner validation",
+        label = "This type does not support inner validation",
+    )
+)]
+pub trait Inner {
+    type Item;
+
+    /// Whether errors are reported at the index of each item, or at the path of the container itself.
+    const INDEXED: bool;
+
+    fn items(&self) -> Box<dyn Iterator<Item = &Self::Item> + '_>;
+}
+
+impl<T: Inner + ?Sized> Inner for &T {
+    type Item = T::Item;
+
+    const INDEXED: bool = T::INDEXED;
+
+    fn items(&self) -> Box<dyn Iterator<Item = &Self::Item> + '_> {
+        T::items(self)
+    }
+}
+
+impl<T> Inner for Option<T> {
+    type Item = T;
+
+    const INDEXED: bool = false;
+
+    fn items(&self) -> Box<dyn Iterator<Item = &Self::Item> + '_> {
+        Box::new(self.iter())
+    }
+}
+
+impl<T> Inner for [T] {
+    type Item = T;
+
+    const INDEXED: bool = true;
+
+    fn items(&self) -> Box<dyn Iterator<Item = &Self::Item> + '_> {
+        Box::new(self.iter())
+    }
+}
+
+impl<T, const N: usize> Inner for [T; N] {
+    type Item = T;
+
+    const INDEXED: bool = true;
+
+    fn items(&self) -> Box<dyn Iterator<Item = &Self::Item> + '_> {
+        Box::new(self.iter())
+    }
+}
+
+impl<T> Inner for Vec<T> {
+    type Item = T;
+
+    const INDEXED: bool = true;
+
+    fn items(&self) -> Box<dyn Iterator<Item = &Self::Item> + '_> {
+        Box::new(self.iter())
+    }
+}
+
+macro_rules! impl_inner_list {
+    (<$T:ident $(, $Other:ident)*> $Container:ty) => {
+        impl<$T, $($Other),*> Inner for $Container {
+            type Item = $T;
+
+            const INDEXED: bool = true;
+
+            fn items(&self) -> Box<dyn Iterator<Item = &Self::Item> + '_> {
+                Box::new(self.iter())
+            }
+        }
+    };
+}
+
+impl_inner_list!(<T> std::collections::VecDeque<T>);
+impl_inner_list!(<T> std::collections::LinkedList<T>);
+impl_inner_list!(<T> std::collections::BTreeSet<T>);
+impl_inner_list!(<T> std::collections::BinaryHeap<T>);
+impl_inner_list!(<T, S> std::collections::HashSet<T, S>);
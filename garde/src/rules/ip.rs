@@ -11,14 +11,38 @@
 //! The entrypoint is the [`Ip`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(ip)]` rule.
 //!
 //! This trait has a blanket implementation for all `T: AsRef<str>`.
+//!
+//! By default, any address which parses is accepted. The address may be restricted to an [`IpScope`]
+//! using `#[garde(ip(public))]`, `#[garde(ip(private))]`, or `#[garde(ip(loopback))]`, which also works with `ipv4` and `ipv6`:
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(ip(public))]
+//!     v: String,
+//!     #[garde(ipv4(private))]
+//!     w: String,
+//! }
+//! ```
+//!
+//! An address is public if it does not belong to any of the special-purpose ranges listed in [`IpClass`],
+//! similar to the unstable `IpAddr::is_global`. IPv6 addresses which embed an IPv4 address are classified by
+//! the IPv4 address they contain: IPv4-mapped addresses such as `::ffff:10.0.0.1`, IPv4-compatible addresses
+//! such as `::10.0.0.1`, and NAT64 addresses in `64:ff9b::/96` such as `64:ff9b::10.0.0.1`.
 
 use std::fmt::Display;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
 
 use crate::error::Error;
 
-pub fn apply<T: Ip>(v: &T, (kind,): (IpKind,)) -> Result<(), Error> {
-    if v.validate_ip(kind).is_err() {
-        return Err(Error::new(format!("not a valid {kind} address")));
+pub fn apply<T: Ip>(v: &T, (kind, scope): (IpKind, IpScope)) -> Result<(), Error> {
+    if let Err(e) = v.validate_ip(kind, scope) {
+        let code = match kind {
+            IpKind::Any => "ip",
+            IpKind::V4 => "ipv4",
+            IpKind::V6 => "ipv6",
+        };
+        return Err(Error::with_code(code, e.to_string()));
     }
     Ok(())
 }
@@ -33,10 +57,10 @@ pub fn apply<T: Ip>(v: &T, (kind,): (IpKind,)) -> Result<(), Error> {
 pub trait Ip {
     type Error: Display;
 
-    fn validate_ip(&self, kind: IpKind) -> Result<(), Self::Error>;
+    fn validate_ip(&self, kind: IpKind, scope: IpScope) -> Result<(), Self::Error>;
 }
 
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum IpKind {
     Any,
     V4,
@@ -53,22 +77,196 @@ impl Display for IpKind {
     }
 }
 
+/// The kind of address which is accepted.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IpScope {
+    /// Any valid address.
+    Any,
+    /// Addresses classified as [`IpClass::Public`].
+    Public,
+    /// Addresses classified as [`IpClass::Private`].
+    Private,
+    /// Addresses classified as [`IpClass::Loopback`].
+    Loopback,
+}
+
+impl Display for IpScope {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IpScope::Any => write!(f, "valid"),
+            IpScope::Public => write!(f, "public"),
+            IpScope::Private => write!(f, "private"),
+            IpScope::Loopback => write!(f, "loopback"),
+        }
+    }
+}
+
+/// The range an address belongs to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IpClass {
+    /// `0.0.0.0` and `::`.
+    Unspecified,
+    /// `127.0.0.0/8` and `::1`.
+    Loopback,
+    /// `10.0.0.0/8`, `172.16.0.0/12`, `192.168.0.0/16`, and `fc00::/7`.
+    Private,
+    /// `169.254.0.0/16` and `fe80::/10`.
+    LinkLocal,
+    /// `100.64.0.0/10`, used for carrier-grade NAT.
+    Shared,
+    /// `192.0.2.0/24`, `198.51.100.0/24`, `203.0.113.0/24`, and `2001:db8::/32`.
+    Documentation,
+    /// `224.0.0.0/4` and `ff00::/8`.
+    Multicast,
+    /// `255.255.255.255`.
+    Broadcast,
+    /// Other special-purpose ranges, such as `0.0.0.0/8`, `192.0.0.0/24`, `198.18.0.0/15`, `240.0.0.0/4`, `100::/64`,
+    /// and `64:ff9b:1::/48`.
+    Reserved,
+    /// Any other address.
+    Public,
+}
+
+impl IpClass {
+    pub fn of(addr: IpAddr) -> Self {
+        match addr {
+            IpAddr::V4(v4) => Self::of_v4(v4),
+            IpAddr::V6(v6) => Self::of_v6(v6),
+        }
+    }
+
+    pub fn of_v4(addr: Ipv4Addr) -> Self {
+        let [a, b, c, _] = addr.octets();
+        if addr.is_unspecified() {
+            IpClass::Unspecified
+        } else if addr.is_loopback() {
+            IpClass::Loopback
+        } else if addr.is_private() {
+            IpClass::Private
+        } else if addr.is_link_local() {
+            IpClass::LinkLocal
+        } else if a == 100 && (b & 0xc0) == 64 {
+            IpClass::Shared
+        } else if addr.is_documentation() {
+            IpClass::Documentation
+        } else if addr.is_multicast() {
+            IpClass::Multicast
+        } else if addr.is_broadcast() {
+            IpClass::Broadcast
+        } else if a == 0
+            || a >= 240
+            || (a == 192 && b == 0 && c == 0)
+            || (a == 198 && (b & 0xfe) == 18)
+        {
+            IpClass::Reserved
+        } else {
+            IpClass::Public
+        }
+    }
+
+    pub fn of_v6(addr: Ipv6Addr) -> Self {
+        let segments = addr.segments();
+        let [_, _, _, _, _, _, _, _, _, _, _, _, a, b, c, d] = addr.octets();
+        let embedded = Ipv4Addr::new(a, b, c, d);
+        match segments {
+            // IPv4-mapped, e.g. `::ffff:10.0.0.1`
+            [0, 0, 0, 0, 0, 0xffff, _, _] => return Self::of_v4(embedded),
+            // IPv4-compatible, e.g. `::10.0.0.1`, other than `::` and `::1`
+            [0, 0, 0, 0, 0, 0, _, _] if !addr.is_unspecified() && !addr.is_loopback() => {
+                return Self::of_v4(embedded)
+            }
+            // NAT64 well-known prefix, e.g. `64:ff9b::10.0.0.1`
+            [0x64, 0xff9b, 0, 0, 0, 0, _, _] => return Self::of_v4(embedded),
+            _ => {}
+        }
+        if addr.is_unspecified() {
+            IpClass::Unspecified
+        } else if addr.is_loopback() {
+            IpClass::Loopback
+        } else if (segments[0] & 0xfe00) == 0xfc00 {
+            IpClass::Private
+        } else if (segments[0] & 0xffc0) == 0xfe80 {
+            IpClass::LinkLocal
+        } else if segments[0] == 0x2001 && segments[1] == 0xdb8 {
+            IpClass::Documentation
+        } else if addr.is_multicast() {
+            IpClass::Multicast
+        } else if segments[..4] == [0x100, 0, 0, 0] || segments[..3] == [0x64, 0xff9b, 1] {
+            IpClass::Reserved
+        } else {
+            IpClass::Public
+        }
+    }
+
+    fn matches(self, scope: IpScope) -> bool {
+        match scope {
+            IpScope::Any => true,
+            IpScope::Public => self == IpClass::Public,
+            IpScope::Private => self == IpClass::Private,
+            IpScope::Loopback => self == IpClass::Loopback,
+        }
+    }
+}
+
+impl Display for IpClass {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IpClass::Unspecified => write!(f, "an unspecified address"),
+            IpClass::Loopback => write!(f, "a loopback address"),
+            IpClass::Private => write!(f, "a private address"),
+            IpClass::LinkLocal => write!(f, "a link-local address"),
+            IpClass::Shared => write!(f, "a shared address"),
+            IpClass::Documentation => write!(f, "a documentation address"),
+            IpClass::Multicast => write!(f, "a multicast address"),
+            IpClass::Broadcast => write!(f, "a broadcast address"),
+            IpClass::Reserved => write!(f, "a reserved address"),
+            IpClass::Public => write!(f, "a public address"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum InvalidIp {
+    Parse(IpKind),
+    Scope {
+        kind: IpKind,
+        expected: IpScope,
+        actual: IpClass,
+    },
+}
+
+impl Display for InvalidIp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InvalidIp::Parse(kind) => write!(f, "not a valid {kind} address"),
+            InvalidIp::Scope {
+                kind,
+                expected,
+                actual,
+            } => write!(f, "expected a {expected} {kind}, got {actual}"),
+        }
+    }
+}
+
 impl<T: AsRef<str>> Ip for T {
-    type Error = std::net::AddrParseError;
+    type Error = InvalidIp;
 
-    fn validate_ip(&self, kind: IpKind) -> Result<(), Self::Error> {
+    fn validate_ip(&self, kind: IpKind, scope: IpScope) -> Result<(), Self::Error> {
         let v = self.as_ref();
-        match kind {
-            IpKind::Any => {
-                let _ = v.parse::<std::net::IpAddr>()?;
-            }
-            IpKind::V4 => {
-                let _ = v.parse::<std::net::Ipv4Addr>()?;
-            }
-            IpKind::V6 => {
-                let _ = v.parse::<std::net::Ipv6Addr>()?;
-            }
+        let addr = match kind {
+            IpKind::Any => v.parse::<IpAddr>().ok(),
+            IpKind::V4 => v.parse::<Ipv4Addr>().ok().map(IpAddr::V4),
+            IpKind::V6 => v.parse::<Ipv6Addr>().ok().map(IpAddr::V6),
         };
+        let addr = addr.ok_or(InvalidIp::Parse(kind))?;
+        let actual = IpClass::of(addr);
+        if !actual.matches(scope) {
+            return Err(InvalidIp::Scope {
+                kind,
+                expected: scope,
+                actual,
+            });
+        }
         Ok(())
     }
 }
@@ -0,0 +1,137 @@
+//! ISBN validation.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(isbn)]
+//!     v: String,
+//! }
+//! ```
+//!
+//! The entrypoint is the [`Isbn`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(isbn)]` rule.
+//!
+//! This trait has a blanket implementation for all `T: AsRef<str>`.
+//!
+//! Hyphens and spaces are treated as separators and ignored. The value is then checked as an ISBN-10
+//! or an ISBN-13 depending on its length, and must pass the respective checksum.
+//! The check digit of an ISBN-10 may be `X`, which stands for `10`.
+//!
+//! A specific form may be required using the `version` argument:
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(isbn(version = 13))]
+//!     v: String,
+//! }
+//! ```
+
+use std::fmt::Display;
+
+use crate::error::Error;
+
+pub fn apply<T: Isbn>(v: &T, (version,): (Option<usize>,)) -> Result<(), Error> {
+    if let Err(e) = v.validate_isbn(version) {
+        let message = match version {
+            Some(version) => format!("not a valid ISBN-{version}: {e}"),
+            None => format!("not a valid ISBN: {e}"),
+        };
+        return Err(Error::with_code("isbn", message));
+    }
+    Ok(())
+}
+
+#[cfg_attr(
+    feature = "nightly-error-messages",
+    rustc_on_unimplemented(
+        message = "`{Self}` does not support ISBN validation",
+        label = "This type does not support ISBN validation",
+    )
+)]
+pub trait Isbn {
+    type Error: Display;
+
+    /// Validates `self` as an ISBN-10 or ISBN-13, or only as the given `version` if one is specified.
+    fn validate_isbn(&self, version: Option<usize>) -> Result<(), Self::Error>;
+}
+
+impl<T: AsRef<str>> Isbn for T {
+    type Error = InvalidIsbn;
+
+    fn validate_isbn(&self, version: Option<usize>) -> Result<(), Self::Error> {
+        parse_isbn(self.as_ref(), version)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum InvalidIsbn {
+    InvalidCharacter,
+    InvalidLength,
+    InvalidChecksum,
+}
+
+impl Display for InvalidIsbn {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InvalidIsbn::InvalidCharacter => write!(f, "invalid character"),
+            InvalidIsbn::InvalidLength => write!(f, "invalid length"),
+            InvalidIsbn::InvalidChecksum => write!(f, "invalid checksum"),
+        }
+    }
+}
+
+pub fn parse_isbn(s: &str, version: Option<usize>) -> Result<(), InvalidIsbn> {
+    let chars = s
+        .chars()
+        .filter(|c| *c != '-' && *c != ' ')
+        .collect::<Vec<_>>();
+
+    // `X` is only allowed as the check digit, which is validated below
+    let digits = chars
+        .iter()
+        .enumerate()
+        .map(|(i, c)| match c {
+            '0'..='9' => Ok(*c as u32 - '0' as u32),
+            'X' | 'x' if i + 1 == chars.len() => Ok(10),
+            _ => Err(InvalidIsbn::InvalidCharacter),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if !matches!(version, Some(v) if v != digits.len()) {
+        match digits.len() {
+            10 => return check_isbn10(&digits),
+            13 => return check_isbn13(&digits),
+            _ => {}
+        }
+    }
+    Err(InvalidIsbn::InvalidLength)
+}
+
+fn check_isbn10(digits: &[u32]) -> Result<(), InvalidIsbn> {
+    // weights go from 10 down to 1, the check digit included
+    let sum = digits
+        .iter()
+        .zip((1..=10).rev())
+        .map(|(digit, weight)| digit * weight)
+        .sum::<u32>();
+    if sum % 11 != 0 {
+        return Err(InvalidIsbn::InvalidChecksum);
+    }
+    Ok(())
+}
+
+fn check_isbn13(digits: &[u32]) -> Result<(), InvalidIsbn> {
+    if digits[12] == 10 {
+        return Err(InvalidIsbn::InvalidCharacter);
+    }
+    // weights alternate between 1 and 3, the check digit included
+    let sum = digits
+        .iter()
+        .zip([1, 3].iter().cycle())
+        .map(|(digit, weight)| digit * weight)
+        .sum::<u32>();
+    if sum % 10 != 0 {
+        return Err(InvalidIsbn::InvalidChecksum);
+    }
+    Ok(())
+}
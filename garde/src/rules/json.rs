@@ -0,0 +1,48 @@
+//! JSON validation using the [`serde_json`] crate.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(json)]
+//!     v: String,
+//! }
+//! ```
+//!
+//! The entrypoint is the [`Json`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(json)]` rule.
+//!
+//! This trait has a blanket implementation for all `T: AsRef<str>`.
+//!
+//! Any JSON value is accepted, including arrays and scalars such as `null` or `1`.
+
+use std::fmt::Display;
+
+use crate::error::Error;
+
+pub fn apply<T: Json>(v: &T, _: ()) -> Result<(), Error> {
+    if let Err(e) = v.validate_json() {
+        return Err(Error::with_code("json", format!("not valid json: {e}")));
+    }
+    Ok(())
+}
+
+#[cfg_attr(
+    feature = "nightly-error-messages",
+    rustc_on_unimplemented(
+        message = "`{Self}` does not support JSON validation",
+        label = "This type does not support JSON validation",
+    )
+)]
+pub trait Json {
+    type Error: Display;
+
+    fn validate_json(&self) -> Result<(), Self::Error>;
+}
+
+impl<T: AsRef<str>> Json for T {
+    type Error = serde_json::Error;
+
+    fn validate_json(&self) -> Result<(), Self::Error> {
+        let _ = serde_json::from_str::<serde_json::Value>(self.as_ref())?;
+        Ok(())
+    }
+}
@@ -0,0 +1,73 @@
+//! Key validation.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(keys(ascii, length(max = 64)))]
+//!     v: std::collections::HashMap<String, String>,
+//! }
+//! ```
+//!
+//! The rules inside `keys(...)` are applied to each key of the map. They may be combined with `dive`,
+//! which validates the values of the map.
+//!
+//! Errors produced by key rules are reported at the path of the key, e.g. `value.names[abcd]`, along with any errors
+//! of the value for that key. Keys are displayed using their [`Display`] implementation, which means they may be of any type,
+//! as long as the key rules support that type.
+//!
+//! The entrypoint is the [`Keys`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(keys(...))]` rule.
+//!
+//! This trait is implemented for `HashMap<K, V, S>` and `BTreeMap<K, V>` where `K: Display`.
+
+use std::fmt::Display;
+
+use crate::error::{Errors, SimpleErrorBuilder};
+
+/// Collects the errors produced by `f` for each key of `v`, at the path of that key.
+#[doc(hidden)]
+pub fn errors<T, F>(v: &T, mut f: F) -> Errors
+where
+    T: Keys + ?Sized,
+    F: FnMut(&T::Key, &mut SimpleErrorBuilder),
+{
+    Errors::map(|errors| {
+        for key in v.keys() {
+            errors.insert(key, Errors::simple(|errors| f(key, errors)));
+        }
+    })
+}
+
+/// Returns the path of `key` in the map at `path`.
+#[doc(hidden)]
+pub fn path<K: Display + ?Sized>(path: &str, key: &K) -> String {
+    format!("{path}[{key}]")
+}
+
+#[cfg_attr(
+    feature = "nightly-error-messages",
+    rustc_on_unimplemented(
+        message = "`{Self}` does not support key validation",
+        label = "This type does not support key validation",
+    )
+)]
+pub trait Keys {
+    type Key: Display;
+
+    fn keys(&self) -> Box<dyn Iterator<Item = &Self::Key> + '_>;
+}
+
+impl<K: Display, V, S> Keys for std::collections::HashMap<K, V, S> {
+    type Key = K;
+
+    fn keys(&self) -> Box<dyn Iterator<Item = &Self::Key> + '_> {
+        Box::new(std::collections::HashMap::keys(self))
+    }
+}
+
+impl<K: Display, V> Keys for std::collections::BTreeMap<K, V> {
+    type Key = K;
+
+    fn keys(&self) -> Box<dyn Iterator<Item = &Self::Key> + '_> {
+        Box::new(std::collections::BTreeMap::keys(self))
+    }
+}
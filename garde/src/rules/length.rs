@@ -14,7 +14,19 @@
 //! [`Length`] is implemented for any `T: HasLength`.
 //!
 //! In case of string types, [`HasLength::length`] should return the number of _characters_ as opposed to the number of _bytes_.
-//! For validation of length counted in _bytes_, see the [`crate::rules::byte_length`] rule.
+//! For validation of length counted in _bytes_, see the [`crate::rules::byte_length`] rule,
+//! which may also be spelled `#[garde(length(bytes, ...))]`. The default unit may be spelled out
+//! as `#[garde(length(chars, ...))]`.
+//!
+//! An exact length may be required using `equal`, which may not be combined with `min` or `max`:
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(length(equal = 6))]
+//!     v: String,
+//! }
+//! ```
 //!
 //! Here's what implementing the trait for a custom string-like type might look like:
 //! ```rust
@@ -31,11 +43,29 @@
 
 use crate::error::Error;
 
-pub fn apply<T: Length>(v: &T, (min, max): (usize, usize)) -> Result<(), Error> {
+/// `exact` is set by `equal = ...`, in which case `min` and `max` are the same
+/// and the error reports the exact length which was expected.
+pub fn apply<T: Length>(v: &T, (min, max, exact): (usize, usize, bool)) -> Result<(), Error> {
     if let Err(e) = v.validate_length(min, max) {
+        if exact {
+            return Err(Error::with_code(
+                "length.equal",
+                format!("length is not exactly {min}"),
+            ));
+        }
         match e {
-            InvalidLength::Min => return Err(Error::new(format!("length is lower than {min}"))),
-            InvalidLength::Max => return Err(Error::new(format!("length is greater than {max}"))),
+            InvalidLength::Min => {
+                return Err(Error::with_code(
+                    "length.min",
+                    format!("length is lower than {min}"),
+                ))
+            }
+            InvalidLength::Max => {
+                return Err(Error::with_code(
+                    "length.max",
+                    format!("length is greater than {max}"),
+                ))
+            }
         }
     }
     Ok(())
@@ -82,13 +112,13 @@ impl HasLength for String {
     }
 }
 
-impl<'a> HasLength for &'a String {
+impl HasLength for &String {
     fn length(&self) -> usize {
         self.chars().count()
     }
 }
 
-impl<'a> HasLength for &'a str {
+impl HasLength for &str {
     fn length(&self) -> usize {
         self.chars().count()
     }
@@ -106,7 +136,7 @@ impl<T> HasLength for Vec<T> {
     }
 }
 
-impl<'a, T> HasLength for &'a Vec<T> {
+impl<T> HasLength for &Vec<T> {
     fn length(&self) -> usize {
         self.len()
     }
@@ -130,7 +160,7 @@ impl<T, const N: usize> HasLength for &[T; N] {
     }
 }
 
-impl<'a, K, V, S> HasLength for &'a std::collections::HashMap<K, V, S> {
+impl<K, V, S> HasLength for &std::collections::HashMap<K, V, S> {
     fn length(&self) -> usize {
         self.len()
     }
@@ -142,7 +172,7 @@ impl<K, V, S> HasLength for std::collections::HashMap<K, V, S> {
     }
 }
 
-impl<'a, T, S> HasLength for &'a std::collections::HashSet<T, S> {
+impl<T, S> HasLength for &std::collections::HashSet<T, S> {
     fn length(&self) -> usize {
         self.len()
     }
@@ -154,7 +184,7 @@ impl<T, S> HasLength for std::collections::HashSet<T, S> {
     }
 }
 
-impl<'a, K, V> HasLength for &'a std::collections::BTreeMap<K, V> {
+impl<K, V> HasLength for &std::collections::BTreeMap<K, V> {
     fn length(&self) -> usize {
         self.len()
     }
@@ -166,7 +196,7 @@ impl<K, V> HasLength for std::collections::BTreeMap<K, V> {
     }
 }
 
-impl<'a, T> HasLength for &'a std::collections::BTreeSet<T> {
+impl<T> HasLength for &std::collections::BTreeSet<T> {
     fn length(&self) -> usize {
         self.len()
     }
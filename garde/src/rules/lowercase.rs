@@ -0,0 +1,46 @@
+//! Lowercase validation.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(lowercase)]
+//!     v: String,
+//! }
+//! ```
+//!
+//! The entrypoint is the [`Lowercase`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(lowercase)]` rule.
+//!
+//! This trait has a blanket implementation for all `T: AsRef<str>`.
+//!
+//! A value is lowercase if it is equal to its [`str::to_lowercase`] mapping.
+//! Characters without case, such as digits and punctuation, are accepted.
+//!
+//! Some characters map to more than one character, and are only accepted if the mapping is identical.
+//! For example, `ß` is lowercase, while `İ` (`U+0130`) is not, because it lowercases to `i̇` (`i` followed by `U+0307`).
+
+use crate::error::Error;
+
+pub fn apply<T: Lowercase>(v: &T, _: ()) -> Result<(), Error> {
+    if !v.validate_lowercase() {
+        return Err(Error::with_code("lowercase", "not lowercase"));
+    }
+    Ok(())
+}
+
+#[cfg_attr(
+    feature = "nightly-error-messages",
+    rustc_on_unimplemented(
+        message = "`{Self}` does not support lowercase validation",
+        label = "This type does not support lowercase validation",
+    )
+)]
+pub trait Lowercase {
+    fn validate_lowercase(&self) -> bool;
+}
+
+impl<T: AsRef<str>> Lowercase for T {
+    fn validate_lowercase(&self) -> bool {
+        let v = self.as_ref();
+        v.chars().flat_map(char::to_lowercase).eq(v.chars())
+    }
+}
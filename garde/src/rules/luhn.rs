@@ -0,0 +1,91 @@
+//! Luhn checksum validation.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(luhn)]
+//!     v: String,
+//! }
+//! ```
+//!
+//! The entrypoint is the [`Luhn`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(luhn)]` rule.
+//!
+//! This trait has a blanket implementation for all `T: AsRef<str>`.
+//!
+//! Spaces and dashes are treated as separators and ignored when computing the checksum.
+//! Unlike [`crate::rules::credit_card`], this rule does not check the length or try to detect the type of the value.
+
+use std::fmt::Display;
+
+use crate::error::Error;
+
+pub fn apply<T: Luhn>(v: &T, _: ()) -> Result<(), Error> {
+    if let Err(e) = v.validate_luhn() {
+        return Err(Error::with_code(
+            "luhn",
+            format!("not a valid luhn number: {e}"),
+        ));
+    }
+    Ok(())
+}
+
+#[cfg_attr(
+    feature = "nightly-error-messages",
+    rustc_on_unimplemented(
+        message = "`{Self}` does not support luhn validation",
+        label = "This type does not support luhn validation",
+    )
+)]
+pub trait Luhn {
+    type Error: Display;
+
+    fn validate_luhn(&self) -> Result<(), Self::Error>;
+}
+
+impl<T: AsRef<str>> Luhn for T {
+    type Error = InvalidLuhn;
+
+    fn validate_luhn(&self) -> Result<(), Self::Error> {
+        let mut sum = 0u64;
+        let mut count = 0usize;
+        for c in self.as_ref().chars().rev() {
+            if c == ' ' || c == '-' {
+                continue;
+            }
+            let mut digit = c.to_digit(10).ok_or(InvalidLuhn::InvalidFormat)?;
+            if count % 2 == 1 {
+                digit *= 2;
+                if digit > 9 {
+                    digit -= 9;
+                }
+            }
+            sum += digit as u64;
+            count += 1;
+        }
+
+        if count == 0 {
+            return Err(InvalidLuhn::Empty);
+        }
+        if sum % 10 != 0 {
+            return Err(InvalidLuhn::InvalidChecksum);
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum InvalidLuhn {
+    Empty,
+    InvalidFormat,
+    InvalidChecksum,
+}
+
+impl Display for InvalidLuhn {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InvalidLuhn::Empty => write!(f, "value is empty"),
+            InvalidLuhn::InvalidFormat => write!(f, "value contains non-digit characters"),
+            InvalidLuhn::InvalidChecksum => write!(f, "invalid luhn"),
+        }
+    }
+}
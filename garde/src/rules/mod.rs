@@ -2,20 +2,51 @@
 
 pub mod alphanumeric;
 pub mod ascii;
+pub mod ascii_bytes;
+#[cfg(feature = "base64")]
+pub mod base64;
 pub mod byte_length;
+pub mod compare;
 pub mod contains;
 #[cfg(feature = "credit-card")]
 pub mod credit_card;
+#[cfg(feature = "chrono")]
+pub mod datetime;
 #[cfg(feature = "email")]
 pub mod email;
+#[cfg(feature = "graphemes")]
+pub mod grapheme_length;
+pub mod hex_color;
+#[cfg(feature = "iban")]
+pub mod iban;
+pub mod inner;
 pub mod ip;
+pub mod isbn;
+#[cfg(feature = "json")]
+pub mod json;
+pub mod keys;
 pub mod length;
+pub mod lowercase;
+pub mod luhn;
+pub mod multiple_of;
+pub mod non_control;
+pub mod not_contains;
+pub mod one_of;
 #[cfg(feature = "pattern")]
 pub mod pattern;
 #[cfg(feature = "phone-number")]
 pub mod phone_number;
+pub mod port;
 pub mod prefix;
 pub mod range;
+pub mod required;
+#[cfg(feature = "semver")]
+pub mod semver;
+pub mod split;
 pub mod suffix;
+pub mod trimmed;
+pub mod uppercase;
 #[cfg(feature = "url")]
 pub mod url;
+#[cfg(feature = "uuid")]
+pub mod uuid;
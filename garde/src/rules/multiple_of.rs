@@ -0,0 +1,72 @@
+//! Divisibility validation.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(multiple_of(12))]
+//!     v: u64,
+//! }
+//! ```
+//!
+//! The entrypoint is the [`MultipleOf`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(multiple_of(...))]` rule.
+//!
+//! This trait is implemented for all primitive integer types.
+//!
+//! A literal `0` divisor is rejected at compile time. Any other divisor which evaluates to zero fails validation.
+
+use std::fmt::Display;
+
+use crate::error::Error;
+
+pub fn apply<T: MultipleOf>(v: &T, (divisor,): (&T,)) -> Result<(), Error> {
+    if let Err(e) = v.validate_multiple_of(divisor) {
+        match e {
+            NotMultipleOf::ZeroDivisor => {
+                return Err(Error::with_code("multiple_of", "divisor must not be zero"));
+            }
+            NotMultipleOf::Remainder => {
+                return Err(Error::with_code(
+                    "multiple_of",
+                    format!("not a multiple of {divisor}"),
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg_attr(
+    feature = "nightly-error-messages",
+    rustc_on_unimplemented(
+        message = "`{Self}` does not support divisibility validation",
+        label = "This type does not support divisibility validation",
+        note = "try implementing `garde::rules::multiple_of::MultipleOf` for `{Self}`"
+    )
+)]
+pub trait MultipleOf: Display {
+    fn validate_multiple_of(&self, divisor: &Self) -> Result<(), NotMultipleOf>;
+}
+
+pub enum NotMultipleOf {
+    ZeroDivisor,
+    Remainder,
+}
+
+macro_rules! impl_for_int {
+    ($($T:ident),*) => {
+        $(
+            impl MultipleOf for $T {
+                fn validate_multiple_of(&self, divisor: &Self) -> Result<(), NotMultipleOf> {
+                    // `checked_rem` also guards against `MIN % -1`, which overflows
+                    match self.checked_rem(*divisor) {
+                        None if *divisor == 0 => Err(NotMultipleOf::ZeroDivisor),
+                        None | Some(0) => Ok(()),
+                        Some(_) => Err(NotMultipleOf::Remainder),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_for_int!(u8, u16, u32, u64, usize, u128, i8, i16, i32, i64, isize, i128);
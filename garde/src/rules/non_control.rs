@@ -0,0 +1,82 @@
+//! Control character validation.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(non_control)]
+//!     v: String,
+//! }
+//! ```
+//!
+//! The entrypoint is the [`NonControl`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(non_control)]` rule.
+//!
+//! This trait has a blanket implementation for all `T: AsRef<str>`.
+//!
+//! Control characters are defined the same way as in [`char::is_control`].
+//! Control characters which are also whitespace, such as `\n` and `\t`, may be allowed using `#[garde(non_control(allow_whitespace))]`:
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(non_control(allow_whitespace))]
+//!     v: String,
+//! }
+//! ```
+
+use std::fmt::Display;
+
+use crate::error::Error;
+
+pub fn apply<T: NonControl>(v: &T, (allow_whitespace,): (bool,)) -> Result<(), Error> {
+    if let Err(e) = v.validate_non_control(allow_whitespace) {
+        return Err(Error::with_code("non_control", e.to_string()));
+    }
+    Ok(())
+}
+
+#[cfg_attr(
+    feature = "nightly-error-messages",
+    rustc_on_unimplemented(
+        message = "`{Self}` does not support control character validation",
+        label = "This type does not support control character validation",
+    )
+)]
+pub trait NonControl {
+    type Error: Display;
+
+    fn validate_non_control(&self, allow_whitespace: bool) -> Result<(), Self::Error>;
+}
+
+impl<T: AsRef<str>> NonControl for T {
+    type Error = ControlCharacter;
+
+    fn validate_non_control(&self, allow_whitespace: bool) -> Result<(), Self::Error> {
+        let control = self
+            .as_ref()
+            .char_indices()
+            .find(|(_, c)| c.is_control() && !(allow_whitespace && c.is_whitespace()));
+        match control {
+            Some((offset, character)) => Err(ControlCharacter { offset, character }),
+            None => Ok(()),
+        }
+    }
+}
+
+/// The first control character found in a value.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ControlCharacter {
+    /// The byte offset of the character.
+    pub offset: usize,
+    pub character: char,
+}
+
+impl Display for ControlCharacter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "contains control character U+{:04X} at offset {}",
+            u32::from(self.character),
+            self.offset
+        )
+    }
+}
@@ -0,0 +1,75 @@
+//! Forbidden substring validation.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(not_contains("admin", "root"))]
+//!     v: String,
+//! }
+//! ```
+//!
+//! The value must not contain any of the given substrings. The error names the first one which was found, in the order they were given.
+//!
+//! The entrypoint is the [`NotContains`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(not_contains)]` rule.
+//!
+//! This trait has a blanket implementation for all `T: AsRef<str>`.
+//!
+//! The match is case-sensitive by default. It may be made case-insensitive using `insensitive`, like for `contains`:
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(not_contains("admin", "root", insensitive))]
+//!     v: String,
+//! }
+//! ```
+
+use crate::error::Error;
+
+pub fn apply<T: NotContains>(v: &T, (pats, insensitive): (&[&str], bool)) -> Result<(), Error> {
+    let found = pats.iter().find(|pat| {
+        if insensitive {
+            !v.validate_not_contains_insensitive(pat)
+        } else {
+            !v.validate_not_contains(pat)
+        }
+    });
+    if let Some(pat) = found {
+        return Err(Error::with_code(
+            "not_contains",
+            format!("contains forbidden \"{pat}\""),
+        ));
+    }
+    Ok(())
+}
+
+#[cfg_attr(
+    feature = "nightly-error-messages",
+    rustc_on_unimplemented(
+        message = "`{Self}` does not support forbidden substring validation",
+        label = "This type does not support forbidden substring validation",
+    )
+)]
+pub trait NotContains {
+    fn validate_not_contains(&self, pat: &str) -> bool;
+
+    /// Used by `not_contains(..., insensitive)`.
+    ///
+    /// The default implementation falls back to the case-sensitive [`NotContains::validate_not_contains`].
+    fn validate_not_contains_insensitive(&self, pat: &str) -> bool {
+        self.validate_not_contains(pat)
+    }
+}
+
+impl<T: AsRef<str>> NotContains for T {
+    fn validate_not_contains(&self, pat: &str) -> bool {
+        !self.as_ref().contains(pat)
+    }
+
+    fn validate_not_contains_insensitive(&self, pat: &str) -> bool {
+        !self
+            .as_ref()
+            .to_lowercase()
+            .contains(pat.to_lowercase().as_str())
+    }
+}
@@ -0,0 +1,120 @@
+//! Allowed value validation.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(one_of("draft", "published", "archived"))]
+//!     status: String,
+//!     #[garde(one_of(1, 2, 3))]
+//!     level: u8,
+//! }
+//! ```
+//!
+//! The value must be equal to one of the given values, which must either all be string literals or all be integer literals.
+//!
+//! The entrypoint is the [`OneOf`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(one_of(...))]` rule.
+//!
+//! This trait is implemented for `str`, `String`, `Cow<str>`, `Box<str>`, `Rc<str>`, and `Arc<str>`,
+//! as well as for all primitive integer types. It is also implemented for any reference to a type which implements [`OneOf`].
+//!
+//! Strings are compared case-sensitively by default. The comparison may be made case-insensitive using `insensitive`:
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(one_of("draft", "published", insensitive))]
+//!     status: String,
+//! }
+//! ```
+//!
+//! Like `contains`, case-insensitive matching lowercases both the value and the allowed values using [`str::to_lowercase`].
+
+use std::borrow::Cow;
+use std::fmt::Debug;
+use std::rc::Rc;
+use std::sync::Arc;
+
+use crate::error::Error;
+
+pub fn apply<T: OneOf>(v: &T, (values, insensitive): (&[T::Value], bool)) -> Result<(), Error> {
+    if !v.validate_one_of(values, insensitive) {
+        return Err(Error::with_code(
+            "one_of",
+            format!(
+                "value is not one of {}",
+                values
+                    .iter()
+                    .map(|value| format!("{value:?}"))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        ));
+    }
+    Ok(())
+}
+
+#[cfg_attr(
+    feature = "nightly-error-messages",
+    rustc_on_unimplemented(
+        message = "`{Self}` does not support allowed value validation",
+        label = "This type does not support allowed value validation",
+    )
+)]
+pub trait OneOf {
+    /// The type of the allowed values, which are reported in the error using their `Debug` representation.
+    type Value: Debug;
+
+    /// Checks that `self` is equal to one of `values`.
+    ///
+    /// `insensitive` is only `true` for string values.
+    fn validate_one_of(&self, values: &[Self::Value], insensitive: bool) -> bool;
+}
+
+impl<T: OneOf + ?Sized> OneOf for &T {
+    type Value = T::Value;
+
+    fn validate_one_of(&self, values: &[Self::Value], insensitive: bool) -> bool {
+        T::validate_one_of(self, values, insensitive)
+    }
+}
+
+fn validate_str(v: &str, values: &[&str], insensitive: bool) -> bool {
+    if insensitive {
+        let v = v.to_lowercase();
+        values.iter().any(|value| value.to_lowercase() == v)
+    } else {
+        values.contains(&v)
+    }
+}
+
+macro_rules! impl_str {
+    ($($T:ty),*) => {
+        $(
+            impl OneOf for $T {
+                type Value = &'static str;
+
+                fn validate_one_of(&self, values: &[Self::Value], insensitive: bool) -> bool {
+                    validate_str(self, values, insensitive)
+                }
+            }
+        )*
+    };
+}
+
+impl_str!(str, String, Cow<'_, str>, Box<str>, Rc<str>, Arc<str>);
+
+macro_rules! impl_int {
+    ($($T:ty),*) => {
+        $(
+            impl OneOf for $T {
+                type Value = $T;
+
+                fn validate_one_of(&self, values: &[Self::Value], _: bool) -> bool {
+                    values.contains(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_int!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
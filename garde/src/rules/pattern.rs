@@ -11,12 +11,38 @@
 //! The entrypoint is the [`Pattern`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(pattern(...))]` rule.
 //!
 //! This trait has a blanket implementation for all `T: AsRef<str>`.
+//!
+//! A string literal is compiled once, on first use. For regexes which are built at runtime, or shared between
+//! many fields, the argument may instead be the path to a function returning a compiled [`regex::Regex`]:
+//!
+//! ```rust
+//! use once_cell::sync::Lazy;
+//! use regex::Regex;
+//!
+//! static USERNAME: Lazy<Regex> = Lazy::new(|| Regex::new(r"^[a-z][a-z0-9_]*$").unwrap());
+//!
+//! fn username() -> &'static Regex {
+//!     &USERNAME
+//! }
+//!
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(pattern(username))]
+//!     v: String,
+//! }
+//! ```
+//!
+//! The function is called every time the rule is validated, so it should not compile the regex itself.
+//! Unlike a string literal, the regex is not checked at compile time.
 
 use crate::error::Error;
 
 pub fn apply<T: Pattern>(v: &T, (pat,): (&regex::Regex,)) -> Result<(), Error> {
     if !v.validate_pattern(pat) {
-        return Err(Error::new(format!("does not match pattern /{pat}/")));
+        return Err(Error::with_code(
+            "pattern",
+            format!("does not match pattern /{pat}/"),
+        ));
     }
     Ok(())
 }
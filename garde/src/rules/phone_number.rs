@@ -11,15 +11,31 @@
 //! The entrypoint is the [`PhoneNumber`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(phone_number)]` rule.
 //!
 //! This trait has a blanket implementation for all `T: AsRef<str>`.
+//!
+//! The number may be required to belong to a specific region using the `region` argument,
+//! which accepts any [`Region`] as an ISO 3166-1 alpha-2 country code:
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(phone_number(region = "FR"))]
+//!     v: String,
+//! }
+//! ```
+//!
+//! The region of a number is determined from its country calling code and, for codes shared by multiple regions
+//! (such as `+1`), from the number itself.
 
 use std::fmt::Display;
 use std::str::FromStr;
 
+pub use phonenumber::country::Id as Region;
+
 use crate::error::Error;
 
-pub fn apply<T: PhoneNumber>(v: &T, _: ()) -> Result<(), Error> {
-    if let Err(e) = v.validate_phone_number() {
-        return Err(Error::new(format!("not a valid phone number: {e}")));
+pub fn apply<T: PhoneNumber>(v: &T, (region,): (Option<Region>,)) -> Result<(), Error> {
+    if let Err(e) = v.validate_phone_number(region) {
+        return Err(Error::with_code("phone_number", e.to_string()));
     }
     Ok(())
 }
@@ -34,14 +50,37 @@ pub fn apply<T: PhoneNumber>(v: &T, _: ()) -> Result<(), Error> {
 pub trait PhoneNumber {
     type Error: Display;
 
-    fn validate_phone_number(&self) -> Result<(), Self::Error>;
+    fn validate_phone_number(&self, region: Option<Region>) -> Result<(), Self::Error>;
 }
 
 impl<T: AsRef<str>> PhoneNumber for T {
-    type Error = phonenumber::ParseError;
+    type Error = InvalidPhoneNumber;
 
-    fn validate_phone_number(&self) -> Result<(), Self::Error> {
-        let _ = phonenumber::PhoneNumber::from_str(self.as_ref())?;
+    fn validate_phone_number(&self, region: Option<Region>) -> Result<(), Self::Error> {
+        let number =
+            phonenumber::PhoneNumber::from_str(self.as_ref()).map_err(InvalidPhoneNumber::Parse)?;
+        if let Some(region) = region {
+            if number.country().id() != Some(region) {
+                return Err(InvalidPhoneNumber::Region(region));
+            }
+        }
         Ok(())
     }
 }
+
+#[derive(Debug, Clone)]
+pub enum InvalidPhoneNumber {
+    Parse(phonenumber::ParseError),
+    Region(Region),
+}
+
+impl Display for InvalidPhoneNumber {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InvalidPhoneNumber::Parse(e) => write!(f, "not a valid phone number: {e}"),
+            InvalidPhoneNumber::Region(region) => {
+                write!(f, "phone number is not a valid {} number", region.as_ref())
+            }
+        }
+    }
+}
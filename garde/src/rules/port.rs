@@ -0,0 +1,114 @@
+//! Network port validation.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(port)]
+//!     v: String,
+//!     #[garde(port(privileged = false))]
+//!     n: u32,
+//! }
+//! ```
+//!
+//! The entrypoint is the [`Port`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(port)]` rule.
+//!
+//! This trait is implemented for `str`, `String`, `Cow<str>`, `Box<str>`, `Rc<str>`, and `Arc<str>`,
+//! as well as for all primitive integer types. It is also implemented for any reference to a type which implements [`Port`].
+//!
+//! The value must be a port in the range `1..=65535`. Strings must consist of ASCII digits only, so signs and whitespace are rejected.
+//! Ports below 1024, which usually require elevated privileges to bind to, may be rejected using `#[garde(port(privileged = false))]`.
+
+use std::borrow::Cow;
+use std::fmt::Display;
+use std::rc::Rc;
+use std::sync::Arc;
+
+use crate::error::Error;
+
+pub fn apply<T: Port>(v: &T, (privileged,): (bool,)) -> Result<(), Error> {
+    if let Err(e) = v.validate_port(privileged) {
+        return Err(Error::with_code("port", format!("not a valid port: {e}")));
+    }
+    Ok(())
+}
+
+#[cfg_attr(
+    feature = "nightly-error-messages",
+    rustc_on_unimplemented(
+        message = "`{Self}` does not support port validation",
+        label = "This type does not support port validation",
+    )
+)]
+pub trait Port {
+    fn validate_port(&self, privileged: bool) -> Result<(), InvalidPort>;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum InvalidPort {
+    NotANumber,
+    OutOfRange,
+    Privileged { port: u16 },
+}
+
+impl Display for InvalidPort {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InvalidPort::NotANumber => write!(f, "not a number"),
+            InvalidPort::OutOfRange => write!(f, "out of range, expected 1 to 65535"),
+            InvalidPort::Privileged { port } => write!(
+                f,
+                "privileged port {port} is not allowed, expected 1024 to 65535"
+            ),
+        }
+    }
+}
+
+fn validate_number(port: Option<u16>, privileged: bool) -> Result<(), InvalidPort> {
+    match port {
+        None | Some(0) => Err(InvalidPort::OutOfRange),
+        Some(port) if port < 1024 && !privileged => Err(InvalidPort::Privileged { port }),
+        Some(_) => Ok(()),
+    }
+}
+
+fn validate_str(v: &str, privileged: bool) -> Result<(), InvalidPort> {
+    if v.is_empty() || !v.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(InvalidPort::NotANumber);
+    }
+    // the only possible error is overflow, as `v` contains only digits
+    validate_number(v.parse().ok(), privileged)
+}
+
+impl<T: Port + ?Sized> Port for &T {
+    fn validate_port(&self, privileged: bool) -> Result<(), InvalidPort> {
+        T::validate_port(self, privileged)
+    }
+}
+
+macro_rules! impl_str {
+    ($($T:ty),*) => {
+        $(
+            impl Port for $T {
+                fn validate_port(&self, privileged: bool) -> Result<(), InvalidPort> {
+                    validate_str(self, privileged)
+                }
+            }
+        )*
+    };
+}
+
+impl_str!(str, String, Cow<'_, str>, Box<str>, Rc<str>, Arc<str>);
+
+macro_rules! impl_int {
+    ($($T:ty),*) => {
+        $(
+            impl Port for $T {
+                fn validate_port(&self, privileged: bool) -> Result<(), InvalidPort> {
+                    validate_number(u16::try_from(*self).ok(), privileged)
+                }
+            }
+        )*
+    };
+}
+
+impl_int!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
@@ -11,12 +11,32 @@
 //! The entrypoint is the [`Prefix`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(prefix)]` rule.
 //!
 //! This trait has a blanket implementation for all `T: AsRef<str>`.
+//!
+//! Several alternatives may be given, in which case the value must begin with at least one of them:
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(prefix("tel:", "sms:"))]
+//!     v: String,
+//! }
+//! ```
 
 use crate::error::Error;
 
-pub fn apply<T: Prefix>(v: &T, (pat,): (&str,)) -> Result<(), Error> {
-    if !v.validate_prefix(pat) {
-        return Err(Error::new(format!("value does not begin with \"{pat}\"")));
+pub fn apply<T: Prefix>(v: &T, (pats,): (&[&str],)) -> Result<(), Error> {
+    if !pats.iter().any(|pat| v.validate_prefix(pat)) {
+        let message = match pats {
+            [pat] => format!("value does not begin with \"{pat}\""),
+            pats => format!(
+                "value does not begin with any of {}",
+                pats.iter()
+                    .map(|pat| format!("\"{pat}\""))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        };
+        return Err(Error::with_code("prefix", message));
     }
     Ok(())
 }
@@ -8,20 +8,69 @@
 //! }
 //! ```
 //!
+//! Both `min` and `max` are inclusive. Either of them may be replaced by `exclusive_min` or `exclusive_max`,
+//! which are exclusive:
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(range(min=0.0, exclusive_max=1.0))]
+//!     v: f64,
+//! }
+//! ```
+//!
+//! If both bounds are numeric literals, the derive macro rejects ranges which contain no values.
+//! Otherwise, such a range is not detected, and validation always fails.
+//!
 //! The entrypoint is the [`Bounds`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(range(...))]` rule.
 //!
-//! This trait is implemented for all primitive integer types.
+//! This trait has a blanket implementation for all `T: PartialOrd + Display`, so it is not limited to primitive numbers.
+//! The bounds must have the same type as the value:
+//!
+//! ```rust
+//! #[derive(PartialEq, PartialOrd)]
+//! struct Money(u64);
+//!
+//! impl std::fmt::Display for Money {
+//!     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+//!         write!(f, "${}", self.0)
+//!     }
+//! }
+//!
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(range(min = Money(1), max = Money(100)))]
+//!     v: Money,
+//! }
+//! ```
+//!
+//! Values which cannot be compared to the bounds, such as floating point `NaN`, are always out of bounds.
 
 use std::fmt::Display;
+use std::ops::Bound;
 
 use crate::error::Error;
 
-pub fn apply<T: Bounds>(v: &T, (min, max): (&T, &T)) -> Result<(), Error> {
+pub fn apply<T: Bounds>(v: &T, (min, max): (Bound<&T>, Bound<&T>)) -> Result<(), Error> {
     if let Err(e) = v.validate_bounds(min, max) {
-        match e {
-            OutOfBounds::Lower => return Err(Error::new(format!("lower than {min}"))),
-            OutOfBounds::Upper => return Err(Error::new(format!("greater than {max}"))),
-        }
+        let code = match e {
+            OutOfBounds::Lower => "range.min",
+            OutOfBounds::Upper => "range.max",
+        };
+        let message = match (e, min, max) {
+            (OutOfBounds::Lower, Bound::Included(min), _) => format!("lower than {min}"),
+            (OutOfBounds::Lower, Bound::Excluded(min), _) => {
+                format!("not strictly greater than {min}")
+            }
+            (OutOfBounds::Upper, _, Bound::Included(max)) => format!("greater than {max}"),
+            (OutOfBounds::Upper, _, Bound::Excluded(max)) => {
+                format!("not strictly lower than {max}")
+            }
+            // only reached by values which cannot be compared at all
+            (OutOfBounds::Lower, Bound::Unbounded, _)
+            | (OutOfBounds::Upper, _, Bound::Unbounded) => String::from("out of bounds"),
+        };
+        return Err(Error::with_code(code, message));
     }
     Ok(())
 }
@@ -35,10 +84,36 @@ pub fn apply<T: Bounds>(v: &T, (min, max): (&T, &T)) -> Result<(), Error> {
     )
 )]
 pub trait Bounds: PartialOrd + Display {
-    const MIN: Self;
-    const MAX: Self;
+    /// Checks that `self` is within `lower_bound` and `upper_bound`.
+    ///
+    /// The default implementation uses [`PartialOrd`], and treats values which cannot be compared to a bound as out of bounds.
+    fn validate_bounds(
+        &self,
+        lower_bound: Bound<&Self>,
+        upper_bound: Bound<&Self>,
+    ) -> Result<(), OutOfBounds> {
+        use std::cmp::Ordering::{Equal, Greater, Less};
+
+        let above_lower = match lower_bound {
+            Bound::Included(min) => matches!(self.partial_cmp(min), Some(Greater | Equal)),
+            Bound::Excluded(min) => matches!(self.partial_cmp(min), Some(Greater)),
+            Bound::Unbounded => self.partial_cmp(self).is_some(),
+        };
+        if !above_lower {
+            return Err(OutOfBounds::Lower);
+        }
 
-    fn validate_bounds(&self, lower_bound: &Self, upper_bound: &Self) -> Result<(), OutOfBounds>;
+        let below_upper = match upper_bound {
+            Bound::Included(max) => matches!(self.partial_cmp(max), Some(Less | Equal)),
+            Bound::Excluded(max) => matches!(self.partial_cmp(max), Some(Less)),
+            Bound::Unbounded => true,
+        };
+        if !below_upper {
+            return Err(OutOfBounds::Upper);
+        }
+
+        Ok(())
+    }
 }
 
 pub enum OutOfBounds {
@@ -46,29 +121,4 @@ pub enum OutOfBounds {
     Upper,
 }
 
-macro_rules! impl_for_int {
-    ($($T:ident),*) => {
-        $(
-            impl Bounds for $T {
-                const MIN: Self = $T::MIN;
-                const MAX: Self = $T::MAX;
-
-                fn validate_bounds(
-                    &self,
-                    lower_bound: &Self,
-                    upper_bound: &Self,
-                ) -> Result<(), OutOfBounds> {
-                    if self < lower_bound {
-                        Err(OutOfBounds::Lower)
-                    } else if self > upper_bound {
-                        Err(OutOfBounds::Upper)
-                    } else {
-                        Ok(())
-                    }
-                }
-            }
-        )*
-    };
-}
-
-impl_for_int!(u8, u16, u32, u64, usize, u128, i8, i16, i32, i64, isize, i128);
+impl<T: PartialOrd + Display> Bounds for T {}
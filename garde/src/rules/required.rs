@@ -0,0 +1,158 @@
+//! Required value validation.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(required)]
+//!     v: Option<String>,
+//! }
+//! ```
+//!
+//! By default, the value must be `Some`, and any `Some` is accepted, including `Some("")`.
+//!
+//! Empty values may also be treated as missing using `#[garde(required(non_empty))]`, in which case
+//! `Some("")` and `Some(vec![])` fail, as well as any empty `String`, `Vec`, or map which is not wrapped in an `Option`:
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(required(non_empty, message = "this field is mandatory"))]
+//!     name: Option<String>,
+//!     #[garde(required(non_empty))]
+//!     tags: Vec<String>,
+//! }
+//! ```
+//!
+//! The `message` argument replaces the default message of this rule, `value is required`.
+//!
+//! The entrypoint is the [`Required`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(required)]` rule,
+//! where the `Mode` parameter is either [`Present`] or [`NonEmpty`].
+//!
+//! `Required<Present>` is implemented for `Option<T>`. `Required<NonEmpty>` is implemented for every `T: IsEmpty`,
+//! and for `Option<T>` where `T: IsEmpty`. [`IsEmpty`] is implemented for `str`, `String`, `[T]`, `[T; N]`, `Vec<T>`, `VecDeque<T>`,
+//! `HashMap<K, V, S>`, `HashSet<T, S>`, `BTreeMap<K, V>`, and `BTreeSet<T>`.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+
+use crate::error::Error;
+
+pub fn apply<T: Required<M> + ?Sized, M>(
+    v: &T,
+    (_, message): (M, Option<&'static str>),
+) -> Result<(), Error> {
+    if !v.validate_required() {
+        return Err(Error::with_code(
+            "required",
+            message.unwrap_or("value is required"),
+        ));
+    }
+    Ok(())
+}
+
+/// The value must be present, e.g. `Some`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Present;
+
+/// The value must be present and not empty.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NonEmpty;
+
+#[cfg_attr(
+    feature = "nightly-error-messages",
+    rustc_on_unimplemented(
+        message = "`{Self}` does not support required validation",
+        label = "This type does not support required validation",
+        note = "`required` is only supported by `Option<T>`, and `required(non_empty)` by types implementing `garde::rules::required::IsEmpty`"
+    )
+)]
+pub trait Required<Mode = Present> {
+    fn validate_required(&self) -> bool;
+}
+
+pub trait IsEmpty {
+    fn is_empty(&self) -> bool;
+}
+
+impl<T> Required<Present> for Option<T> {
+    fn validate_required(&self) -> bool {
+        self.is_some()
+    }
+}
+
+impl<T: IsEmpty> Required<NonEmpty> for Option<T> {
+    fn validate_required(&self) -> bool {
+        self.as_ref().map_or(false, |v| !v.is_empty())
+    }
+}
+
+impl<T: IsEmpty + ?Sized> Required<NonEmpty> for T {
+    fn validate_required(&self) -> bool {
+        !self.is_empty()
+    }
+}
+
+impl<T: IsEmpty + ?Sized> IsEmpty for &T {
+    fn is_empty(&self) -> bool {
+        T::is_empty(self)
+    }
+}
+
+impl IsEmpty for str {
+    fn is_empty(&self) -> bool {
+        str::is_empty(self)
+    }
+}
+
+impl IsEmpty for String {
+    fn is_empty(&self) -> bool {
+        String::is_empty(self)
+    }
+}
+
+impl<T> IsEmpty for [T] {
+    fn is_empty(&self) -> bool {
+        <[T]>::is_empty(self)
+    }
+}
+
+impl<T, const N: usize> IsEmpty for [T; N] {
+    fn is_empty(&self) -> bool {
+        N == 0
+    }
+}
+
+impl<T> IsEmpty for Vec<T> {
+    fn is_empty(&self) -> bool {
+        Vec::is_empty(self)
+    }
+}
+
+impl<T> IsEmpty for VecDeque<T> {
+    fn is_empty(&self) -> bool {
+        VecDeque::is_empty(self)
+    }
+}
+
+impl<K, V, S> IsEmpty for HashMap<K, V, S> {
+    fn is_empty(&self) -> bool {
+        HashMap::is_empty(self)
+    }
+}
+
+impl<T, S> IsEmpty for HashSet<T, S> {
+    fn is_empty(&self) -> bool {
+        HashSet::is_empty(self)
+    }
+}
+
+impl<K, V> IsEmpty for BTreeMap<K, V> {
+    fn is_empty(&self) -> bool {
+        BTreeMap::is_empty(self)
+    }
+}
+
+impl<T> IsEmpty for BTreeSet<T> {
+    fn is_empty(&self) -> bool {
+        BTreeSet::is_empty(self)
+    }
+}
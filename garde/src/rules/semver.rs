@@ -0,0 +1,67 @@
+//! Semantic version validation using the [`semver`] crate.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(semver)]
+//!     version: String,
+//!     #[garde(semver(req))]
+//!     requirement: String,
+//! }
+//! ```
+//!
+//! The entrypoint is the [`Semver`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(semver)]` rule.
+//!
+//! This trait has a blanket implementation for all `T: AsRef<str>`.
+//!
+//! By default, the value must be an exact version as accepted by [`semver::Version::parse`], such as `1.2.3` or `1.0.0-alpha.1+build`,
+//! so incomplete versions like `1.2` and prefixed versions like `v1.0.0` are rejected. This may also be spelled out as `#[garde(semver(version))]`.
+//! A version requirement as accepted by [`semver::VersionReq::parse`], such as `>=1.2, <2` or `^1.4`, may be validated using `#[garde(semver(req))]` instead.
+
+use std::fmt::Display;
+
+use crate::error::Error;
+
+pub fn apply<T: Semver>(v: &T, (kind,): (SemverKind,)) -> Result<(), Error> {
+    if let Err(e) = v.validate_semver(kind) {
+        let message = match kind {
+            SemverKind::Version => format!("not a valid semantic version: {e}"),
+            SemverKind::Req => format!("not a valid version requirement: {e}"),
+        };
+        return Err(Error::with_code("semver", message));
+    }
+    Ok(())
+}
+
+#[cfg_attr(
+    feature = "nightly-error-messages",
+    rustc_on_unimplemented(
+        message = "`{Self}` does not support semantic version validation",
+        label = "This type does not support semantic version validation",
+    )
+)]
+pub trait Semver {
+    type Error: Display;
+
+    fn validate_semver(&self, kind: SemverKind) -> Result<(), Self::Error>;
+}
+
+/// What a value must be parsed as.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SemverKind {
+    /// An exact version, such as `1.2.3`.
+    Version,
+    /// A version requirement, such as `>=1.2, <2`.
+    Req,
+}
+
+impl<T: AsRef<str>> Semver for T {
+    type Error = semver::Error;
+
+    fn validate_semver(&self, kind: SemverKind) -> Result<(), Self::Error> {
+        match kind {
+            SemverKind::Version => semver::Version::parse(self.as_ref()).map(|_| ()),
+            SemverKind::Req => semver::VersionReq::parse(self.as_ref()).map(|_| ()),
+        }
+    }
+}
@@ -0,0 +1,107 @@
+//! Validation of the segments of a delimited string.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(split(",", inner(length(min = 1, max = 20))))]
+//!     tags: String,
+//! }
+//! ```
+//!
+//! The value is split on the delimiter, and the rules inside `inner(...)` are applied to each segment as a `&str`.
+//! Errors are reported at the index of the segment they were produced for, such as `value.tags[1]`.
+//! The rules may be combined with other rules, which are applied to the whole string.
+//!
+//! By default, empty segments, such as the one in `"a,,b"`, are validated like any other segment. They may instead
+//! be skipped using `skip_empty`, or rejected using `deny_empty`. A skipped segment still counts towards the indices
+//! of the segments which follow it. Note that an empty string consists of a single empty segment.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(split(",", deny_empty, inner(length(max = 20))))]
+//!     tags: String,
+//! }
+//! ```
+//!
+//! The entrypoint is the [`Split`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(split(...))]` rule.
+//!
+//! This trait has a blanket implementation for all `T: AsRef<str>`.
+
+use crate::error::{Error, Errors, SimpleErrorBuilder};
+
+/// Collects the errors produced by `f` for each segment of `v`.
+#[doc(hidden)]
+pub fn errors<T, F>(v: &T, (delimiter, empty): (&str, EmptySegments), mut f: F) -> Errors
+where
+    T: Split + ?Sized,
+    F: FnMut(&&str, &mut SimpleErrorBuilder),
+{
+    Errors::list(|errors| {
+        for segment in v.segments(delimiter) {
+            errors.push(segment_errors(segment, empty, &mut f));
+        }
+    })
+}
+
+/// Returns the first error produced by `f` for a segment of `v`, along with its path.
+#[doc(hidden)]
+pub fn first_error<T, F>(
+    v: &T,
+    (delimiter, empty): (&str, EmptySegments),
+    path: &str,
+    mut f: F,
+) -> Result<(), (String, Error)>
+where
+    T: Split + ?Sized,
+    F: FnMut(&&str, &mut SimpleErrorBuilder),
+{
+    for (index, segment) in v.segments(delimiter).enumerate() {
+        let errors = segment_errors(segment, empty, &mut f);
+        if let Some((_, error)) = errors.flatten().into_iter().next() {
+            return Err((format!("{path}[{index}]"), error));
+        }
+    }
+    Ok(())
+}
+
+fn segment_errors<F>(segment: &str, empty: EmptySegments, f: &mut F) -> Errors
+where
+    F: FnMut(&&str, &mut SimpleErrorBuilder),
+{
+    match empty {
+        EmptySegments::Skip if segment.is_empty() => Errors::empty(),
+        EmptySegments::Deny if segment.is_empty() => Errors::simple(|errors| {
+            errors.push(Error::with_code("split", "segment is empty"));
+        }),
+        _ => Errors::simple(|errors| f(&segment, errors)),
+    }
+}
+
+/// How empty segments are handled.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EmptySegments {
+    /// Empty segments are validated like any other segment.
+    Validate,
+    /// Empty segments are not validated.
+    Skip,
+    /// Empty segments fail validation.
+    Deny,
+}
+
+#[cfg_attr(
+    feature = "nightly-error-messages",
+    rustc_on_unimplemented(
+        message = "`{Self}` does not support split validation",
+        label = "This type does not support split validation",
+    )
+)]
+pub trait Split {
+    fn segments<'a>(&'a self, delimiter: &'a str) -> Box<dyn Iterator<Item = &'a str> + 'a>;
+}
+
+impl<T: AsRef<str>> Split for T {
+    fn segments<'a>(&'a self, delimiter: &'a str) -> Box<dyn Iterator<Item = &'a str> + 'a> {
+        Box::new(self.as_ref().split(delimiter))
+    }
+}
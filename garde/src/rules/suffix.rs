@@ -11,12 +11,32 @@
 //! The entrypoint is the [`Suffix`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(suffix)]` rule.
 //!
 //! This trait has a blanket implementation for all `T: AsRef<str>`.
+//!
+//! Several alternatives may be given, in which case the value must end with at least one of them:
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(suffix(".com", ".org"))]
+//!     v: String,
+//! }
+//! ```
 
 use crate::error::Error;
 
-pub fn apply<T: Suffix>(v: &T, (pat,): (&str,)) -> Result<(), Error> {
-    if !v.validate_suffix(pat) {
-        return Err(Error::new(format!("does not end with \"{pat}\"")));
+pub fn apply<T: Suffix>(v: &T, (pats,): (&[&str],)) -> Result<(), Error> {
+    if !pats.iter().any(|pat| v.validate_suffix(pat)) {
+        let message = match pats {
+            [pat] => format!("does not end with \"{pat}\""),
+            pats => format!(
+                "does not end with any of {}",
+                pats.iter()
+                    .map(|pat| format!("\"{pat}\""))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        };
+        return Err(Error::with_code("suffix", message));
     }
     Ok(())
 }
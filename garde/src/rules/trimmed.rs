@@ -0,0 +1,46 @@
+//! Surrounding whitespace validation.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(trimmed)]
+//!     v: String,
+//! }
+//! ```
+//!
+//! The entrypoint is the [`Trimmed`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(trimmed)]` rule.
+//!
+//! This trait has a blanket implementation for all `T: AsRef<str>`.
+//!
+//! Whitespace is defined the same way as in [`str::trim`], which means that any Unicode whitespace is rejected,
+//! and that a value consisting only of whitespace is also rejected. Empty values are accepted.
+
+use crate::error::Error;
+
+pub fn apply<T: Trimmed>(v: &T, _: ()) -> Result<(), Error> {
+    if !v.validate_trimmed() {
+        return Err(Error::with_code(
+            "trimmed",
+            "has leading or trailing whitespace",
+        ));
+    }
+    Ok(())
+}
+
+#[cfg_attr(
+    feature = "nightly-error-messages",
+    rustc_on_unimplemented(
+        message = "`{Self}` does not support whitespace validation",
+        label = "This type does not support whitespace validation",
+    )
+)]
+pub trait Trimmed {
+    fn validate_trimmed(&self) -> bool;
+}
+
+impl<T: AsRef<str>> Trimmed for T {
+    fn validate_trimmed(&self) -> bool {
+        let v = self.as_ref();
+        v.trim().len() == v.len()
+    }
+}
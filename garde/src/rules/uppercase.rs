@@ -0,0 +1,46 @@
+//! Uppercase validation.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(uppercase)]
+//!     v: String,
+//! }
+//! ```
+//!
+//! The entrypoint is the [`Uppercase`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(uppercase)]` rule.
+//!
+//! This trait has a blanket implementation for all `T: AsRef<str>`.
+//!
+//! A value is uppercase if it is equal to its [`str::to_uppercase`] mapping.
+//! Characters without case, such as digits and punctuation, are accepted.
+//!
+//! Some characters map to more than one character, and are only accepted if the mapping is identical.
+//! For example, `İ` (`U+0130`) is uppercase, while `ß` is not, because it uppercases to `SS`.
+
+use crate::error::Error;
+
+pub fn apply<T: Uppercase>(v: &T, _: ()) -> Result<(), Error> {
+    if !v.validate_uppercase() {
+        return Err(Error::with_code("uppercase", "not uppercase"));
+    }
+    Ok(())
+}
+
+#[cfg_attr(
+    feature = "nightly-error-messages",
+    rustc_on_unimplemented(
+        message = "`{Self}` does not support uppercase validation",
+        label = "This type does not support uppercase validation",
+    )
+)]
+pub trait Uppercase {
+    fn validate_uppercase(&self) -> bool;
+}
+
+impl<T: AsRef<str>> Uppercase for T {
+    fn validate_uppercase(&self) -> bool {
+        let v = self.as_ref();
+        v.chars().flat_map(char::to_uppercase).eq(v.chars())
+    }
+}
@@ -14,14 +14,43 @@
 //!
 //! If you need to implement this for a string-like type where a contiguous slice of the entire contents cannot be obtained,
 //! then there is currently no way for you to implement this trait.
+//!
+//! The accepted URL schemes may be restricted using the `schemes` argument:
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(url(schemes("https")))]
+//!     v: String,
+//! }
+//! ```
+//!
+//! Schemes are compared against [`url::Url::scheme`], which is always lowercase.
+//! If `schemes` is empty or omitted, any scheme is accepted.
+//!
+//! The accepted hosts may be restricted using the `host` argument, which may be combined with `schemes`:
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(url(schemes("https"), host("example.com", "api.example.com")))]
+//!     v: String,
+//! }
+//! ```
+//!
+//! Hosts are compared case-insensitively against [`url::Url::host_str`]. Only exact matches are accepted,
+//! so subdomains must be listed explicitly. IP addresses are matched as written by [`url::Url::host_str`],
+//! which means that IPv6 addresses must be enclosed in brackets, such as `[::1]`.
+//! URLs without a host, such as `mailto:user@example.com`, are rejected when `host` is present.
+//! If `host` is empty or omitted, any host is accepted.
 
 use std::fmt::Display;
 
 use crate::error::Error;
 
-pub fn apply<T: Url>(v: &T, _: ()) -> Result<(), Error> {
-    if let Err(e) = v.validate_url() {
-        return Err(Error::new(format!("not a valid url: {e}")));
+pub fn apply<T: Url>(v: &T, (schemes, hosts): (&[&str], &[&str])) -> Result<(), Error> {
+    if let Err(e) = v.validate_url(schemes, hosts) {
+        return Err(Error::with_code("url", format!("not a valid url: {e}")));
     }
     Ok(())
 }
@@ -36,14 +65,59 @@ pub fn apply<T: Url>(v: &T, _: ()) -> Result<(), Error> {
 pub trait Url {
     type Error: Display;
 
-    fn validate_url(&self) -> Result<(), Self::Error>;
+    fn validate_url(&self, schemes: &[&str], hosts: &[&str]) -> Result<(), Self::Error>;
 }
 
 impl<T: AsRef<str>> Url for T {
-    type Error = url::ParseError;
+    type Error = InvalidUrl;
 
-    fn validate_url(&self) -> Result<(), Self::Error> {
-        let _ = url::Url::parse(self.as_ref())?;
+    fn validate_url(&self, schemes: &[&str], hosts: &[&str]) -> Result<(), Self::Error> {
+        let url = url::Url::parse(self.as_ref()).map_err(InvalidUrl::Parse)?;
+        if !schemes.is_empty() && !schemes.contains(&url.scheme()) {
+            return Err(InvalidUrl::Scheme {
+                scheme: url.scheme().to_string(),
+                allowed: schemes.iter().map(|s| s.to_string()).collect(),
+            });
+        }
+        if !hosts.is_empty() {
+            let allowed = url.host_str().map_or(false, |host| {
+                hosts
+                    .iter()
+                    .any(|allowed| allowed.eq_ignore_ascii_case(host))
+            });
+            if !allowed {
+                return Err(InvalidUrl::Host {
+                    host: url.host_str().unwrap_or_default().to_string(),
+                });
+            }
+        }
         Ok(())
     }
 }
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum InvalidUrl {
+    Parse(url::ParseError),
+    Scheme {
+        scheme: String,
+        allowed: Vec<String>,
+    },
+    Host {
+        host: String,
+    },
+}
+
+impl Display for InvalidUrl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InvalidUrl::Parse(e) => write!(f, "{e}"),
+            InvalidUrl::Scheme { scheme, allowed } => write!(
+                f,
+                "url scheme `{scheme}` is not allowed (expected one of: {})",
+                allowed.join(", ")
+            ),
+            InvalidUrl::Host { host } if host.is_empty() => write!(f, "url has no host"),
+            InvalidUrl::Host { host } => write!(f, "url host `{host}` is not allowed"),
+        }
+    }
+}
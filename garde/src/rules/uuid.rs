@@ -0,0 +1,89 @@
+//! UUID validation using the [`uuid`] crate.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(uuid)]
+//!     v: String,
+//! }
+//! ```
+//!
+//! The entrypoint is the [`Uuid`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(uuid)]` rule.
+//!
+//! This trait has a blanket implementation for all `T: AsRef<str>`.
+//!
+//! Every format accepted by [`uuid::Uuid::try_parse`] is valid:
+//! - hyphenated: `67e55044-10b1-426f-9247-bb680e5fe0c8`
+//! - simple: `67e5504410b1426f9247bb680e5fe0c8`
+//! - braced: `{67e55044-10b1-426f-9247-bb680e5fe0c8}`
+//! - URN: `urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8`
+//!
+//! Hex digits may be uppercase or lowercase.
+//!
+//! A specific version may be required using the `version` argument:
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(uuid(version = 4))]
+//!     v: String,
+//! }
+//! ```
+//!
+//! If `version` is omitted, any valid UUID is accepted, including the nil UUID.
+
+use std::fmt::Display;
+
+use crate::error::Error;
+
+pub fn apply<T: Uuid>(v: &T, (version,): (Option<usize>,)) -> Result<(), Error> {
+    if let Err(e) = v.validate_uuid(version) {
+        return Err(Error::with_code("uuid", e.to_string()));
+    }
+    Ok(())
+}
+
+#[cfg_attr(
+    feature = "nightly-error-messages",
+    rustc_on_unimplemented(
+        message = "`{Self}` does not support UUID validation",
+        label = "This type does not support UUID validation",
+    )
+)]
+pub trait Uuid {
+    type Error: Display;
+
+    fn validate_uuid(&self, version: Option<usize>) -> Result<(), Self::Error>;
+}
+
+impl<T: AsRef<str>> Uuid for T {
+    type Error = InvalidUuid;
+
+    fn validate_uuid(&self, version: Option<usize>) -> Result<(), Self::Error> {
+        let uuid = uuid::Uuid::try_parse(self.as_ref()).map_err(InvalidUuid::Parse)?;
+        if let Some(expected) = version {
+            let actual = uuid.get_version_num();
+            if actual != expected {
+                return Err(InvalidUuid::Version { expected, actual });
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum InvalidUuid {
+    Parse(uuid::Error),
+    Version { expected: usize, actual: usize },
+}
+
+impl Display for InvalidUuid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InvalidUuid::Parse(e) => write!(f, "not a valid uuid: {e}"),
+            InvalidUuid::Version { expected, actual } => {
+                write!(f, "expected uuid version {expected}, got version {actual}")
+            }
+        }
+    }
+}
@@ -1,8 +1,9 @@
 //! ## Core validation traits and types
 
+use std::cell::Cell;
 use std::fmt::Debug;
 
-use crate::error::Errors;
+use crate::error::{Error, Errors};
 
 /// The core trait of this crate.
 ///
@@ -17,6 +18,135 @@ pub trait Validate {
     /// Validates `Self`, returning an `Err` with an aggregate of all errors if
     /// the validation failed.
     fn validate(&self, ctx: &Self::Context) -> Result<(), Errors>;
+
+    /// Validates `Self`, returning an `Err` with the path and error of the first rule which failed.
+    ///
+    /// The path uses the same format as [`Errors::flatten`].
+    /// Fields are checked in the order in which [`Errors::flatten`] would yield their errors,
+    /// and collections are checked in iteration order.
+    ///
+    /// The default implementation calls [`Validate::validate`] and discards all but the first error.
+    /// The derive macro instead generates an implementation which stops at the first failing rule,
+    /// which avoids doing unnecessary work when only the validity of the value matters.
+    fn validate_first_error(&self, ctx: &Self::Context) -> Result<(), (String, Error)> {
+        match self.validate(ctx) {
+            Ok(()) => Ok(()),
+            Err(errors) => match errors.flatten().into_iter().next() {
+                Some(first) => Err(first),
+                None => Ok(()),
+            },
+        }
+    }
+}
+
+/// Validation using a context which is created on demand.
+///
+/// The derive macro implements this for every type, using the function which creates the context if one is given
+/// via `#[garde(context(MyContext, default = make_context))]`, and `Default::default()` otherwise, in which case
+/// the implementation only applies if the context implements `Default`. For a context which does not:
+///
+/// ```rust
+/// use garde::validate::ValidateDefault;
+///
+/// struct Limits {
+///     max_name: usize,
+/// }
+///
+/// fn default_limits() -> Limits {
+///     Limits { max_name: 8 }
+/// }
+///
+/// fn name_fits(value: &str, limits: &Limits) -> garde::Result {
+///     if value.len() > limits.max_name {
+///         return Err(garde::Error::new("name is too long"));
+///     }
+///     Ok(())
+/// }
+///
+/// #[derive(garde::Validate)]
+/// #[garde(context(Limits, default = default_limits))]
+/// struct User<'a> {
+///     #[garde(custom(name_fits))]
+///     name: &'a str,
+/// }
+///
+/// assert!(User { name: "alice" }.validate_default().is_ok());
+/// assert!(User { name: "bartholomew" }.validate_default().is_err());
+/// ```
+///
+/// The function may also be given for contexts which implement `Default`, in which case it is used instead.
+/// Manual implementations of [`Validate`] may implement this trait as well. It is also implemented for the containers
+/// which implement [`Validate`], using the context of their items.
+pub trait ValidateDefault: Validate {
+    /// Creates the context used by [`ValidateDefault::validate_default`].
+    fn default_context() -> Self::Context;
+
+    /// Validates `Self` using the context returned by [`ValidateDefault::default_context`].
+    fn validate_default(&self) -> Result<(), Errors> {
+        self.validate(&Self::default_context())
+    }
+}
+
+macro_rules! impl_validate_default {
+    (<$T:ident $(: ?$Sized:ident)? $(, $Other:ident)*> $Container:ty) => {
+        impl<$T $(: ?$Sized)? $(, $Other)*> ValidateDefault for $Container
+        where
+            $T: ValidateDefault,
+            $Container: Validate<Context = $T::Context>,
+        {
+            fn default_context() -> Self::Context {
+                $T::default_context()
+            }
+        }
+    };
+}
+
+impl_validate_default!(<T: ?Sized> std::boxed::Box<T>);
+impl_validate_default!(<T: ?Sized> std::rc::Rc<T>);
+impl_validate_default!(<T: ?Sized> std::sync::Arc<T>);
+impl_validate_default!(<T> Option<T>);
+impl_validate_default!(<T, S> std::collections::HashSet<T, S>);
+impl_validate_default!(<T> std::collections::BTreeSet<T>);
+impl_validate_default!(<T> std::collections::BinaryHeap<T>);
+impl_validate_default!(<T> std::collections::LinkedList<T>);
+impl_validate_default!(<T> std::collections::VecDeque<T>);
+impl_validate_default!(<T> std::vec::Vec<T>);
+impl_validate_default!(<T> [T]);
+impl_validate_default!(<V, K, S> std::collections::HashMap<K, V, S>);
+impl_validate_default!(<V, K> std::collections::BTreeMap<K, V>);
+impl_validate_default!(<A> (A,));
+impl_validate_default!(<A, B> (A, B));
+impl_validate_default!(<A, B, C> (A, B, C));
+impl_validate_default!(<A, B, C, D> (A, B, C, D));
+impl_validate_default!(<A, B, C, D, E> (A, B, C, D, E));
+impl_validate_default!(<A, B, C, D, E, F> (A, B, C, D, E, F));
+impl_validate_default!(<A, B, C, D, E, F, G> (A, B, C, D, E, F, G));
+impl_validate_default!(<A, B, C, D, E, F, G, H> (A, B, C, D, E, F, G, H));
+impl_validate_default!(<A, B, C, D, E, F, G, H, I> (A, B, C, D, E, F, G, H, I));
+impl_validate_default!(<A, B, C, D, E, F, G, H, I, J> (A, B, C, D, E, F, G, H, I, J));
+impl_validate_default!(<A, B, C, D, E, F, G, H, I, J, K> (A, B, C, D, E, F, G, H, I, J, K));
+impl_validate_default!(<A, B, C, D, E, F, G, H, I, J, K, L> (A, B, C, D, E, F, G, H, I, J, K, L));
+
+impl<T: ?Sized + ValidateDefault> ValidateDefault for &T {
+    fn default_context() -> Self::Context {
+        T::default_context()
+    }
+}
+
+impl<T: ?Sized + ValidateDefault> ValidateDefault for &mut T {
+    fn default_context() -> Self::Context {
+        T::default_context()
+    }
+}
+
+impl<T: ValidateDefault, const N: usize> ValidateDefault for [T; N] {
+    fn default_context() -> Self::Context {
+        T::default_context()
+    }
+}
+
+impl ValidateDefault for () {
+    fn default_context() -> Self::Context {}
 }
 
 /// A struct which wraps a valid instance of some `T`.
@@ -29,12 +159,55 @@ pub trait Validate {
 pub struct Valid<T>(T);
 
 impl<T: Validate> Valid<T> {
+    /// Validates `value` using `ctx`, and wraps it if it is valid.
+    ///
+    /// This is equivalent to `Unvalidated::new(value).validate(ctx)`.
+    pub fn from_validated(value: T, ctx: &<T as Validate>::Context) -> Result<Self, Errors> {
+        value.validate(ctx)?;
+        Ok(Valid(value))
+    }
+
     /// Returns the inner value.
     pub fn into_inner(self) -> T {
         self.0
     }
 }
 
+/// Deserializes a `T`, and then validates it using the context returned by [`ValidateDefault::default_context`],
+/// which is the default value of the context unless it was customized.
+///
+/// If validation fails, deserialization fails with the validation errors as its message.
+///
+/// ```rust
+/// #[derive(Debug, serde::Deserialize, garde::Validate)]
+/// struct User {
+///     #[garde(length(min = 3))]
+///     name: String,
+/// }
+///
+/// let user: garde::Valid<User> = serde_json::from_str(r#"{ "name": "Alice" }"#).unwrap();
+/// assert_eq!(user.name, "Alice");
+///
+/// let error = serde_json::from_str::<garde::Valid<User>>(r#"{ "name": "Al" }"#).unwrap_err();
+/// assert_eq!(error.to_string(), "value.name: length is lower than 3");
+/// ```
+///
+/// For contexts which cannot be created up front, deserialize an [`Unvalidated<T>`] instead,
+/// and then validate it with the context.
+#[cfg(feature = "serde")]
+impl<'de, T> serde::Deserialize<'de> for Valid<T>
+where
+    T: serde::Deserialize<'de> + ValidateDefault,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let value = T::deserialize(deserializer)?;
+        Valid::from_validated(value, &T::default_context()).map_err(serde::de::Error::custom)
+    }
+}
+
 impl<T> std::ops::Deref for Valid<T> {
     type Target = T;
 
@@ -76,44 +249,84 @@ impl<T: Debug> Debug for Unvalidated<T> {
     }
 }
 
-impl<'a, T: ?Sized + Validate> Validate for &'a T {
+impl<T: ?Sized + Validate> Validate for &T {
     type Context = T::Context;
 
     fn validate(&self, ctx: &Self::Context) -> Result<(), Errors> {
         <T as Validate>::validate(self, ctx)
     }
+
+    fn validate_first_error(&self, ctx: &Self::Context) -> Result<(), (String, Error)> {
+        <T as Validate>::validate_first_error(self, ctx)
+    }
 }
 
-impl<'a, T: ?Sized + Validate> Validate for &'a mut T {
+impl<T: ?Sized + Validate> Validate for &mut T {
     type Context = T::Context;
 
     fn validate(&self, ctx: &Self::Context) -> Result<(), Errors> {
         <T as Validate>::validate(self, ctx)
     }
+
+    fn validate_first_error(&self, ctx: &Self::Context) -> Result<(), (String, Error)> {
+        <T as Validate>::validate_first_error(self, ctx)
+    }
 }
 
-impl<T: Validate> Validate for std::boxed::Box<T> {
+impl<T: ?Sized + Validate> Validate for std::boxed::Box<T> {
     type Context = T::Context;
 
     fn validate(&self, ctx: &Self::Context) -> Result<(), Errors> {
         <T as Validate>::validate(self, ctx)
     }
+
+    fn validate_first_error(&self, ctx: &Self::Context) -> Result<(), (String, Error)> {
+        <T as Validate>::validate_first_error(self, ctx)
+    }
 }
 
-impl<T: Validate> Validate for std::rc::Rc<T> {
+impl<T: ?Sized + Validate> Validate for std::rc::Rc<T> {
     type Context = T::Context;
 
     fn validate(&self, ctx: &Self::Context) -> Result<(), Errors> {
         <T as Validate>::validate(self, ctx)
     }
+
+    fn validate_first_error(&self, ctx: &Self::Context) -> Result<(), (String, Error)> {
+        <T as Validate>::validate_first_error(self, ctx)
+    }
 }
 
-impl<T: Validate> Validate for std::sync::Arc<T> {
+impl<T: ?Sized + Validate> Validate for std::sync::Arc<T> {
     type Context = T::Context;
 
     fn validate(&self, ctx: &Self::Context) -> Result<(), Errors> {
         <T as Validate>::validate(self, ctx)
     }
+
+    fn validate_first_error(&self, ctx: &Self::Context) -> Result<(), (String, Error)> {
+        <T as Validate>::validate_first_error(self, ctx)
+    }
+}
+
+/// `None` is always valid, and `Some` is validated as if it were the inner value,
+/// so its errors are reported at the same path.
+impl<T: Validate> Validate for Option<T> {
+    type Context = T::Context;
+
+    fn validate(&self, ctx: &Self::Context) -> Result<(), Errors> {
+        match self {
+            Some(value) => <T as Validate>::validate(value, ctx),
+            None => Ok(()),
+        }
+    }
+
+    fn validate_first_error(&self, ctx: &Self::Context) -> Result<(), (String, Error)> {
+        match self {
+            Some(value) => <T as Validate>::validate_first_error(value, ctx),
+            None => Ok(()),
+        }
+    }
 }
 
 macro_rules! impl_validate_list {
@@ -138,6 +351,15 @@ macro_rules! impl_validate_list {
                 }
                 Ok(())
             }
+
+            fn validate_first_error(&self, ctx: &Self::Context) -> Result<(), (String, Error)> {
+                for (index, item) in self.iter().enumerate() {
+                    if let Err((path, error)) = <T as Validate>::validate_first_error(item, ctx) {
+                        return Err((crate::error::nest_path(&format!("value[{index}]"), &path), error));
+                    }
+                }
+                Ok(())
+            }
         }
     };
 }
@@ -167,6 +389,18 @@ impl<T: Validate, const N: usize> Validate for [T; N] {
         }
         Ok(())
     }
+
+    fn validate_first_error(&self, ctx: &Self::Context) -> Result<(), (String, Error)> {
+        for (index, item) in self.iter().enumerate() {
+            if let Err((path, error)) = <T as Validate>::validate_first_error(item, ctx) {
+                return Err((
+                    crate::error::nest_path(&format!("value[{index}]"), &path),
+                    error,
+                ));
+            }
+        }
+        Ok(())
+    }
 }
 
 macro_rules! impl_validate_tuple {
@@ -200,6 +434,21 @@ macro_rules! impl_validate_tuple {
                 }
                 Ok(())
             }
+
+            #[allow(non_snake_case)]
+            fn validate_first_error(&self, ctx: &Self::Context) -> Result<(), (String, Error)> {
+                let ($A, $($T,)*) = self;
+                let items: &[&dyn Fn() -> Result<(), (String, Error)>] = &[
+                    &|| <$A as Validate>::validate_first_error($A, ctx),
+                    $(&|| <$T as Validate>::validate_first_error($T, ctx),)*
+                ];
+                for (index, item) in items.iter().enumerate() {
+                    if let Err((path, error)) = item() {
+                        return Err((crate::error::nest_path(&format!("value[{index}]"), &path), error));
+                    }
+                }
+                Ok(())
+            }
         }
     }
 }
@@ -227,17 +476,16 @@ impl Validate for () {
 
 impl<K, V, S> Validate for std::collections::HashMap<K, V, S>
 where
-    std::borrow::Cow<'static, str>: From<K>,
-    K: Clone,
+    K: std::fmt::Display,
     V: Validate,
 {
     type Context = V::Context;
 
     fn validate(&self, ctx: &Self::Context) -> Result<(), Errors> {
-        let errors = Errors::fields(|errors| {
+        let errors = Errors::map(|errors| {
             for (key, value) in self.iter() {
                 errors.insert(
-                    std::borrow::Cow::from(key.clone()),
+                    key,
                     <V as Validate>::validate(value, ctx)
                         .err()
                         .unwrap_or_else(Errors::empty),
@@ -249,21 +497,32 @@ where
         }
         Ok(())
     }
+
+    fn validate_first_error(&self, ctx: &Self::Context) -> Result<(), (String, Error)> {
+        for (key, value) in self.iter() {
+            if let Err((path, error)) = <V as Validate>::validate_first_error(value, ctx) {
+                return Err((
+                    crate::error::nest_path(&format!("value[{key}]"), &path),
+                    error,
+                ));
+            }
+        }
+        Ok(())
+    }
 }
 
 impl<K, V> Validate for std::collections::BTreeMap<K, V>
 where
-    std::borrow::Cow<'static, str>: From<K>,
-    K: Clone,
+    K: std::fmt::Display,
     V: Validate,
 {
     type Context = V::Context;
 
     fn validate(&self, ctx: &Self::Context) -> Result<(), Errors> {
-        let errors = Errors::fields(|errors| {
+        let errors = Errors::map(|errors| {
             for (key, value) in self.iter() {
                 errors.insert(
-                    std::borrow::Cow::from(key.clone()),
+                    key,
                     <V as Validate>::validate(value, ctx)
                         .err()
                         .unwrap_or_else(Errors::empty),
@@ -275,4 +534,301 @@ where
         }
         Ok(())
     }
+
+    fn validate_first_error(&self, ctx: &Self::Context) -> Result<(), (String, Error)> {
+        for (key, value) in self.iter() {
+            if let Err((path, error)) = <V as Validate>::validate_first_error(value, ctx) {
+                return Err((
+                    crate::error::nest_path(&format!("value[{key}]"), &path),
+                    error,
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Validates each of `items` with the context at the same index in `ctxs`.
+///
+/// The result of each item is returned separately, in the same order as `items`, instead of being merged
+/// into a single [`Errors`]. This is useful when each item needs a different context, such as per-tenant rules.
+///
+/// ```rust
+/// #[derive(garde::Validate)]
+/// #[garde(context(Limits))]
+/// struct Upload {
+///     #[garde(custom(check_size))]
+///     size: usize,
+/// }
+///
+/// struct Limits {
+///     max_size: usize,
+/// }
+///
+/// fn check_size(value: &usize, ctx: &Limits) -> garde::Result {
+///     if *value > ctx.max_size {
+///         return Err(garde::Error::new("file is too large"));
+///     }
+///     Ok(())
+/// }
+///
+/// let uploads = [Upload { size: 10 }, Upload { size: 10 }];
+/// let limits = [Limits { max_size: 100 }, Limits { max_size: 5 }];
+/// let results = garde::validate_each(&uploads, &limits).unwrap();
+/// assert!(results[0].is_ok());
+/// assert_eq!(results[1].as_ref().unwrap_err().to_string(), "value.size: file is too large");
+/// ```
+///
+/// Returns [`LengthMismatch`] without validating anything if `items` and `ctxs` have different lengths.
+pub fn validate_each<T: Validate>(
+    items: &[T],
+    ctxs: &[T::Context],
+) -> Result<Vec<Result<(), Errors>>, LengthMismatch> {
+    if items.len() != ctxs.len() {
+        return Err(LengthMismatch {
+            items: items.len(),
+            ctxs: ctxs.len(),
+        });
+    }
+    Ok(items
+        .iter()
+        .zip(ctxs)
+        .map(|(item, ctx)| item.validate(ctx))
+        .collect())
+}
+
+/// The error returned by [`validate_each`] when there is not exactly one context per item.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LengthMismatch {
+    /// The number of items.
+    pub items: usize,
+    /// The number of contexts.
+    pub ctxs: usize,
+}
+
+impl std::fmt::Display for LengthMismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "expected one context per item, got {} items and {} contexts",
+            self.items, self.ctxs
+        )
+    }
+}
+
+impl std::error::Error for LengthMismatch {}
+
+thread_local! {
+    /// The current `dive` depth, and the maximum set by [`with_max_depth`], if any.
+    static DEPTH: Cell<(usize, Option<usize>)> = const { Cell::new((0, None)) };
+}
+
+/// Runs `f` with a limit of `max_depth` levels of nested validation.
+///
+/// Each `#[garde(dive)]` performed by a derived implementation counts as one level.
+/// Once the limit is reached, any further `dive` fails with the error `maximum validation depth exceeded`
+/// instead of validating the nested value. This protects against stack overflows when validating
+/// deeply nested or cyclic input, such as a recursive tree structure built from untrusted data.
+///
+/// Without a limit, validation may nest arbitrarily deep.
+///
+/// ```rust
+/// #[derive(garde::Validate)]
+/// struct Node {
+///     #[garde(dive)]
+///     children: Vec<Node>,
+/// }
+///
+/// let node = Node { children: vec![Node { children: vec![Node { children: vec![] }] }] };
+/// let result = garde::validate::with_max_depth(1, || garde::Validate::validate(&node, &()));
+/// assert_eq!(
+///     result.unwrap_err().to_string(),
+///     "value.children[0].children: maximum validation depth exceeded"
+/// );
+/// ```
+///
+/// The limit is not part of the [`Validate::Context`], because the context is a user-defined type
+/// which is passed unchanged to every nested value. Instead, it applies to the validation performed
+/// by `f` on the current thread. Manual implementations of [`Validate`] which call
+/// [`Validate::validate`] on their fields directly do not count towards the limit.
+///
+/// For [`AsyncValidate`](crate::AsyncValidate), which may resume on a different thread,
+/// use [`validate_async::with_max_depth`](crate::validate_async::with_max_depth) instead.
+pub fn with_max_depth<R>(max_depth: usize, f: impl FnOnce() -> R) -> R {
+    with_depth((0, Some(max_depth)), f)
+}
+
+/// Runs `f` with the depth state set to `state`, restoring the previous state afterwards.
+///
+/// This is also used to carry the depth over to the threads of a parallel validation,
+/// and to the polls of an asynchronous validation.
+pub(crate) fn with_depth<R>(state: (usize, Option<usize>), f: impl FnOnce() -> R) -> R {
+    struct Restore((usize, Option<usize>));
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            DEPTH.with(|depth| depth.set(self.0));
+        }
+    }
+
+    let _restore = Restore(DEPTH.with(|depth| depth.replace(state)));
+    f()
+}
+
+/// Returns the depth state one `dive` below the current one, or an error if the limit is reached.
+pub(crate) fn deeper() -> Result<(usize, Option<usize>), Error> {
+    match DEPTH.with(Cell::get) {
+        (current, Some(max)) if current >= max => Err(Error::with_code(
+            "depth",
+            "maximum validation depth exceeded",
+        )),
+        (current, max) => Ok((current + 1, max)),
+    }
+}
+
+/// Validates a field marked with `#[garde(dive)]`, respecting the limit set by [`with_max_depth`].
+#[doc(hidden)]
+pub fn dive<T: ?Sized + Validate>(value: &T, ctx: &T::Context) -> Errors {
+    match deeper() {
+        Ok(state) => with_depth(state, || {
+            value.validate(ctx).err().unwrap_or_else(Errors::empty)
+        }),
+        Err(error) => Errors::Simple(vec![error]),
+    }
+}
+
+/// Like [`dive`], but for [`Validate::validate_first_error`].
+#[doc(hidden)]
+pub fn dive_first_error<T: ?Sized + Validate>(
+    value: &T,
+    ctx: &T::Context,
+) -> Result<(), (String, Error)> {
+    match deeper() {
+        Ok(state) => with_depth(state, || value.validate_first_error(ctx)),
+        Err(error) => Err((String::from("value"), error)),
+    }
+}
+
+/// Slices with more items than this are validated in parallel by [`par_validate`].
+#[cfg(feature = "rayon")]
+const PARALLEL_THRESHOLD: usize = 1024;
+
+/// Validates the items of a slice in parallel, if there are more than 1024 of them.
+///
+/// The errors are reported in index order, so the result is the same as that of
+/// [`Validate::validate`] on the slice itself. Unlike the sequential implementation,
+/// this requires the items and the context to be `Sync`.
+///
+/// A field may be validated this way using `#[garde(dive(par))]`:
+///
+/// ```rust
+/// #[derive(garde::Validate)]
+/// struct Batch {
+///     #[garde(dive(par))]
+///     records: Vec<Record>,
+/// }
+///
+/// #[derive(garde::Validate)]
+/// struct Record {
+///     #[garde(range(max = 10))]
+///     value: u32,
+/// }
+///
+/// let records = (0..2000).map(|value| Record { value }).collect::<Vec<_>>();
+/// assert!(garde::validate::par_validate(&records, &()).is_err());
+/// ```
+#[cfg(feature = "rayon")]
+pub fn par_validate<T>(items: &[T], ctx: &T::Context) -> Result<(), Errors>
+where
+    T: Validate + Sync,
+    T::Context: Sync,
+{
+    use rayon::prelude::*;
+
+    if items.len() <= PARALLEL_THRESHOLD {
+        return items.validate(ctx);
+    }
+    let depth = DEPTH.with(Cell::get);
+    let errors = Errors::List(
+        items
+            .par_iter()
+            .map(|item| {
+                with_depth(depth, || {
+                    item.validate(ctx).err().unwrap_or_else(Errors::empty)
+                })
+            })
+            .collect(),
+    );
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+    Ok(())
+}
+
+/// Like [`par_validate`], but for [`Validate::validate_first_error`].
+///
+/// The error of the item with the lowest index is returned, as with sequential validation.
+#[cfg(feature = "rayon")]
+pub fn par_validate_first_error<T>(items: &[T], ctx: &T::Context) -> Result<(), (String, Error)>
+where
+    T: Validate + Sync,
+    T::Context: Sync,
+{
+    use rayon::prelude::*;
+
+    if items.len() <= PARALLEL_THRESHOLD {
+        return items.validate_first_error(ctx);
+    }
+    let depth = DEPTH.with(Cell::get);
+    let first = items
+        .par_iter()
+        .enumerate()
+        .find_map_first(|(index, item)| {
+            with_depth(depth, || item.validate_first_error(ctx))
+                .err()
+                .map(|(path, error)| {
+                    (
+                        crate::error::nest_path(&format!("value[{index}]"), &path),
+                        error,
+                    )
+                })
+        });
+    match first {
+        Some(first) => Err(first),
+        None => Ok(()),
+    }
+}
+
+/// Validates a field marked with `#[garde(dive(par))]` using [`par_validate`].
+#[cfg(feature = "rayon")]
+#[doc(hidden)]
+pub fn par_dive<T, S>(value: &S, ctx: &T::Context) -> Errors
+where
+    S: ?Sized + AsRef<[T]>,
+    T: Validate + Sync,
+    T::Context: Sync,
+{
+    match deeper() {
+        Ok(state) => with_depth(state, || {
+            par_validate(value.as_ref(), ctx)
+                .err()
+                .unwrap_or_else(Errors::empty)
+        }),
+        Err(error) => Errors::Simple(vec![error]),
+    }
+}
+
+/// Like [`par_dive`], but for [`Validate::validate_first_error`].
+#[cfg(feature = "rayon")]
+#[doc(hidden)]
+pub fn par_dive_first_error<T, S>(value: &S, ctx: &T::Context) -> Result<(), (String, Error)>
+where
+    S: ?Sized + AsRef<[T]>,
+    T: Validate + Sync,
+    T::Context: Sync,
+{
+    match deeper() {
+        Ok(state) => with_depth(state, || par_validate_first_error(value.as_ref(), ctx)),
+        Err(error) => Err((String::from("value"), error)),
+    }
 }
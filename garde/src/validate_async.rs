@@ -0,0 +1,327 @@
+//! ## Asynchronous validation
+//!
+//! The [`AsyncValidate`] trait is the asynchronous counterpart of [`Validate`][`crate::Validate`].
+//! It may be derived using `#[derive(garde::AsyncValidate)]`, which accepts the same rules as `#[derive(garde::Validate)]`,
+//! as well as the `custom_async` rule:
+//!
+//! ```rust
+//! #[derive(garde::AsyncValidate)]
+//! #[garde(context(Database))]
+//! struct User {
+//!     #[garde(length(min = 3, max = 32), custom_async(is_unique))]
+//!     username: String,
+//! }
+//!
+//! struct Database { /* ... */ }
+//!
+//! async fn is_unique(value: &str, db: &Database) -> garde::Result {
+//!     // query the database here
+//!     Ok(())
+//! }
+//! ```
+//!
+//! Synchronous rules are run inline, so all errors are aggregated into a single [`Errors`].
+//! Fields marked with `dive` are validated using [`AsyncValidate`], which means that their type must implement it.
+//! Like [`Validate`][`crate::Validate`], it is implemented for containers, maps, and tuples of up to 12 elements.
+//!
+//! The returned future is `Send`, which is why both the validated type and its context must be `Sync`.
+
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use crate::error::Errors;
+
+/// A boxed future, as returned by [`AsyncValidate::validate_async`].
+pub type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+/// The asynchronous counterpart of [`Validate`][`crate::Validate`].
+pub trait AsyncValidate: Sync {
+    /// A user-provided context.
+    ///
+    /// Custom validators receive a reference to this context.
+    type Context: Sync;
+
+    /// Validates `Self`, returning an `Err` with an aggregate of all errors if
+    /// the validation failed.
+    fn validate_async<'a>(&'a self, ctx: &'a Self::Context) -> BoxFuture<'a, Result<(), Errors>>;
+}
+
+impl<T: ?Sized + AsyncValidate> AsyncValidate for &T {
+    type Context = T::Context;
+
+    fn validate_async<'a>(&'a self, ctx: &'a Self::Context) -> BoxFuture<'a, Result<(), Errors>> {
+        <T as AsyncValidate>::validate_async(self, ctx)
+    }
+}
+
+impl<T: ?Sized + AsyncValidate> AsyncValidate for &mut T {
+    type Context = T::Context;
+
+    fn validate_async<'a>(&'a self, ctx: &'a Self::Context) -> BoxFuture<'a, Result<(), Errors>> {
+        <T as AsyncValidate>::validate_async(self, ctx)
+    }
+}
+
+impl<T: ?Sized + AsyncValidate> AsyncValidate for std::boxed::Box<T> {
+    type Context = T::Context;
+
+    fn validate_async<'a>(&'a self, ctx: &'a Self::Context) -> BoxFuture<'a, Result<(), Errors>> {
+        <T as AsyncValidate>::validate_async(self, ctx)
+    }
+}
+
+impl<T: ?Sized + AsyncValidate + Send> AsyncValidate for std::sync::Arc<T> {
+    type Context = T::Context;
+
+    fn validate_async<'a>(&'a self, ctx: &'a Self::Context) -> BoxFuture<'a, Result<(), Errors>> {
+        <T as AsyncValidate>::validate_async(self, ctx)
+    }
+}
+
+impl<T: AsyncValidate> AsyncValidate for Option<T> {
+    type Context = T::Context;
+
+    fn validate_async<'a>(&'a self, ctx: &'a Self::Context) -> BoxFuture<'a, Result<(), Errors>> {
+        match self {
+            Some(value) => <T as AsyncValidate>::validate_async(value, ctx),
+            None => Box::pin(async { Ok(()) }),
+        }
+    }
+}
+
+macro_rules! impl_validate_async_list {
+    (<$T:ident $(, $Other:ident)*> $Container:ty) => {
+        impl<$T, $($Other),*> AsyncValidate for $Container
+        where
+            $T: AsyncValidate,
+            $($Other: Sync,)*
+        {
+            type Context = T::Context;
+
+            fn validate_async<'a>(&'a self, ctx: &'a Self::Context) -> BoxFuture<'a, Result<(), Errors>> {
+                Box::pin(async move {
+                    let mut items = Vec::new();
+                    for item in self.iter() {
+                        items.push(
+                            <T as AsyncValidate>::validate_async(item, ctx)
+                                .await
+                                .err()
+                                .unwrap_or_else(Errors::empty),
+                        );
+                    }
+                    Errors::list(|errors| {
+                        for item in items.drain(..) {
+                            errors.push(item);
+                        }
+                    })
+                    .finish()
+                })
+            }
+        }
+    };
+}
+
+impl_validate_async_list!(<T, S> std::collections::HashSet<T, S>);
+impl_validate_async_list!(<T> std::collections::BTreeSet<T>);
+impl_validate_async_list!(<T> std::collections::BinaryHeap<T>);
+impl_validate_async_list!(<T> std::collections::LinkedList<T>);
+impl_validate_async_list!(<T> std::collections::VecDeque<T>);
+impl_validate_async_list!(<T> std::vec::Vec<T>);
+impl_validate_async_list!(<T> [T]);
+
+impl<T: AsyncValidate, const N: usize> AsyncValidate for [T; N] {
+    type Context = T::Context;
+
+    fn validate_async<'a>(&'a self, ctx: &'a Self::Context) -> BoxFuture<'a, Result<(), Errors>> {
+        <[T] as AsyncValidate>::validate_async(self, ctx)
+    }
+}
+
+macro_rules! impl_validate_async_map {
+    (<$K:ident, $V:ident $(, $Other:ident)*> $Container:ty) => {
+        impl<$K, $V, $($Other),*> AsyncValidate for $Container
+        where
+            $K: std::fmt::Display + Sync,
+            $V: AsyncValidate,
+            $($Other: Sync,)*
+        {
+            type Context = V::Context;
+
+            fn validate_async<'a>(&'a self, ctx: &'a Self::Context) -> BoxFuture<'a, Result<(), Errors>> {
+                Box::pin(async move {
+                    let mut items = Vec::new();
+                    for (key, value) in self.iter() {
+                        items.push((
+                            key,
+                            <V as AsyncValidate>::validate_async(value, ctx)
+                                .await
+                                .err()
+                                .unwrap_or_else(Errors::empty),
+                        ));
+                    }
+                    Errors::map(|errors| {
+                        for (key, item) in items.drain(..) {
+                            errors.insert(key, item);
+                        }
+                    })
+                    .finish()
+                })
+            }
+        }
+    };
+}
+
+impl_validate_async_map!(<K, V, S> std::collections::HashMap<K, V, S>);
+impl_validate_async_map!(<K, V> std::collections::BTreeMap<K, V>);
+
+macro_rules! impl_validate_async_tuple {
+    ($A:ident, $($T:ident),*) => {
+        impl<$A, $($T),*> AsyncValidate for ($A, $($T,)*)
+        where
+            $A: AsyncValidate,
+            $($T: AsyncValidate<Context = $A::Context>,)*
+        {
+            type Context = $A::Context;
+
+            #[allow(non_snake_case)]
+            fn validate_async<'a>(&'a self, ctx: &'a Self::Context) -> BoxFuture<'a, Result<(), Errors>> {
+                Box::pin(async move {
+                    let ($A, $($T,)*) = self;
+                    let mut items = vec![
+                        <$A as AsyncValidate>::validate_async($A, ctx)
+                            .await
+                            .err()
+                            .unwrap_or_else(Errors::empty),
+                        $(
+                            <$T as AsyncValidate>::validate_async($T, ctx)
+                                .await
+                                .err()
+                                .unwrap_or_else(Errors::empty),
+                        )*
+                    ];
+                    Errors::list(|errors| {
+                        for item in items.drain(..) {
+                            errors.push(item);
+                        }
+                    })
+                    .finish()
+                })
+            }
+        }
+    };
+}
+
+impl_validate_async_tuple!(A,);
+impl_validate_async_tuple!(A, B);
+impl_validate_async_tuple!(A, B, C);
+impl_validate_async_tuple!(A, B, C, D);
+impl_validate_async_tuple!(A, B, C, D, E);
+impl_validate_async_tuple!(A, B, C, D, E, F);
+impl_validate_async_tuple!(A, B, C, D, E, F, G);
+impl_validate_async_tuple!(A, B, C, D, E, F, G, H);
+impl_validate_async_tuple!(A, B, C, D, E, F, G, H, I);
+impl_validate_async_tuple!(A, B, C, D, E, F, G, H, I, J);
+impl_validate_async_tuple!(A, B, C, D, E, F, G, H, I, J, K);
+impl_validate_async_tuple!(A, B, C, D, E, F, G, H, I, J, K, L);
+
+impl AsyncValidate for () {
+    type Context = ();
+
+    fn validate_async<'a>(&'a self, _: &'a Self::Context) -> BoxFuture<'a, Result<(), Errors>> {
+        Box::pin(async { Ok(()) })
+    }
+}
+
+/// Runs `future` with a limit of `max_depth` levels of nested validation.
+///
+/// This is the asynchronous counterpart of [`validate::with_max_depth`](crate::validate::with_max_depth).
+/// The limit is carried by the returned future rather than by the current thread, so it still applies
+/// when the validation resumes on a different thread.
+///
+/// ```rust
+/// # use std::future::Future;
+/// # use std::sync::Arc;
+/// # use std::task::{Context, Poll, Wake, Waker};
+/// #
+/// # // a minimal executor; use the one of your runtime instead
+/// # fn block_on<F: Future>(future: F) -> F::Output {
+/// #     struct Noop;
+/// #     impl Wake for Noop {
+/// #         fn wake(self: Arc<Self>) {}
+/// #     }
+/// #     let mut future = Box::pin(future);
+/// #     let waker = Waker::from(Arc::new(Noop));
+/// #     let mut cx = Context::from_waker(&waker);
+/// #     loop {
+/// #         if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+/// #             return output;
+/// #         }
+/// #     }
+/// # }
+/// use garde::AsyncValidate;
+///
+/// #[derive(garde::AsyncValidate)]
+/// struct Node {
+///     #[garde(dive)]
+///     children: Vec<Node>,
+/// }
+///
+/// let node = Node {
+///     children: vec![Node {
+///         children: vec![Node { children: vec![] }],
+///     }],
+/// };
+///
+/// assert!(block_on(garde::validate_async::with_max_depth(3, node.validate_async(&()))).is_ok());
+///
+/// let errors = block_on(garde::validate_async::with_max_depth(2, node.validate_async(&()))).unwrap_err();
+/// assert_eq!(
+///     errors.to_string(),
+///     "value.children[0].children[0].children: maximum validation depth exceeded"
+/// );
+/// ```
+pub fn with_max_depth<'a, F>(max_depth: usize, future: F) -> BoxFuture<'a, F::Output>
+where
+    F: Future + Send + 'a,
+{
+    Box::pin(WithDepth {
+        state: (0, Some(max_depth)),
+        inner: Box::pin(future),
+    })
+}
+
+/// Validates a field marked with `#[garde(dive)]`, respecting the limit set by [`with_max_depth`].
+#[doc(hidden)]
+pub fn dive<'a, T: ?Sized + AsyncValidate>(
+    value: &'a T,
+    ctx: &'a T::Context,
+) -> BoxFuture<'a, Errors> {
+    match crate::validate::deeper() {
+        Ok(state) => {
+            let inner = value.validate_async(ctx);
+            Box::pin(async move {
+                WithDepth { state, inner }
+                    .await
+                    .err()
+                    .unwrap_or_else(Errors::empty)
+            })
+        }
+        Err(error) => Box::pin(async move { Errors::Simple(vec![error]) }),
+    }
+}
+
+/// Polls `inner` with the depth state set to `state`.
+struct WithDepth<'a, T> {
+    state: (usize, Option<usize>),
+    inner: BoxFuture<'a, T>,
+}
+
+impl<'a, T> Future for WithDepth<'a, T> {
+    type Output = T;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let state = self.state;
+        crate::validate::with_depth(state, || self.inner.as_mut().poll(cx))
+    }
+}
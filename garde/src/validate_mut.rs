@@ -0,0 +1,264 @@
+//! ## Validation with a mutable context
+//!
+//! The [`ValidateMut`] trait is the counterpart of [`Validate`][`crate::Validate`] which receives the context
+//! by mutable reference, so that validators may record side effects into it, such as which optional fields were present.
+//! It may be derived using `#[derive(garde::ValidateMut)]`, which accepts the same rules as `#[derive(garde::Validate)]`:
+//!
+//! ```rust
+//! use garde::ValidateMut;
+//!
+//! #[derive(garde::ValidateMut)]
+//! #[garde(context(Seen))]
+//! struct Settings {
+//!     #[garde(custom(record_theme))]
+//!     theme: Option<String>,
+//!     #[garde(dive)]
+//!     profiles: Vec<Profile>,
+//! }
+//!
+//! #[derive(garde::ValidateMut)]
+//! #[garde(context(Seen))]
+//! struct Profile {
+//!     #[garde(custom(record_avatar))]
+//!     avatar: Option<String>,
+//! }
+//!
+//! #[derive(Default)]
+//! struct Seen(Vec<&'static str>);
+//!
+//! fn record_theme(value: &Option<String>, seen: &mut Seen) -> garde::Result {
+//!     if value.is_some() {
+//!         seen.0.push("theme");
+//!     }
+//!     Ok(())
+//! }
+//!
+//! fn record_avatar(value: &Option<String>, seen: &mut Seen) -> garde::Result {
+//!     if value.is_some() {
+//!         seen.0.push("avatar");
+//!     }
+//!     Ok(())
+//! }
+//!
+//! let settings = Settings {
+//!     theme: Some("dark".into()),
+//!     profiles: vec![Profile { avatar: None }, Profile { avatar: Some("cat.png".into()) }],
+//! };
+//! let mut seen = Seen::default();
+//! settings.validate_mut(&mut seen).unwrap();
+//! assert!(seen.0.contains(&"theme") && seen.0.contains(&"avatar"));
+//! ```
+//!
+//! Rules are run in the same order as with [`Validate`][`crate::Validate`], and produce the same errors.
+//! The functions passed to `custom`, `custom_errors`, and `validate = ...` receive the context as `&mut Self::Context`,
+//! though they may also accept it as `&Self::Context`. Fields marked with `dive` are validated using [`ValidateMut`],
+//! which means that their type must implement it, and receive the same mutable context.
+//! Like [`Validate`][`crate::Validate`], it is implemented for containers, maps, and tuples of up to 12 elements.
+
+use crate::error::Errors;
+
+/// The counterpart of [`Validate`][`crate::Validate`] which receives the context by mutable reference.
+pub trait ValidateMut {
+    /// A user-provided context.
+    ///
+    /// Custom validators receive a mutable reference to this context.
+    type Context;
+
+    /// Validates `Self`, returning an `Err` with an aggregate of all errors if
+    /// the validation failed.
+    fn validate_mut(&self, ctx: &mut Self::Context) -> Result<(), Errors>;
+}
+
+impl<T: ?Sized + ValidateMut> ValidateMut for &T {
+    type Context = T::Context;
+
+    fn validate_mut(&self, ctx: &mut Self::Context) -> Result<(), Errors> {
+        <T as ValidateMut>::validate_mut(self, ctx)
+    }
+}
+
+impl<T: ?Sized + ValidateMut> ValidateMut for &mut T {
+    type Context = T::Context;
+
+    fn validate_mut(&self, ctx: &mut Self::Context) -> Result<(), Errors> {
+        <T as ValidateMut>::validate_mut(self, ctx)
+    }
+}
+
+impl<T: ?Sized + ValidateMut> ValidateMut for std::boxed::Box<T> {
+    type Context = T::Context;
+
+    fn validate_mut(&self, ctx: &mut Self::Context) -> Result<(), Errors> {
+        <T as ValidateMut>::validate_mut(self, ctx)
+    }
+}
+
+impl<T: ?Sized + ValidateMut> ValidateMut for std::rc::Rc<T> {
+    type Context = T::Context;
+
+    fn validate_mut(&self, ctx: &mut Self::Context) -> Result<(), Errors> {
+        <T as ValidateMut>::validate_mut(self, ctx)
+    }
+}
+
+impl<T: ?Sized + ValidateMut> ValidateMut for std::sync::Arc<T> {
+    type Context = T::Context;
+
+    fn validate_mut(&self, ctx: &mut Self::Context) -> Result<(), Errors> {
+        <T as ValidateMut>::validate_mut(self, ctx)
+    }
+}
+
+impl<T: ValidateMut> ValidateMut for Option<T> {
+    type Context = T::Context;
+
+    fn validate_mut(&self, ctx: &mut Self::Context) -> Result<(), Errors> {
+        match self {
+            Some(value) => <T as ValidateMut>::validate_mut(value, ctx),
+            None => Ok(()),
+        }
+    }
+}
+
+macro_rules! impl_validate_mut_list {
+    (<$T:ident $(, $Other:ident)*> $Container:ty) => {
+        impl<$T, $($Other),*> ValidateMut for $Container
+        where
+            $T: ValidateMut,
+        {
+            type Context = T::Context;
+
+            fn validate_mut(&self, ctx: &mut Self::Context) -> Result<(), Errors> {
+                let errors = Errors::list(|errors| {
+                    for item in self.iter() {
+                        errors.push(
+                            <T as ValidateMut>::validate_mut(item, ctx)
+                                .err()
+                                .unwrap_or_else(Errors::empty),
+                        )
+                    }
+                });
+                if !errors.is_empty() {
+                    return Err(errors);
+                }
+                Ok(())
+            }
+        }
+    };
+}
+
+impl_validate_mut_list!(<T, S> std::collections::HashSet<T, S>);
+impl_validate_mut_list!(<T> std::collections::BTreeSet<T>);
+impl_validate_mut_list!(<T> std::collections::BinaryHeap<T>);
+impl_validate_mut_list!(<T> std::collections::LinkedList<T>);
+impl_validate_mut_list!(<T> std::collections::VecDeque<T>);
+impl_validate_mut_list!(<T> std::vec::Vec<T>);
+impl_validate_mut_list!(<T> [T]);
+
+impl<T: ValidateMut, const N: usize> ValidateMut for [T; N] {
+    type Context = T::Context;
+
+    fn validate_mut(&self, ctx: &mut Self::Context) -> Result<(), Errors> {
+        <[T] as ValidateMut>::validate_mut(self, ctx)
+    }
+}
+
+macro_rules! impl_validate_mut_map {
+    (<$K:ident, $V:ident $(, $Other:ident)*> $Container:ty) => {
+        impl<$K, $V, $($Other),*> ValidateMut for $Container
+        where
+            $K: std::fmt::Display,
+            $V: ValidateMut,
+        {
+            type Context = V::Context;
+
+            fn validate_mut(&self, ctx: &mut Self::Context) -> Result<(), Errors> {
+                let errors = Errors::map(|errors| {
+                    for (key, value) in self.iter() {
+                        errors.insert(
+                            key,
+                            <V as ValidateMut>::validate_mut(value, ctx)
+                                .err()
+                                .unwrap_or_else(Errors::empty),
+                        )
+                    }
+                });
+                if !errors.is_empty() {
+                    return Err(errors);
+                }
+                Ok(())
+            }
+        }
+    };
+}
+
+impl_validate_mut_map!(<K, V, S> std::collections::HashMap<K, V, S>);
+impl_validate_mut_map!(<K, V> std::collections::BTreeMap<K, V>);
+
+macro_rules! impl_validate_mut_tuple {
+    ($A:ident, $($T:ident),*) => {
+        impl<$A, $($T),*> ValidateMut for ($A, $($T,)*)
+        where
+            $A: ValidateMut,
+            $($T: ValidateMut<Context = $A::Context>,)*
+        {
+            type Context = $A::Context;
+
+            #[allow(non_snake_case)]
+            fn validate_mut(&self, ctx: &mut Self::Context) -> Result<(), Errors> {
+                let errors = Errors::list(|errors| {
+                    let ($A, $($T,)*) = self;
+                    errors.push(
+                        <$A as ValidateMut>::validate_mut($A, ctx)
+                            .err()
+                            .unwrap_or_else(Errors::empty)
+                    );
+                    $(
+                        errors.push(
+                            <$T as ValidateMut>::validate_mut($T, ctx)
+                                .err()
+                                .unwrap_or_else(Errors::empty)
+                        );
+                    )*
+                });
+                if !errors.is_empty() {
+                    return Err(errors);
+                }
+                Ok(())
+            }
+        }
+    };
+}
+
+impl_validate_mut_tuple!(A,);
+impl_validate_mut_tuple!(A, B);
+impl_validate_mut_tuple!(A, B, C);
+impl_validate_mut_tuple!(A, B, C, D);
+impl_validate_mut_tuple!(A, B, C, D, E);
+impl_validate_mut_tuple!(A, B, C, D, E, F);
+impl_validate_mut_tuple!(A, B, C, D, E, F, G);
+impl_validate_mut_tuple!(A, B, C, D, E, F, G, H);
+impl_validate_mut_tuple!(A, B, C, D, E, F, G, H, I);
+impl_validate_mut_tuple!(A, B, C, D, E, F, G, H, I, J);
+impl_validate_mut_tuple!(A, B, C, D, E, F, G, H, I, J, K);
+impl_validate_mut_tuple!(A, B, C, D, E, F, G, H, I, J, K, L);
+
+impl ValidateMut for () {
+    type Context = ();
+
+    fn validate_mut(&self, _: &mut Self::Context) -> Result<(), Errors> {
+        Ok(())
+    }
+}
+
+/// Validates a field marked with `#[garde(dive)]`, respecting the limit set by
+/// [`validate::with_max_depth`](crate::validate::with_max_depth).
+#[doc(hidden)]
+pub fn dive<T: ?Sized + ValidateMut>(value: &T, ctx: &mut T::Context) -> Errors {
+    match crate::validate::deeper() {
+        Ok(state) => crate::validate::with_depth(state, || {
+            value.validate_mut(ctx).err().unwrap_or_else(Errors::empty)
+        }),
+        Err(error) => Errors::Simple(vec![error]),
+    }
+}
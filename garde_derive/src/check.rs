@@ -1,6 +1,6 @@
 use std::collections::BTreeSet;
 
-use proc_macro2::Span;
+use proc_macro2::{Ident, Span};
 use syn::parse_quote;
 use syn::spanned::Spanned;
 
@@ -17,14 +17,30 @@ pub fn check(input: model::Input) -> syn::Result<model::Validate> {
 
     let mut error = None;
 
-    let context = match get_context(&attrs) {
+    let (context, default_context) = match get_context(&attrs) {
         Ok(v) => v,
         Err(e) => {
             error.maybe_fold(e);
-            parse_quote!(())
+            (parse_quote!(()), None)
         }
     };
 
+    let transparent = match check_transparent(&attrs, &kind) {
+        Ok(v) => v,
+        Err(e) => {
+            error.maybe_fold(e);
+            false
+        }
+    };
+
+    let validators = attrs
+        .into_iter()
+        .filter_map(|(_, attr)| match attr {
+            model::Attr::Validate(func) => Some(func.expr()),
+            _ => None,
+        })
+        .collect();
+
     let kind = match kind {
         model::InputKind::Struct(variant) => {
             let variant = match check_variant(variant) {
@@ -60,17 +76,78 @@ pub fn check(input: model::Input) -> syn::Result<model::Validate> {
         ident,
         generics,
         context,
+        default_context,
+        transparent,
+        validators,
         kind,
     })
 }
 
-fn get_context(attrs: &[(Span, model::Attr)]) -> syn::Result<syn::Type> {
+/// Ensures that no asynchronous rules are used, which is required by `#[derive(Validate)]`.
+pub fn check_sync(input: &model::Validate) -> syn::Result<()> {
+    let mut error = None;
+
+    for field in fields(input) {
+        for rule in &field.custom_async_rules {
+            error.maybe_fold(syn::Error::new(
+                rule.span(),
+                "`custom_async` may only be used with `#[derive(AsyncValidate)]`",
+            ));
+        }
+    }
+
+    if let Some(error) = error {
+        return Err(error);
+    }
+
+    Ok(())
+}
+
+/// Ensures that `dive(par)` is not used, as `#[derive(AsyncValidate)]` and `#[derive(ValidateMut)]`
+/// always validate the items of a field one after another.
+pub fn check_sequential(input: &model::Validate) -> syn::Result<()> {
+    let mut error = None;
+
+    for field in fields(input) {
+        if let Some(span) = field.par {
+            error.maybe_fold(syn::Error::new(
+                span,
+                "`dive(par)` may only be used with `#[derive(Validate)]`",
+            ));
+        }
+    }
+
+    if let Some(error) = error {
+        return Err(error);
+    }
+
+    Ok(())
+}
+
+fn fields(input: &model::Validate) -> Vec<&model::ValidateField> {
+    let variants: Vec<&model::ValidateVariant> = match &input.kind {
+        model::ValidateKind::Struct(variant) => vec![variant],
+        model::ValidateKind::Enum(variants) => variants.iter().map(|(_, v)| v).collect(),
+    };
+    variants
+        .into_iter()
+        .flat_map(|variant| -> Vec<&model::ValidateField> {
+            match variant {
+                model::ValidateVariant::Struct(fields) => fields.iter().map(|(_, f)| f).collect(),
+                model::ValidateVariant::Tuple(fields) => fields.iter().collect(),
+            }
+        })
+        .collect()
+}
+
+fn get_context(attrs: &[(Span, model::Attr)]) -> syn::Result<(syn::Type, Option<syn::ExprPath>)> {
     let error = None;
     let mut context = None;
 
     for (_, attr) in attrs {
         match attr {
-            model::Attr::Context(ty) => context = Some(ty),
+            model::Attr::Context(ty, default) => context = Some((ty, default)),
+            model::Attr::Transparent | model::Attr::Validate(_) => {}
         }
     }
 
@@ -79,11 +156,63 @@ fn get_context(attrs: &[(Span, model::Attr)]) -> syn::Result<syn::Type> {
     }
 
     match context {
-        Some(v) => Ok((**v).clone()),
-        None => Ok(parse_quote!(())),
+        Some((ty, default)) => Ok(((**ty).clone(), default.clone())),
+        None => Ok((parse_quote!(()), None)),
+    }
+}
+
+/// Replaces the names of the fields compared to by `field` with their aliases, so that error messages
+/// refer to them by the same name as error paths.
+fn rename_compared_fields(field: &mut model::ValidateField, aliases: &[(Ident, String)]) {
+    field.rules = std::mem::take(&mut field.rules)
+        .into_iter()
+        .map(|mut rule| {
+            if let model::ValidateRuleKind::GreaterThan(compared)
+            | model::ValidateRuleKind::LessThan(compared) = &mut rule.kind
+            {
+                if let Some((_, alias)) = aliases.iter().find(|(ident, _)| ident == &compared.field)
+                {
+                    compared.name = alias.clone();
+                }
+            }
+            rule
+        })
+        .collect();
+    if let Some(keys) = &mut field.keys {
+        rename_compared_fields(keys, aliases);
+    }
+    if let Some(inner) = &mut field.inner {
+        rename_compared_fields(inner, aliases);
+    }
+    if let Some(split) = &mut field.split {
+        rename_compared_fields(&mut split.inner, aliases);
     }
 }
 
+fn check_transparent(attrs: &[(Span, model::Attr)], kind: &model::InputKind) -> syn::Result<bool> {
+    let span = match attrs
+        .iter()
+        .find(|(_, attr)| matches!(attr, model::Attr::Transparent))
+    {
+        Some((span, _)) => *span,
+        None => return Ok(false),
+    };
+
+    let len = match kind {
+        model::InputKind::Struct(model::Variant::Struct(fields)) => fields.len(),
+        model::InputKind::Struct(model::Variant::Tuple(fields)) => fields.len(),
+        model::InputKind::Enum(_) => 0,
+    };
+    if len != 1 {
+        return Err(syn::Error::new(
+            span,
+            "`transparent` may only be used on structs with exactly one field",
+        ));
+    }
+
+    Ok(true)
+}
+
 fn check_variant(variant: model::Variant) -> syn::Result<model::ValidateVariant> {
     let mut error = None;
 
@@ -100,6 +229,58 @@ fn check_variant(variant: model::Variant) -> syn::Result<model::ValidateVariant>
                 };
                 fields.push((ident, field))
             }
+            let mut names = BTreeSet::new();
+            for (ident, field) in fields.iter() {
+                let (span, name) = match &field.alias {
+                    Some(alias) => (alias.span, alias.value.clone()),
+                    None => (ident.span(), ident.to_string()),
+                };
+                if !names.insert(name.clone()) {
+                    error.maybe_fold(syn::Error::new(
+                        span,
+                        format!("duplicate field name `{name}`"),
+                    ));
+                }
+            }
+            let aliases = fields
+                .iter()
+                .filter_map(|(ident, field)| {
+                    Some((ident.clone(), field.alias.as_ref()?.value.clone()))
+                })
+                .collect::<Vec<_>>();
+            if !aliases.is_empty() {
+                for (_, field) in fields.iter_mut() {
+                    rename_compared_fields(field, &aliases);
+                }
+            }
+            for (ident, field) in fields.iter() {
+                for other in field.referenced_fields() {
+                    if other == ident {
+                        error.maybe_fold(syn::Error::new(
+                            other.span(),
+                            "a field may not be compared to itself",
+                        ));
+                    } else if !fields.iter().any(|(name, _)| name == other) {
+                        error.maybe_fold(syn::Error::new(
+                            other.span(),
+                            format!("unknown field `{other}`"),
+                        ));
+                    }
+                }
+                if let Some(when) = &field.when {
+                    if &when.root == ident {
+                        error.maybe_fold(syn::Error::new(
+                            when.span,
+                            "a field may not depend on itself",
+                        ));
+                    } else if !fields.iter().any(|(name, _)| name == &when.root) {
+                        error.maybe_fold(syn::Error::new(
+                            when.root.span(),
+                            format!("unknown field `{}`", when.root),
+                        ));
+                    }
+                }
+            }
             model::ValidateVariant::Struct(fields)
         }
         model::Variant::Tuple(list) => {
@@ -114,6 +295,26 @@ fn check_variant(variant: model::Variant) -> syn::Result<model::ValidateVariant>
                 };
                 fields.push(field);
             }
+            for field in fields.iter() {
+                for other in field.referenced_fields() {
+                    error.maybe_fold(syn::Error::new(
+                        other.span(),
+                        "fields may only be compared in structs with named fields",
+                    ));
+                }
+                if let Some(when) = &field.when {
+                    error.maybe_fold(syn::Error::new(
+                        when.span,
+                        "`when` may only be used in structs with named fields",
+                    ));
+                }
+                if let Some(alias) = &field.alias {
+                    error.maybe_fold(syn::Error::new(
+                        alias.span,
+                        "`rename` may only be used in structs with named fields",
+                    ));
+                }
+            }
             model::ValidateVariant::Tuple(fields)
         }
     };
@@ -125,31 +326,145 @@ fn check_variant(variant: model::Variant) -> syn::Result<model::ValidateVariant>
     Ok(variant)
 }
 
-fn check_field(field: model::Field) -> syn::Result<model::ValidateField> {
-    let mut error = None;
-
-    let model::Field {
-        ty,
-        rules: raw_rules,
-    } = field;
-
-    let mut field = model::ValidateField {
+fn empty_field(ty: syn::Type) -> model::ValidateField {
+    model::ValidateField {
         ty,
         skip: model::Skip {
             span: Span::call_site(),
             value: false,
         },
+        skip_if: None,
+        when: None,
+        unless: None,
         alias: None,
         message: None,
         code: None,
         dive: false,
+        par: None,
         rules: BTreeSet::new(),
         custom_rules: Vec::new(),
+        custom_async_rules: Vec::new(),
+        custom_errors_rules: Vec::new(),
+        keys: None,
+        inner: None,
+        split: None,
+    }
+}
+
+fn check_field(field: model::Field) -> syn::Result<model::ValidateField> {
+    let mut error = None;
+
+    let model::Field {
+        ty,
+        rules: raw_rules,
+    } = field;
+
+    let mut field = empty_field(ty);
+
+    // rules which failed to check are not added to the field,
+    // so it would otherwise be reported as having no validation
+    let rules_ok = match check_rules(&mut field, raw_rules) {
+        Ok(()) => true,
+        Err(e) => {
+            error.maybe_fold(e);
+            false
+        }
     };
 
+    if rules_ok && field.is_empty() && !field.skip.value {
+        error.maybe_fold(syn::Error::new(
+            field.ty.span(),
+            "field has no validation, use `#[garde(skip)]` if this is intentional",
+        ));
+    }
+
+    match &field.unless {
+        Some(unless) if !field.skip.value => error.maybe_fold(syn::Error::new(
+            unless.span(),
+            "`unless` may only be combined with `skip`",
+        )),
+        Some(_) if rules_ok && field.is_empty() => error.maybe_fold(syn::Error::new(
+            field.skip.span,
+            "`skip` with `unless` requires other rules to run",
+        )),
+        // the rules run only if `unless` returns `true`, so the field is not skipped entirely
+        Some(_) => field.skip.value = false,
+        None => {
+            if field.skip.value
+                && (!field.is_empty() || field.skip_if.is_some() || field.when.is_some())
+            {
+                error.maybe_fold(syn::Error::new(
+                    field.skip.span,
+                    "`skip` may not be combined with other rules",
+                ))
+            }
+        }
+    }
+
+    if let Some(error) = error {
+        return Err(error);
+    }
+
+    Ok(field)
+}
+
+/// Checks the rules nested under another rule, such as `keys(...)`.
+///
+/// `ty` is the type of the value the nested rules are applied to.
+fn check_nested_field(
+    name: &str,
+    ty: syn::Type,
+    list: model::List<model::RawRule>,
+) -> syn::Result<model::ValidateField> {
+    let mut error = None;
+
+    let mut field = empty_field(ty);
+
+    if let Err(e) = check_rules(&mut field, list.contents) {
+        error.maybe_fold(e);
+    }
+
+    if field.skip.value
+        || field.skip_if.is_some()
+        || field.when.is_some()
+        || field.unless.is_some()
+        || field.alias.is_some()
+        || field.dive
+        || field.keys.is_some()
+        || field.inner.is_some()
+        || field.split.is_some()
+        || !field.custom_async_rules.is_empty()
+        || !field.custom_errors_rules.is_empty()
+    {
+        error.maybe_fold(syn::Error::new(
+            list.span,
+            format!("`{name}` may only contain validation rules"),
+        ));
+    }
+
+    if field.is_empty() {
+        error.maybe_fold(syn::Error::new(
+            list.span,
+            format!("`{name}` must contain at least one rule"),
+        ));
+    }
+
+    if let Some(error) = error {
+        return Err(error);
+    }
+
+    Ok(field)
+}
+
+fn check_rules(
+    field: &mut model::ValidateField,
+    raw_rules: Vec<model::RawRule>,
+) -> syn::Result<()> {
+    let mut error = None;
+
     for raw_rule in raw_rules {
         let span = raw_rule.span;
-        match check_rule(&mut field, raw_rule, 0) {
+        match check_rule(field, raw_rule, 0) {
             Ok(Some(v)) => {
                 if field.rules.contains(&v) {
                     error.maybe_fold(syn::Error::new(
@@ -165,25 +480,11 @@ fn check_field(field: model::Field) -> syn::Result<model::ValidateField> {
         }
     }
 
-    if field.is_empty() && !field.skip.value {
-        error.maybe_fold(syn::Error::new(
-            field.ty.span(),
-            "field has no validation, use `#[garde(skip)]` if this is intentional",
-        ));
-    }
-
-    if field.skip.value && !field.is_empty() {
-        error.maybe_fold(syn::Error::new(
-            field.skip.span,
-            "`skip` may not be combined with other rules",
-        ))
-    }
-
     if let Some(error) = error {
         return Err(error);
     }
 
-    Ok(field)
+    Ok(())
 }
 
 fn check_rule(
@@ -203,18 +504,47 @@ fn check_rule(
             };
             None
         }
+        model::RawRuleKind::SkipIf(func) => {
+            if field.skip_if.is_some() {
+                return Err(syn::Error::new(raw_rule.span, "duplicate rule `skip_if`"));
+            }
+            field.skip_if = Some(func.expr());
+            None
+        }
+        model::RawRuleKind::When(path) => {
+            if field.when.is_some() {
+                return Err(syn::Error::new(raw_rule.span, "duplicate rule `when`"));
+            }
+            field.when = Some(path);
+            None
+        }
+        model::RawRuleKind::Unless(func) => {
+            if field.unless.is_some() {
+                return Err(syn::Error::new(raw_rule.span, "duplicate rule `unless`"));
+            }
+            field.unless = Some(func.expr());
+            None
+        }
         model::RawRuleKind::Rename(alias) => {
             if field.alias.is_some() {
                 return Err(syn::Error::new(raw_rule.span, "duplicate rule `rename`"));
             }
-            field.alias = Some(alias.value);
+            field.alias = Some(alias);
             None
         }
         model::RawRuleKind::Message(message) => {
             if field.message.is_some() {
                 return Err(syn::Error::new(raw_rule.span, "duplicate rule `message`"));
             }
-            field.message = Some(message);
+            match message {
+                model::Message::Fmt(message) => field.message = Some(message.value),
+                model::Message::Func(_) => {
+                    return Err(syn::Error::new(
+                        raw_rule.span,
+                        "`message` must be a string literal",
+                    ))
+                }
+            }
             None
         }
         model::RawRuleKind::Code(code) => {
@@ -224,78 +554,214 @@ fn check_rule(
             field.code = Some(code.value);
             None
         }
-        model::RawRuleKind::Dive => {
+        model::RawRuleKind::Dive(dive) => {
             if field.dive {
                 return Err(syn::Error::new(raw_rule.span, "duplicate rule `dive`"));
             }
+            if field.inner.is_some() {
+                return Err(syn::Error::new(
+                    raw_rule.span,
+                    "`dive` may not be combined with `inner`",
+                ));
+            }
+            if field.split.is_some() {
+                return Err(syn::Error::new(
+                    raw_rule.span,
+                    "`dive` may not be combined with `split`",
+                ));
+            }
             field.dive = true;
+            field.par = check_dive_mode(dive)?;
             None
         }
         model::RawRuleKind::Custom(custom) => {
             field.custom_rules.push(custom.expr());
             None
         }
-        model::RawRuleKind::Ascii => Some(model::ValidateRule {
+        model::RawRuleKind::CustomAsync(custom) => {
+            field.custom_async_rules.push(custom.expr());
+            None
+        }
+        model::RawRuleKind::CustomErrors(custom) => {
+            field.custom_errors_rules.push(custom.expr());
+            None
+        }
+        model::RawRuleKind::Keys(list) => {
+            if field.keys.is_some() {
+                return Err(syn::Error::new(raw_rule.span, "duplicate rule `keys`"));
+            }
+            let ty = &field.ty;
+            let ty = parse_quote!(<#ty as ::garde::rules::keys::Keys>::Key);
+            field.keys = Some(Box::new(check_nested_field("keys", ty, list)?));
+            None
+        }
+        model::RawRuleKind::Ascii(v) => Some(model::ValidateRule {
+            depth,
+            kind: check_ascii_kind(v)?,
+        }),
+        model::RawRuleKind::Alphanumeric(v) => Some(model::ValidateRule {
             depth,
-            kind: model::ValidateRuleKind::Ascii,
+            kind: model::ValidateRuleKind::Alphanumeric(check_alphanumeric_kind(v)?),
         }),
-        model::RawRuleKind::Alphanumeric => Some(model::ValidateRule {
+        model::RawRuleKind::Trimmed => Some(model::ValidateRule {
             depth,
-            kind: model::ValidateRuleKind::Alphanumeric,
+            kind: model::ValidateRuleKind::Trimmed,
+        }),
+        model::RawRuleKind::Required(v) => Some(model::ValidateRule {
+            depth,
+            kind: model::ValidateRuleKind::Required(v.non_empty, v.message.map(|m| m.value)),
+        }),
+        model::RawRuleKind::NonControl(v) => Some(model::ValidateRule {
+            depth,
+            kind: model::ValidateRuleKind::NonControl(v.allow_whitespace),
+        }),
+        model::RawRuleKind::Lowercase => Some(model::ValidateRule {
+            depth,
+            kind: model::ValidateRuleKind::Lowercase,
+        }),
+        model::RawRuleKind::Uppercase => Some(model::ValidateRule {
+            depth,
+            kind: model::ValidateRuleKind::Uppercase,
         }),
         model::RawRuleKind::Email => Some(model::ValidateRule {
             depth,
             kind: model::ValidateRuleKind::Email,
         }),
-        model::RawRuleKind::Url => Some(model::ValidateRule {
+        model::RawRuleKind::Url(v) => Some(model::ValidateRule {
+            depth,
+            kind: {
+                let (schemes, hosts) = check_url(v)?;
+                model::ValidateRuleKind::Url(schemes, hosts)
+            },
+        }),
+        model::RawRuleKind::Ip(v) => Some(model::ValidateRule {
+            depth,
+            kind: model::ValidateRuleKind::Ip(check_ip_scope(v)?),
+        }),
+        model::RawRuleKind::IpV4(v) => Some(model::ValidateRule {
+            depth,
+            kind: model::ValidateRuleKind::IpV4(check_ip_scope(v)?),
+        }),
+        model::RawRuleKind::IpV6(v) => Some(model::ValidateRule {
+            depth,
+            kind: model::ValidateRuleKind::IpV6(check_ip_scope(v)?),
+        }),
+        model::RawRuleKind::CreditCard(v) => Some(model::ValidateRule {
+            depth,
+            kind: model::ValidateRuleKind::CreditCard(check_card_types(v)?),
+        }),
+        model::RawRuleKind::Luhn => Some(model::ValidateRule {
+            depth,
+            kind: model::ValidateRuleKind::Luhn,
+        }),
+        model::RawRuleKind::Iban => Some(model::ValidateRule {
+            depth,
+            kind: model::ValidateRuleKind::Iban,
+        }),
+        model::RawRuleKind::Isbn(v) => Some(model::ValidateRule {
+            depth,
+            kind: model::ValidateRuleKind::Isbn(check_isbn_version(v)?),
+        }),
+        model::RawRuleKind::Json => Some(model::ValidateRule {
+            depth,
+            kind: model::ValidateRuleKind::Json,
+        }),
+        model::RawRuleKind::Uuid(v) => Some(model::ValidateRule {
+            depth,
+            kind: model::ValidateRuleKind::Uuid(check_uuid_version(v)?),
+        }),
+        model::RawRuleKind::HexColor(v) => Some(model::ValidateRule {
             depth,
-            kind: model::ValidateRuleKind::Url,
+            kind: model::ValidateRuleKind::HexColor(v.alpha.map_or(true, |alpha| alpha.value)),
         }),
-        model::RawRuleKind::Ip => Some(model::ValidateRule {
+        model::RawRuleKind::Port(v) => Some(model::ValidateRule {
             depth,
-            kind: model::ValidateRuleKind::Ip,
+            kind: model::ValidateRuleKind::Port(
+                v.privileged.map_or(true, |privileged| privileged.value),
+            ),
         }),
-        model::RawRuleKind::IpV4 => Some(model::ValidateRule {
+        model::RawRuleKind::Semver(v) => Some(model::ValidateRule {
             depth,
-            kind: model::ValidateRuleKind::IpV4,
+            kind: model::ValidateRuleKind::Semver(check_semver_kind(v)?),
         }),
-        model::RawRuleKind::IpV6 => Some(model::ValidateRule {
+        model::RawRuleKind::Base64(v) => Some(model::ValidateRule {
             depth,
-            kind: model::ValidateRuleKind::IpV6,
+            kind: model::ValidateRuleKind::Base64(check_base64_kind(v)?),
         }),
-        model::RawRuleKind::CreditCard => Some(model::ValidateRule {
+        model::RawRuleKind::PhoneNumber(v) => Some(model::ValidateRule {
             depth,
-            kind: model::ValidateRuleKind::CreditCard,
+            kind: model::ValidateRuleKind::PhoneNumber(check_phone_number_region(v)?),
         }),
-        model::RawRuleKind::PhoneNumber => Some(model::ValidateRule {
+        model::RawRuleKind::Date(v) => Some(model::ValidateRule {
             depth,
-            kind: model::ValidateRuleKind::PhoneNumber,
+            kind: model::ValidateRuleKind::Date(
+                check_datetime_format(v)?.unwrap_or_else(|| String::from("%Y-%m-%d")),
+            ),
         }),
-        model::RawRuleKind::Length(v) => Some(model::ValidateRule {
+        model::RawRuleKind::DateTime(v) => Some(model::ValidateRule {
             depth,
-            kind: model::ValidateRuleKind::Length(check_range(v)?),
+            kind: model::ValidateRuleKind::DateTime(check_datetime_format(v)?),
         }),
+        model::RawRuleKind::Length(v) => {
+            let range = check_range(v.range)?;
+            let kind = match v.unit {
+                model::LengthUnit::Chars => model::ValidateRuleKind::Length(range),
+                model::LengthUnit::Bytes => model::ValidateRuleKind::ByteLength(range),
+                model::LengthUnit::Graphemes => model::ValidateRuleKind::GraphemeLength(range),
+            };
+            Some(model::ValidateRule { depth, kind })
+        }
+        // the same as `length(bytes, ...)`
         model::RawRuleKind::ByteLength(v) => Some(model::ValidateRule {
             depth,
             kind: model::ValidateRuleKind::ByteLength(check_range(v)?),
         }),
         model::RawRuleKind::Range(v) => Some(model::ValidateRule {
             depth,
-            kind: model::ValidateRuleKind::Range(check_range_not_ord(v)?),
+            kind: model::ValidateRuleKind::Range(check_bounds(v)?),
+        }),
+        model::RawRuleKind::MultipleOf(v) => Some(model::ValidateRule {
+            depth,
+            kind: model::ValidateRuleKind::MultipleOf(check_multiple_of(v)?),
+        }),
+        model::RawRuleKind::GreaterThan(v) => Some(model::ValidateRule {
+            depth,
+            kind: model::ValidateRuleKind::GreaterThan(model::Compared {
+                name: v.to_string(),
+                field: v,
+            }),
+        }),
+        model::RawRuleKind::LessThan(v) => Some(model::ValidateRule {
+            depth,
+            kind: model::ValidateRuleKind::LessThan(model::Compared {
+                name: v.to_string(),
+                field: v,
+            }),
+        }),
+        model::RawRuleKind::OneOf(v) => Some(model::ValidateRule {
+            depth,
+            kind: model::ValidateRuleKind::OneOf(check_one_of(v)?),
         }),
         model::RawRuleKind::Contains(v) => Some(model::ValidateRule {
             depth,
-            kind: model::ValidateRuleKind::Contains(v.value),
+            kind: model::ValidateRuleKind::Contains(v.pattern.value, v.insensitive),
+        }),
+        model::RawRuleKind::NotContains(v) => Some(model::ValidateRule {
+            depth,
+            kind: model::ValidateRuleKind::NotContains(
+                check_patterns("not_contains", v.patterns)?,
+                v.insensitive,
+            ),
         }),
         model::RawRuleKind::Prefix(v) => Some(model::ValidateRule {
             depth,
-            kind: model::ValidateRuleKind::Prefix(v.value),
+            kind: model::ValidateRuleKind::Prefix(check_patterns("prefix", v)?),
         }),
         model::RawRuleKind::Suffix(v) => Some(model::ValidateRule {
             depth,
-            kind: model::ValidateRuleKind::Suffix(v.value),
+            kind: model::ValidateRuleKind::Suffix(check_patterns("suffix", v)?),
         }),
-        model::RawRuleKind::Pattern(v) => {
+        model::RawRuleKind::Pattern(model::Pattern::Lit(v)) => {
             #[cfg(feature = "regex")]
             {
                 if let Err(e) = regex::Regex::new(&v.value) {
@@ -307,29 +773,73 @@ fn check_rule(
             }
             Some(model::ValidateRule {
                 depth,
-                kind: model::ValidateRuleKind::Pattern(v.value),
+                kind: model::ValidateRuleKind::Pattern(model::ValidatePattern::Lit(v.value)),
             })
         }
-        model::RawRuleKind::Inner(v) => {
-            return Err(syn::Error::new(
-                v.span,
-                "`inner` rule is not yet implemented ",
-            ));
-            // check_rule(field, *v, depth + 1)?
+        model::RawRuleKind::Pattern(model::Pattern::Func(v)) => Some(model::ValidateRule {
+            depth,
+            kind: model::ValidateRuleKind::Pattern(model::ValidatePattern::Func(v)),
+        }),
+        model::RawRuleKind::Inner(list) => {
+            if field.inner.is_some() {
+                return Err(syn::Error::new(raw_rule.span, "duplicate rule `inner`"));
+            }
+            if field.dive {
+                return Err(syn::Error::new(
+                    raw_rule.span,
+                    "`inner` may not be combined with `dive`",
+                ));
+            }
+            if field.split.is_some() {
+                return Err(syn::Error::new(
+                    raw_rule.span,
+                    "`inner` may not be combined with `split`",
+                ));
+            }
+            let ty = &field.ty;
+            let ty = parse_quote!(<#ty as ::garde::rules::inner::Inner>::Item);
+            field.inner = Some(Box::new(check_nested_field("inner", ty, list)?));
+            None
+        }
+        model::RawRuleKind::Split(split) => {
+            if field.split.is_some() {
+                return Err(syn::Error::new(raw_rule.span, "duplicate rule `split`"));
+            }
+            if field.dive || field.inner.is_some() {
+                return Err(syn::Error::new(
+                    raw_rule.span,
+                    "`split` may not be combined with `dive` or `inner`",
+                ));
+            }
+            field.split = Some(Box::new(check_split(raw_rule.span, split)?));
+            None
         }
     };
 
     Ok(rule)
 }
 
-trait CheckRange: Sized {
-    fn check_range(self) -> syn::Result<model::ValidateRange<Self>>;
-}
-
 fn check_range<T>(range: model::Range<T>) -> syn::Result<model::ValidateRange<T>>
 where
     T: PartialOrd,
 {
+    if range.exclusive_min.is_some() || range.exclusive_max.is_some() {
+        return Err(syn::Error::new(
+            range.span,
+            "`exclusive_min` and `exclusive_max` are only supported by `range`",
+        ));
+    }
+
+    if let Some(equal) = range.equal {
+        if range.min.is_some() || range.max.is_some() {
+            return Err(syn::Error::new(
+                range.span,
+                "`equal` may not be combined with `min` or `max`",
+            ));
+        }
+        return Ok(model::ValidateRange::Equal(equal));
+    }
+
     match (range.min, range.max) {
         (Some(min), Some(max)) if min < max => Ok(model::ValidateRange::Between(min, max)),
         (Some(_), Some(_)) => Err(syn::Error::new(
@@ -345,14 +855,456 @@ where
     }
 }
 
-fn check_range_not_ord<T>(range: model::Range<T>) -> syn::Result<model::ValidateRange<T>> {
-    match (range.min, range.max) {
-        (Some(min), Some(max)) => Ok(model::ValidateRange::Between(min, max)),
-        (Some(min), None) => Ok(model::ValidateRange::GreaterThan(min)),
-        (None, Some(max)) => Ok(model::ValidateRange::LowerThan(max)),
-        (None, None) => Err(syn::Error::new(
-            range.span,
-            "range must have at least one of `min`, `max`",
+fn check_multiple_of(divisor: syn::Expr) -> syn::Result<syn::Expr> {
+    if let syn::Expr::Lit(syn::ExprLit {
+        lit: syn::Lit::Int(lit),
+        ..
+    }) = &divisor
+    {
+        if lit.base10_digits().bytes().all(|b| b == b'0') {
+            return Err(syn::Error::new(lit.span(), "divisor must not be zero"));
+        }
+    }
+    Ok(divisor)
+}
+
+fn check_bounds(range: model::Range<syn::Expr>) -> syn::Result<model::ValidateBounds> {
+    let model::Range {
+        span,
+        min,
+        max,
+        exclusive_min,
+        exclusive_max,
+        equal,
+    } = range;
+
+    if equal.is_some() {
+        return Err(syn::Error::new(
+            span,
+            "`equal` is only supported by `length` and `byte_length`",
+        ));
+    }
+
+    let min = match (min, exclusive_min) {
+        (Some(_), Some(_)) => {
+            return Err(syn::Error::new(
+                span,
+                "`min` may not be combined with `exclusive_min`",
+            ))
+        }
+        (Some(v), None) => Some(model::ValidateBound::Inclusive(v)),
+        (None, Some(v)) => Some(model::ValidateBound::Exclusive(v)),
+        (None, None) => None,
+    };
+    let max = match (max, exclusive_max) {
+        (Some(_), Some(_)) => {
+            return Err(syn::Error::new(
+                span,
+                "`max` may not be combined with `exclusive_max`",
+            ))
+        }
+        (Some(v), None) => Some(model::ValidateBound::Inclusive(v)),
+        (None, Some(v)) => Some(model::ValidateBound::Exclusive(v)),
+        (None, None) => None,
+    };
+
+    if let (Some(min), Some(max)) = (&min, &max) {
+        // bounds can only be compared if both of them are numeric literals
+        if let (Some(lower), Some(upper)) = (min.literal_value(), max.literal_value()) {
+            let exclusive = matches!(min, model::ValidateBound::Exclusive(_))
+                || matches!(max, model::ValidateBound::Exclusive(_));
+            if lower > upper || (lower == upper && exclusive) {
+                return Err(syn::Error::new(
+                    span,
+                    "range is empty, validation would always fail",
+                ));
+            }
+        }
+    }
+
+    if min.is_none() && max.is_none() {
+        return Err(syn::Error::new(
+            span,
+            "range must have at least one of `min`, `max`, `exclusive_min`, `exclusive_max`",
+        ));
+    }
+
+    Ok(model::ValidateBounds { min, max })
+}
+
+/// Card brand names as reported by `garde::rules::credit_card::CardBrand::name`.
+const CARD_TYPES: &[&str] = &[
+    "visaelectron",
+    "maestro",
+    "forbrugsforeningen",
+    "dankort",
+    "visa",
+    "mir",
+    "mastercard",
+    "amex",
+    "dinersclub",
+    "discover",
+    "unionpay",
+    "jcb",
+];
+
+fn check_url(url: model::Url) -> syn::Result<(Vec<String>, Vec<String>)> {
+    let mut error = None;
+    let mut schemes = Vec::new();
+    let mut hosts = Vec::new();
+
+    let url_schemes = match url.schemes {
+        Some(list) if list.contents.is_empty() => {
+            error.maybe_fold(syn::Error::new(
+                list.span,
+                "`schemes` must contain at least one scheme",
+            ));
+            Vec::new()
+        }
+        Some(list) => list.contents,
+        None => Vec::new(),
+    };
+    let url_hosts = match url.hosts {
+        Some(list) if list.contents.is_empty() => {
+            error.maybe_fold(syn::Error::new(
+                list.span,
+                "`host` must contain at least one host",
+            ));
+            Vec::new()
+        }
+        Some(list) => list.contents,
+        None => Vec::new(),
+    };
+
+    for scheme in url_schemes {
+        // https://url.spec.whatwg.org/#url-scheme-string
+        let mut chars = scheme.value.chars();
+        let valid = chars.next().map_or(false, |c| c.is_ascii_lowercase())
+            && chars.all(|c| {
+                c.is_ascii_lowercase() || c.is_ascii_digit() || c == '+' || c == '-' || c == '.'
+            });
+        if !valid {
+            error.maybe_fold(syn::Error::new(
+                scheme.span,
+                "invalid url scheme, expected a lowercase ASCII scheme such as `https`",
+            ));
+            continue;
+        }
+        if schemes.contains(&scheme.value) {
+            error.maybe_fold(syn::Error::new(scheme.span, "duplicate url scheme"));
+            continue;
+        }
+        schemes.push(scheme.value);
+    }
+
+    for host in url_hosts {
+        if host.value.is_empty() {
+            error.maybe_fold(syn::Error::new(host.span, "url host may not be empty"));
+            continue;
+        }
+        let value = host.value.to_ascii_lowercase();
+        if hosts.contains(&value) {
+            error.maybe_fold(syn::Error::new(host.span, "duplicate url host"));
+            continue;
+        }
+        hosts.push(value);
+    }
+
+    if let Some(error) = error {
+        return Err(error);
+    }
+
+    Ok((schemes, hosts))
+}
+
+fn check_isbn_version(isbn: model::Isbn) -> syn::Result<Option<usize>> {
+    let lit = match isbn.version {
+        Some(lit) => lit,
+        None => return Ok(None),
+    };
+    match lit.base10_parse::<usize>() {
+        Ok(version @ (10 | 13)) => Ok(Some(version)),
+        _ => Err(syn::Error::new(
+            lit.span(),
+            "invalid isbn version, expected `10` or `13`",
+        )),
+    }
+}
+
+fn check_uuid_version(uuid: model::Uuid) -> syn::Result<Option<usize>> {
+    let lit = match uuid.version {
+        Some(lit) => lit,
+        None => return Ok(None),
+    };
+    match lit.base10_parse::<usize>() {
+        Ok(version @ 1..=8) => Ok(Some(version)),
+        _ => Err(syn::Error::new(
+            lit.span(),
+            "invalid uuid version, expected a number between 1 and 8",
+        )),
+    }
+}
+
+fn check_dive_mode(dive: model::Dive) -> syn::Result<Option<Span>> {
+    match dive.mode {
+        None => Ok(None),
+        Some(ident) if ident == "par" => Ok(Some(ident.span())),
+        Some(ident) => Err(syn::Error::new(
+            ident.span(),
+            "unknown dive mode, expected `par`",
+        )),
+    }
+}
+
+fn check_ascii_kind(ascii: model::Ascii) -> syn::Result<model::ValidateRuleKind> {
+    match ascii.kind {
+        None => Ok(model::ValidateRuleKind::Ascii),
+        Some(ident) if ident == "bytes" => Ok(model::ValidateRuleKind::AsciiBytes),
+        Some(ident) => Err(syn::Error::new(
+            ident.span(),
+            "unknown ascii mode, expected `bytes`",
+        )),
+    }
+}
+
+fn check_ip_scope(ip: model::Ip) -> syn::Result<model::IpScope> {
+    match ip.scope {
+        None => Ok(model::IpScope::Any),
+        Some(ident) if ident == "public" => Ok(model::IpScope::Public),
+        Some(ident) if ident == "private" => Ok(model::IpScope::Private),
+        Some(ident) if ident == "loopback" => Ok(model::IpScope::Loopback),
+        Some(ident) => Err(syn::Error::new(
+            ident.span(),
+            "unknown ip scope, expected `public`, `private`, or `loopback`",
+        )),
+    }
+}
+
+fn check_alphanumeric_kind(
+    alphanumeric: model::Alphanumeric,
+) -> syn::Result<model::AlphanumericKind> {
+    match alphanumeric.kind {
+        None => Ok(model::AlphanumericKind::Unicode),
+        Some(ident) if ident == "unicode" => Ok(model::AlphanumericKind::Unicode),
+        Some(ident) if ident == "ascii" => Ok(model::AlphanumericKind::Ascii),
+        Some(ident) => Err(syn::Error::new(
+            ident.span(),
+            "unknown alphanumeric variant, expected `unicode` or `ascii`",
+        )),
+    }
+}
+
+fn check_semver_kind(semver: model::Semver) -> syn::Result<model::SemverKind> {
+    match semver.kind {
+        None => Ok(model::SemverKind::Version),
+        Some(ident) if ident == "version" => Ok(model::SemverKind::Version),
+        Some(ident) if ident == "req" => Ok(model::SemverKind::Req),
+        Some(ident) => Err(syn::Error::new(
+            ident.span(),
+            "unknown semver mode, expected `version` or `req`",
         )),
     }
 }
+
+fn check_base64_kind(base64: model::Base64) -> syn::Result<model::Base64Kind> {
+    match base64.kind {
+        None => Ok(model::Base64Kind::Standard),
+        Some(ident) if ident == "standard" => Ok(model::Base64Kind::Standard),
+        Some(ident) if ident == "url_safe" => Ok(model::Base64Kind::UrlSafe),
+        Some(ident) => Err(syn::Error::new(
+            ident.span(),
+            "unknown base64 variant, expected `standard` or `url_safe`",
+        )),
+    }
+}
+
+fn check_datetime_format(datetime: model::DateTime) -> syn::Result<Option<String>> {
+    match datetime.format {
+        Some(format) if format.value.is_empty() => {
+            Err(syn::Error::new(format.span, "format may not be empty"))
+        }
+        Some(format) => Ok(Some(format.value)),
+        None => Ok(None),
+    }
+}
+
+fn check_phone_number_region(phone_number: model::PhoneNumber) -> syn::Result<Option<Ident>> {
+    let region = match phone_number.region {
+        Some(region) => region,
+        None => return Ok(None),
+    };
+    // unknown regions of the right shape are rejected when the emitted code is compiled
+    if region.value.len() != 2 || !region.value.bytes().all(|b| b.is_ascii_uppercase()) {
+        return Err(syn::Error::new(
+            region.span,
+            "invalid region, expected an ISO 3166-1 alpha-2 country code such as \"FR\"",
+        ));
+    }
+    Ok(Some(Ident::new(&region.value, region.span)))
+}
+
+fn check_split(span: Span, split: model::Split) -> syn::Result<model::ValidateSplit> {
+    let mut error = None;
+
+    if split.delimiter.value.is_empty() {
+        error.maybe_fold(syn::Error::new(
+            split.delimiter.span,
+            "`split` delimiter may not be empty",
+        ));
+    }
+
+    let empty = match split.empty {
+        Some(ident) if ident == "skip_empty" => model::EmptySegments::Skip,
+        Some(_) => model::EmptySegments::Deny,
+        None => model::EmptySegments::Validate,
+    };
+
+    let inner = match split.inner {
+        Some(list) => check_nested_field("split", parse_quote!(&str), list),
+        None => Err(syn::Error::new(
+            span,
+            "`split` must contain `inner(...)` with the rules for each segment",
+        )),
+    };
+
+    match (inner, error) {
+        (Ok(inner), None) => Ok(model::ValidateSplit {
+            delimiter: split.delimiter.value,
+            empty,
+            inner,
+        }),
+        (Ok(_), Some(error)) | (Err(error), None) => Err(error),
+        (Err(e), Some(mut error)) => {
+            error.combine(e);
+            Err(error)
+        }
+    }
+}
+
+/// Checks the alternatives of rules such as `prefix(...)`, at least one of which must be present.
+fn check_patterns(name: &str, list: model::List<model::Str>) -> syn::Result<Vec<String>> {
+    let mut error = None;
+    let mut patterns = Vec::new();
+
+    if list.contents.is_empty() {
+        return Err(syn::Error::new(
+            list.span,
+            format!("`{name}` must contain at least one pattern"),
+        ));
+    }
+
+    for pattern in list.contents {
+        if patterns.contains(&pattern.value) {
+            error.maybe_fold(syn::Error::new(
+                pattern.span,
+                format!("duplicate {name} pattern"),
+            ));
+            continue;
+        }
+        patterns.push(pattern.value);
+    }
+
+    if let Some(error) = error {
+        return Err(error);
+    }
+
+    Ok(patterns)
+}
+
+/// Checks the allowed values of `one_of(...)`, which must all be strings or all be integers.
+fn check_one_of(one_of: model::OneOf) -> syn::Result<model::ValidateOneOf> {
+    let mut error = None;
+    let mut strings = Vec::new();
+    let mut ints = Vec::new();
+    // integers are compared by their digits, so that e.g. `1` and `1u8` are duplicates
+    let mut seen_ints = Vec::new();
+
+    if one_of.values.is_empty() {
+        return Err(syn::Error::new(
+            one_of.span,
+            "`one_of` must contain at least one value",
+        ));
+    }
+
+    for value in one_of.values {
+        match value {
+            model::OneOfValue::Str(v) => {
+                if strings.contains(&v.value) {
+                    error.maybe_fold(syn::Error::new(v.span, "duplicate one_of value"));
+                    continue;
+                }
+                strings.push(v.value);
+            }
+            model::OneOfValue::Int { negative, lit } => {
+                let digits = (negative, lit.base10_digits().to_string());
+                if seen_ints.contains(&digits) {
+                    error.maybe_fold(syn::Error::new(lit.span(), "duplicate one_of value"));
+                    continue;
+                }
+                seen_ints.push(digits);
+                ints.push(if negative {
+                    syn::parse_quote!(-#lit)
+                } else {
+                    syn::parse_quote!(#lit)
+                });
+            }
+        }
+    }
+
+    if !strings.is_empty() && !ints.is_empty() {
+        error.maybe_fold(syn::Error::new(
+            one_of.span,
+            "`one_of` values must either all be strings or all be integers",
+        ));
+    }
+
+    if let (Some(span), false) = (one_of.insensitive, ints.is_empty()) {
+        error.maybe_fold(syn::Error::new(
+            span,
+            "`insensitive` is only supported by string values",
+        ));
+    }
+
+    if let Some(error) = error {
+        return Err(error);
+    }
+
+    if ints.is_empty() {
+        Ok(model::ValidateOneOf::Str(
+            strings,
+            one_of.insensitive.is_some(),
+        ))
+    } else {
+        Ok(model::ValidateOneOf::Int(ints))
+    }
+}
+
+fn check_card_types(card: model::CreditCard) -> syn::Result<Vec<String>> {
+    let mut error = None;
+    let mut types = Vec::new();
+
+    for ty in card.types {
+        if !CARD_TYPES.contains(&ty.value.as_str()) {
+            error.maybe_fold(syn::Error::new(
+                ty.span,
+                format!(
+                    "unknown card type `{}`, expected one of: {}",
+                    ty.value,
+                    CARD_TYPES.join(", ")
+                ),
+            ));
+            continue;
+        }
+        if types.contains(&ty.value) {
+            error.maybe_fold(syn::Error::new(ty.span, "duplicate card type"));
+            continue;
+        }
+        types.push(ty.value);
+    }
+
+    if let Some(error) = error {
+        return Err(error);
+    }
+
+    Ok(types)
+}
@@ -7,12 +7,53 @@ pub fn emit(input: model::Validate) -> TokenStream2 {
     input.to_token_stream()
 }
 
+pub fn emit_async(input: model::Validate) -> TokenStream2 {
+    AsyncValidate(&input).to_token_stream()
+}
+
+pub fn emit_mut(input: model::Validate) -> TokenStream2 {
+    ValidateMut(&input).to_token_stream()
+}
+
 impl ToTokens for model::Validate {
     fn to_tokens(&self, tokens: &mut TokenStream2) {
         let ident = &self.ident;
         let context_ty = &self.context;
         let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
-        let kind = &self.kind;
+        let kind = with_validators(
+            &self.validators,
+            ValidationKind(&self.kind, self.transparent).to_token_stream(),
+        );
+        let first_error = first_error_with_validators(
+            &self.validators,
+            FirstErrorKind(&self.kind, self.transparent).to_token_stream(),
+        );
+        let default_context = match &self.default_context {
+            Some(func) => quote! {
+                impl #impl_generics ::garde::validate::ValidateDefault for #ident #ty_generics #where_clause {
+                    fn default_context() -> Self::Context {
+                        #func()
+                    }
+                }
+            },
+            None => {
+                // the bound is higher-ranked so that it is only checked where the impl is used,
+                // instead of failing to compile for contexts which do not implement `Default`
+                let mut generics = self.generics.clone();
+                generics
+                    .make_where_clause()
+                    .predicates
+                    .push(syn::parse_quote!(for<'__garde> #context_ty: ::core::default::Default));
+                let (_, _, where_clause) = generics.split_for_impl();
+                quote! {
+                    impl #impl_generics ::garde::validate::ValidateDefault for #ident #ty_generics #where_clause {
+                        fn default_context() -> Self::Context {
+                            ::core::default::Default::default()
+                        }
+                    }
+                }
+            }
+        };
 
         quote! {
             impl #impl_generics ::garde::Validate for #ident #ty_generics #where_clause {
@@ -24,18 +65,73 @@ impl ToTokens for model::Validate {
                     )
                     .finish()
                 }
+
+                fn validate_first_error(&self, __garde_user_ctx: &Self::Context) -> ::core::result::Result<(), (::std::string::String, ::garde::error::Error)> {
+                    #first_error
+                }
             }
+
+            #default_context
         }
         .to_tokens(tokens)
     }
 }
 
-impl ToTokens for model::ValidateKind {
+/// Wraps the expression `errors`, which evaluates to the `Errors` of the fields,
+/// so that the `validate = ...` functions run afterwards, with their errors reported at the root.
+fn with_validators(validators: &[syn::Expr], errors: TokenStream2) -> TokenStream2 {
+    if validators.is_empty() {
+        return errors;
+    }
+    quote! {{
+        let __garde_inner = #errors;
+        let mut __garde_errors = ::std::vec::Vec::<::garde::error::Error>::new();
+        #(
+            if let Err(__garde_error) = (#validators)(self, __garde_user_ctx) {
+                __garde_errors.push(__garde_error);
+            }
+        )*
+        if __garde_errors.is_empty() {
+            __garde_inner
+        } else {
+            ::garde::error::Errors::Nested(__garde_errors, ::std::boxed::Box::new(__garde_inner))
+        }
+    }}
+}
+
+/// Wraps the statements `first_error`, which return the first error of the fields,
+/// so that the `validate = ...` functions run afterwards.
+///
+/// Their errors are reported at the root, which `Errors::flatten` yields before the errors of any field,
+/// so they take precedence.
+fn first_error_with_validators(
+    validators: &[syn::Expr],
+    first_error: TokenStream2,
+) -> TokenStream2 {
+    if validators.is_empty() {
+        return first_error;
+    }
+    quote! {
+        let __garde_first_error = (|| -> ::core::result::Result<(), (::std::string::String, ::garde::error::Error)> {
+            #first_error
+        })();
+        #(
+            if let Err(__garde_error) = (#validators)(self, __garde_user_ctx) {
+                return ::core::result::Result::Err((::std::string::String::from("value"), __garde_error));
+            }
+        )*
+        __garde_first_error
+    }
+}
+
+struct ValidationKind<'a>(&'a model::ValidateKind, bool);
+
+impl<'a> ToTokens for ValidationKind<'a> {
     fn to_tokens(&self, tokens: &mut TokenStream2) {
-        match self {
+        match &self.0 {
             model::ValidateKind::Struct(variant) => {
                 let bindings = Bindings(variant);
-                let validation = Validation(variant);
+                let validation = Validation(variant, self.1);
 
                 quote! {{
                     let Self #bindings = self;
@@ -45,7 +141,7 @@ impl ToTokens for model::ValidateKind {
             model::ValidateKind::Enum(variants) => {
                 let variants = variants.iter().map(|(name, variant)| {
                     let bindings = Bindings(variant);
-                    let validation = Validation(variant);
+                    let validation = Validation(variant, false);
 
                     quote!(Self::#name #bindings => #validation)
                 });
@@ -61,111 +157,703 @@ impl ToTokens for model::ValidateKind {
     }
 }
 
-struct Validation<'a>(&'a model::ValidateVariant);
+/// Emits an expression which evaluates to the `Errors` of the variant.
+///
+/// If `transparent` is set, the variant has exactly one field, and its errors are not nested under its name.
+struct Validation<'a>(&'a model::ValidateVariant, bool);
 
 impl<'a> ToTokens for Validation<'a> {
     fn to_tokens(&self, tokens: &mut TokenStream2) {
-        // TODO: deduplicate this a bit
+        let fields: Vec<(Option<String>, Binding, &model::ValidateField)> = match &self.0 {
+            model::ValidateVariant::Struct(fields) => fields
+                .iter()
+                .map(|(ident, field)| {
+                    (Some(field_name(ident, field)), Binding::Ident(ident), field)
+                })
+                .collect(),
+            model::ValidateVariant::Tuple(fields) => fields
+                .iter()
+                .enumerate()
+                .map(|(i, field)| (None, Binding::Index(i), field))
+                .collect(),
+        };
+
+        if self.1 {
+            let (_, binding, field) = &fields[0];
+            let errors = FieldErrors(*binding, field);
+            if field.skip.value {
+                quote!(::garde::error::Errors::empty())
+            } else {
+                unless_skipped(
+                    field,
+                    quote!(#errors),
+                    Some(quote!(::garde::error::Errors::empty())),
+                )
+            }
+            .to_tokens(tokens);
+            return;
+        }
+
+        let fields = fields.into_iter().map(|(key, binding, field)| {
+            let errors = FieldErrors(binding, field);
+            match key {
+                Some(key) => {
+                    if field.skip.value {
+                        return quote!();
+                    }
+                    unless_skipped(field, quote!(__garde_errors.insert(#key, #errors);), None)
+                }
+                // skipped fields still take up a position in the list
+                None => {
+                    if field.skip.value {
+                        return quote!(__garde_errors.push(::garde::error::Errors::empty()););
+                    }
+                    unless_skipped(
+                        field,
+                        quote!(__garde_errors.push(#errors);),
+                        Some(quote!(__garde_errors.push(::garde::error::Errors::empty());)),
+                    )
+                }
+            }
+        });
+
         match &self.0 {
-            model::ValidateVariant::Struct(fields) => {
-                let fields =
-                    fields
-                        .iter()
-                        .filter(|(_, field)| !field.skip.value)
-                        .map(|(ident, field)| {
-                            let key = ident.to_string();
-                            let binding = Binding::Ident(ident);
-                            let rules = Rules(binding, field);
-                            if field.dive {
-                                if field.has_top_level_rules() {
-                                    quote! {
-                                        __garde_errors.insert(
-                                            #key,
-                                            ::garde::error::Errors::nested(
-                                                |__garde_errors| {#rules},
-                                                ::garde::validate::Validate::validate(&#binding, __garde_user_ctx)
-                                                    .err()
-                                                    .unwrap_or_else(::garde::error::Errors::empty)
-                                            )
-                                        );
-                                    }
-                                } else {
-                                    quote! {
-                                        __garde_errors.insert(
-                                            #key,
-                                            ::garde::validate::Validate::validate(&#binding, __garde_user_ctx)
-                                                .err()
-                                                .unwrap_or_else(::garde::error::Errors::empty)
-                                        );
-                                    }
-                                }
-                            } else {
-                                quote! {
-                                    __garde_errors.insert(
-                                        #key,
-                                        ::garde::error::Errors::simple(|__garde_errors| {#rules})
-                                    );
-                                }
-                            }
-                        });
+            model::ValidateVariant::Struct(_) => quote! {
+                ::garde::error::Errors::fields(|__garde_errors| {#(#fields)*})
+            },
+            model::ValidateVariant::Tuple(_) => quote! {
+                ::garde::error::Errors::list(|__garde_errors| {#(#fields)*})
+            },
+        }
+        .to_tokens(tokens)
+    }
+}
+
+/// Emits an expression which evaluates to the `Errors` of a single field.
+struct FieldErrors<'a>(Binding<'a>, &'a model::ValidateField);
+
+impl<'a> ToTokens for FieldErrors<'a> {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let binding = &self.0;
+        let field = self.1;
+        let rules = Rules(*binding, field, OnError::Push, Ctx::Shared);
+        let dive = if field.par.is_some() {
+            quote!(::garde::validate::par_dive(&#binding, __garde_user_ctx))
+        } else {
+            quote!(::garde::validate::dive(&#binding, __garde_user_ctx))
+        };
+        match nested_errors(*binding, field, dive, Ctx::Shared) {
+            Some(nested) if field.has_top_level_rules() => {
+                quote!(::garde::error::Errors::nested(|__garde_errors| {#rules}, #nested))
+            }
+            Some(nested) => nested,
+            None => quote!(::garde::error::Errors::simple(|__garde_errors| {#rules})),
+        }
+        .to_tokens(tokens)
+    }
+}
+
+/// Emits an expression which evaluates to the `Errors` nested under a field, if it has any rules
+/// which produce them, such as `dive`.
+///
+/// `dive` is the expression which validates the field itself.
+fn nested_errors(
+    binding: Binding,
+    field: &model::ValidateField,
+    dive: TokenStream2,
+    ctx: Ctx,
+) -> Option<TokenStream2> {
+    let keys = field
+        .keys
+        .as_ref()
+        .map(|keys| KeysErrors(binding, keys, ctx).to_token_stream());
+    let dive = if field.dive {
+        Some(dive)
+    } else if let Some(split) = &field.split {
+        Some(SplitErrors(binding, split, ctx).to_token_stream())
+    } else {
+        field
+            .inner
+            .as_ref()
+            .map(|inner| InnerErrors(binding, inner, ctx).to_token_stream())
+    };
+    let custom_errors_rules = field.custom_errors_rules.iter().map(|func| {
+        quote! {
+            ::garde::error::Errors::from((#func)(&*#binding, #ctx))
+        }
+    });
+    keys.into_iter()
+        .chain(dive)
+        .chain(custom_errors_rules)
+        .reduce(|errors, other| quote!(::garde::error::Errors::merge(#errors, #other)))
+}
+
+/// Emits an expression which evaluates to the `Errors` of the `keys(...)` rules of a field.
+struct KeysErrors<'a>(Binding<'a>, &'a model::ValidateField, Ctx);
+
+impl<'a> ToTokens for KeysErrors<'a> {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let binding = &self.0;
+        let rules = Rules(Binding::Key, self.1, OnError::Push, self.2);
+        quote! {
+            ::garde::rules::keys::errors(&*#binding, |__garde_key, __garde_errors| {
+                #rules
+            })
+        }
+        .to_tokens(tokens)
+    }
+}
+
+/// Emits an expression which evaluates to the `Errors` of the `inner(...)` rules of a field.
+struct InnerErrors<'a>(Binding<'a>, &'a model::ValidateField, Ctx);
+
+impl<'a> ToTokens for InnerErrors<'a> {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let binding = &self.0;
+        let rules = Rules(Binding::Item, self.1, OnError::Push, self.2);
+        quote! {
+            ::garde::rules::inner::errors(&*#binding, |__garde_item, __garde_errors| {
+                #rules
+            })
+        }
+        .to_tokens(tokens)
+    }
+}
+
+/// Emits an expression which evaluates to the `Errors` of the `split(...)` rule of a field.
+struct SplitErrors<'a>(Binding<'a>, &'a model::ValidateSplit, Ctx);
+
+impl<'a> ToTokens for SplitErrors<'a> {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let binding = &self.0;
+        let args = SplitArgs(self.1);
+        let rules = Rules(Binding::Item, &self.1.inner, OnError::Push, self.2);
+        quote! {
+            ::garde::rules::split::errors(&*#binding, #args, |__garde_item, __garde_errors| {
+                #rules
+            })
+        }
+        .to_tokens(tokens)
+    }
+}
+
+/// Emits the delimiter and the handling of empty segments of a `split(...)` rule.
+struct SplitArgs<'a>(&'a model::ValidateSplit);
+
+impl<'a> ToTokens for SplitArgs<'a> {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let delimiter = &self.0.delimiter;
+        let empty = match self.0.empty {
+            model::EmptySegments::Validate => quote!(Validate),
+            model::EmptySegments::Skip => quote!(Skip),
+            model::EmptySegments::Deny => quote!(Deny),
+        };
+        quote!((#delimiter, ::garde::rules::split::EmptySegments::#empty)).to_tokens(tokens)
+    }
+}
+
+struct AsyncValidate<'a>(&'a model::Validate);
+
+impl<'a> ToTokens for AsyncValidate<'a> {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let ident = &self.0.ident;
+        let context_ty = &self.0.context;
+        let (impl_generics, ty_generics, where_clause) = self.0.generics.split_for_impl();
+        let kind = with_validators(
+            &self.0.validators,
+            SequentialKind(self.0, Entrypoint::Async).to_token_stream(),
+        );
+
+        quote! {
+            impl #impl_generics ::garde::validate_async::AsyncValidate for #ident #ty_generics #where_clause {
+                type Context = #context_ty ;
+
+                fn validate_async<'__garde>(
+                    &'__garde self,
+                    __garde_user_ctx: &'__garde Self::Context,
+                ) -> ::garde::validate_async::BoxFuture<'__garde, ::core::result::Result<(), ::garde::error::Errors>> {
+                    ::std::boxed::Box::pin(async move {
+                        (
+                            #kind
+                        )
+                        .finish()
+                    })
+                }
+            }
+        }
+        .to_tokens(tokens)
+    }
+}
+
+struct ValidateMut<'a>(&'a model::Validate);
+
+impl<'a> ToTokens for ValidateMut<'a> {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let ident = &self.0.ident;
+        let context_ty = &self.0.context;
+        let (impl_generics, ty_generics, where_clause) = self.0.generics.split_for_impl();
+        let kind = with_validators(
+            &self.0.validators,
+            SequentialKind(self.0, Entrypoint::Mut).to_token_stream(),
+        );
+
+        quote! {
+            impl #impl_generics ::garde::validate_mut::ValidateMut for #ident #ty_generics #where_clause {
+                type Context = #context_ty ;
+
+                fn validate_mut(&self, __garde_user_ctx: &mut Self::Context) -> ::core::result::Result<(), ::garde::error::Errors> {
+                    (
+                        #kind
+                    )
+                    .finish()
+                }
+            }
+        }
+        .to_tokens(tokens)
+    }
+}
+
+/// The trait implemented by the code of [`SequentialValidation`].
+#[derive(Clone, Copy)]
+enum Entrypoint {
+    /// `AsyncValidate`, which awaits `custom_async` rules and `dive`.
+    Async,
+    /// `ValidateMut`, which passes the context to custom rules and `dive` by mutable reference.
+    Mut,
+}
+
+struct SequentialKind<'a>(&'a model::Validate, Entrypoint);
+
+impl<'a> ToTokens for SequentialKind<'a> {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        match &self.0.kind {
+            model::ValidateKind::Struct(variant) => {
+                let bindings = Bindings(variant);
+                let validation = SequentialValidation(variant, self.0.transparent, self.1);
+
+                quote! {{
+                    let Self #bindings = self;
+                    #validation
+                }}
+            }
+            model::ValidateKind::Enum(variants) => {
+                let variants = variants.iter().map(|(name, variant)| {
+                    let bindings = Bindings(variant);
+                    let validation = SequentialValidation(variant, false, self.1);
+
+                    quote!(Self::#name #bindings => #validation)
+                });
+
+                quote! {{
+                    match self {
+                        #(#variants,)*
+                    }
+                }}
+            }
+        }
+        .to_tokens(tokens)
+    }
+}
+
+/// Emits an expression which evaluates to the `Errors` of the variant, validating one field after the other.
+///
+/// Closures cannot contain `.await`, nor share a mutable context with the nested validation passed
+/// alongside them, so the errors of each field are collected up front, and then moved into the builder.
+///
+/// See [`Validation`] for the meaning of `transparent`.
+struct SequentialValidation<'a>(&'a model::ValidateVariant, bool, Entrypoint);
+
+impl<'a> ToTokens for SequentialValidation<'a> {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let fields: Vec<(TokenStream2, Binding, &model::ValidateField)> = match &self.0 {
+            model::ValidateVariant::Struct(fields) => fields
+                .iter()
+                .map(|(ident, field)| {
+                    let key = field_name(ident, field);
+                    (quote!(#key), Binding::Ident(ident), field)
+                })
+                .collect(),
+            model::ValidateVariant::Tuple(fields) => fields
+                .iter()
+                .enumerate()
+                .map(|(i, field)| (quote!(()), Binding::Index(i), field))
+                .collect(),
+        };
 
+        let fields = fields
+            .into_iter()
+            .map(|(key, binding, field)| {
+                // skipped fields still take up a position in the list of a tuple
+                if field.skip.value {
+                    return quote!(__garde_items.push((#key, ::garde::error::Errors::empty())););
+                }
+                let (dive, ctx) = match self.2 {
+                    Entrypoint::Async => (
+                        quote!(::garde::validate_async::dive(&#binding, __garde_user_ctx).await),
+                        Ctx::Shared,
+                    ),
+                    Entrypoint::Mut => (
+                        quote!(::garde::validate_mut::dive(&#binding, &mut *__garde_user_ctx)),
+                        Ctx::Mut,
+                    ),
+                };
+                let rules = Rules(binding, field, OnError::Push, ctx);
+                let errors = match nested_errors(binding, field, dive, ctx) {
+                    Some(nested) if field.has_top_level_rules() => {
+                        quote!(::garde::error::Errors::Nested(__garde_errors, ::std::boxed::Box::new(#nested)))
+                    }
+                    Some(nested) => nested,
+                    None => quote!(::garde::error::Errors::Simple(__garde_errors)),
+                };
+                let validation = quote! {{
+                    let mut __garde_errors = ::std::vec::Vec::<::garde::error::Error>::new();
+                    #rules
+                    __garde_items.push((#key, #errors));
+                }};
+                unless_skipped(
+                    field,
+                    validation,
+                    Some(quote!(__garde_items.push((#key, ::garde::error::Errors::empty()));)),
+                )
+            });
+
+        let (key_ty, builder) = match &self.0 {
+            model::ValidateVariant::Struct(_) => (
+                quote!(&'static str),
                 quote! {
-                    ::garde::error::Errors::fields(|__garde_errors| {#(#fields)*})
+                    ::garde::error::Errors::fields(|__garde_errors| {
+                        for (__garde_key, __garde_item) in __garde_items.drain(..) {
+                            __garde_errors.insert(__garde_key, __garde_item);
+                        }
+                    })
+                },
+            ),
+            model::ValidateVariant::Tuple(_) => (
+                quote!(()),
+                quote! {
+                    ::garde::error::Errors::list(|__garde_errors| {
+                        for ((), __garde_item) in __garde_items.drain(..) {
+                            __garde_errors.push(__garde_item);
+                        }
+                    })
+                },
+            ),
+        };
+        let builder = if self.1 {
+            quote! {
+                match __garde_items.pop() {
+                    ::core::option::Option::Some((_, __garde_item)) => __garde_item,
+                    ::core::option::Option::None => ::garde::error::Errors::empty(),
                 }
             }
-            model::ValidateVariant::Tuple(fields) => {
-                let fields = fields
+        } else {
+            builder
+        };
+
+        quote! {{
+            let mut __garde_items = ::std::vec::Vec::<(#key_ty, ::garde::error::Errors)>::new();
+            #(#fields)*
+            #builder
+        }}
+        .to_tokens(tokens)
+    }
+}
+
+struct FirstErrorKind<'a>(&'a model::ValidateKind, bool);
+
+impl<'a> ToTokens for FirstErrorKind<'a> {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        match &self.0 {
+            model::ValidateKind::Struct(variant) => {
+                let bindings = Bindings(variant);
+                let validation = FirstError(variant, self.1);
+
+                quote! {{
+                    let Self #bindings = self;
+                    #validation
+                    ::core::result::Result::Ok(())
+                }}
+            }
+            model::ValidateKind::Enum(variants) => {
+                let variants = variants.iter().map(|(name, variant)| {
+                    let bindings = Bindings(variant);
+                    let validation = FirstError(variant, false);
+
+                    quote!(Self::#name #bindings => {
+                        #validation
+                        ::core::result::Result::Ok(())
+                    })
+                });
+
+                quote! {{
+                    match self {
+                        #(#variants,)*
+                    }
+                }}
+            }
+        }
+        .to_tokens(tokens)
+    }
+}
+
+/// Emits statements which return the first error found in the variant.
+///
+/// Fields are visited in the same order in which `Errors::flatten` would yield their errors.
+/// See [`Validation`] for the meaning of `transparent`.
+struct FirstError<'a>(&'a model::ValidateVariant, bool);
+
+impl<'a> ToTokens for FirstError<'a> {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let fields: Vec<(String, Binding, &model::ValidateField)> = match &self.0 {
+            model::ValidateVariant::Struct(fields) => {
+                let mut fields = fields
                     .iter()
-                    .enumerate()
-                    .filter(|(_, field)| !field.skip.value)
-                    .map(|(i, field)| {
-                        let binding = Binding::Index(i);
-                        let rules = Rules(binding, field);
-                        if field.dive {
-                            if field.has_top_level_rules() {
-                                quote! {
-                                    __garde_errors.push(
-                                        ::garde::error::Errors::nested(
-                                            |__garde_errors| {#rules},
-                                            ::garde::validate::Validate::validate(&#binding, __garde_user_ctx)
-                                                .err()
-                                                .unwrap_or_else(::garde::error::Errors::empty)
-                                        )
-                                    );
-                                }
-                            } else {
-                                quote! {
-                                    __garde_errors.push(
-                                        ::garde::validate::Validate::validate(&#binding, __garde_user_ctx)
-                                            .err()
-                                            .unwrap_or_else(::garde::error::Errors::empty)
-                                    );
-                                }
-                            }
+                    .map(|(ident, field)| (field_name(ident, field), Binding::Ident(ident), field))
+                    .collect::<Vec<_>>();
+                // `Errors::Fields` is ordered by name, which may differ from the order of the identifiers
+                fields.sort_by(|a, b| a.0.cmp(&b.0));
+                fields
+                    .into_iter()
+                    .map(|(name, binding, field)| (format!("value.{name}"), binding, field))
+                    .collect()
+            }
+            model::ValidateVariant::Tuple(fields) => fields
+                .iter()
+                .enumerate()
+                .map(|(i, field)| (format!("value[{i}]"), Binding::Index(i), field))
+                .collect(),
+        };
+        let fields = fields.into_iter().map(|(path, binding, field)| {
+            let path = if self.1 { String::from("value") } else { path };
+            (path, binding, field)
+        });
+
+        let fields =
+            fields
+                .filter(|(_, _, field)| !field.skip.value)
+                .map(|(path, binding, field)| {
+                    let dive = if field.dive {
+                        let dive_first_error = if field.par.is_some() {
+                            quote!(::garde::validate::par_dive_first_error)
                         } else {
-                            quote! {
-                                __garde_errors.push(
-                                    ::garde::error::Errors::simple(|__garde_errors| {#rules})
-                                );
+                            quote!(::garde::validate::dive_first_error)
+                        };
+                        Some(quote! {
+                            if let Err((__garde_path, __garde_error)) =
+                                #dive_first_error(&#binding, __garde_user_ctx)
+                            {
+                                return ::core::result::Result::Err((
+                                    ::garde::error::nest_path(#path, &__garde_path),
+                                    __garde_error,
+                                ));
+                            }
+                        })
+                    } else {
+                        None
+                    };
+                    let keys = field.keys.as_ref().map(|keys| {
+                        let rules =
+                            Rules(Binding::Key, keys, OnError::Key(path.clone()), Ctx::Shared);
+                        quote! {
+                            for __garde_key in ::garde::rules::keys::Keys::keys(&*#binding) {
+                                #rules
+                            }
+                        }
+                    });
+                    let inner = field.inner.as_ref().map(|inner| {
+                        let rules = Rules(
+                            Binding::Item,
+                            inner,
+                            OnError::Item(path.clone()),
+                            Ctx::Shared,
+                        );
+                        quote! {{
+                            let __garde_inner = &*#binding;
+                            for (__garde_index, __garde_item) in
+                                ::garde::rules::inner::Inner::items(__garde_inner).enumerate()
+                            {
+                                #rules
+                            }
+                        }}
+                    });
+                    let split = field.split.as_ref().map(|split| {
+                        let args = SplitArgs(split);
+                        let rules = Rules(Binding::Item, &split.inner, OnError::Push, Ctx::Shared);
+                        quote! {
+                            ::garde::rules::split::first_error(
+                                &*#binding,
+                                #args,
+                                #path,
+                                |__garde_item, __garde_errors| {
+                                    #rules
+                                },
+                            )?;
+                        }
+                    });
+                    let custom_errors_rules = field.custom_errors_rules.iter().map(|func| {
+                        quote! {
+                            if let Err(__garde_errors) = (#func)(&*#binding, &__garde_user_ctx) {
+                                if let ::core::option::Option::Some((__garde_path, __garde_error)) =
+                                    __garde_errors.flatten().into_iter().next()
+                                {
+                                    return ::core::result::Result::Err((
+                                        ::garde::error::nest_path(#path, &__garde_path),
+                                        __garde_error,
+                                    ));
+                                }
                             }
                         }
                     });
+                    let custom_errors_rules = quote!(#(#custom_errors_rules)*);
+                    let rules = Rules(binding, field, OnError::Return(path), Ctx::Shared);
 
-                quote! {
-                    ::garde::error::Errors::list(|__garde_errors| {#(#fields)*})
-                }
-            }
+                    unless_skipped(
+                        field,
+                        quote! {
+                            #rules
+                            #keys
+                            #inner
+                            #split
+                            #dive
+                            #custom_errors_rules
+                        },
+                        None,
+                    )
+                });
+
+        quote! {
+            #(#fields)*
+        }
+        .to_tokens(tokens)
+    }
+}
+
+/// The name of the field in error paths, which is its identifier unless it was renamed.
+fn field_name(ident: &Ident, field: &model::ValidateField) -> String {
+    match &field.alias {
+        Some(alias) => alias.value.clone(),
+        None => ident.to_string(),
+    }
+}
+
+/// Wraps `validation` so that it only runs if the field's `skip_if` predicate returns `false`,
+/// its `unless` predicate returns `true`, and its `when` field is `true`.
+///
+/// If the field is skipped, `otherwise` runs instead.
+fn unless_skipped(
+    field: &model::ValidateField,
+    validation: TokenStream2,
+    otherwise: Option<TokenStream2>,
+) -> TokenStream2 {
+    let skip_if = field
+        .skip_if
+        .as_ref()
+        .map(|func| quote!(!(#func)(&__garde_user_ctx)));
+    let when = field.when.as_ref().map(|when| {
+        let root = &when.root;
+        let rest = &when.rest;
+        // the field path is auto-dereferenced, and the reference to it coerced to `&bool`
+        quote!(<bool as ::core::clone::Clone>::clone(&#root #(.#rest)*))
+    });
+    let unless = field
+        .unless
+        .as_ref()
+        .map(|func| quote!((#func)(&__garde_user_ctx)));
+    let conditions = skip_if
+        .into_iter()
+        .chain(unless)
+        .chain(when)
+        .collect::<Vec<_>>();
+    if conditions.is_empty() {
+        return validation;
+    }
+    quote! {
+        if #(#conditions)&&* {
+            #validation
+        } else {
+            #otherwise
+        }
+    }
+}
+
+struct Rules<'a>(Binding<'a>, &'a model::ValidateField, OnError, Ctx);
+
+/// How the emitted code passes `__garde_user_ctx` to custom rules.
+#[derive(Clone, Copy)]
+enum Ctx {
+    /// By shared reference.
+    Shared,
+    /// By mutable reference, for `ValidateMut`.
+    Mut,
+}
+
+impl ToTokens for Ctx {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        match self {
+            Ctx::Shared => quote!(&__garde_user_ctx),
+            Ctx::Mut => quote!(&mut *__garde_user_ctx),
         }
         .to_tokens(tokens)
     }
 }
 
-struct Rules<'a>(Binding<'a>, &'a model::ValidateField);
+/// What the emitted code does with an error returned by a rule.
+#[derive(Clone)]
+enum OnError {
+    /// Push it into `__garde_errors`.
+    Push,
+    /// Return it from the enclosing function along with the given path.
+    Return(String),
+    /// Return it from the enclosing function along with the path of the current `__garde_item`
+    /// in `__garde_inner`, which is itself at the given path.
+    Item(String),
+    /// Return it from the enclosing function along with the path of the current `__garde_key`
+    /// in the map at the given path.
+    Key(String),
+    /// Keep it in `__garde_message_error` if it is the first error of the field,
+    /// so that it is handled once all rules have run.
+    Message,
+}
+
+impl ToTokens for OnError {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        match self {
+            OnError::Push => quote! {
+                __garde_errors.push(__garde_error)
+            },
+            OnError::Return(path) => quote! {
+                return ::core::result::Result::Err((::std::string::String::from(#path), __garde_error))
+            },
+            OnError::Item(path) => quote! {
+                return ::core::result::Result::Err((
+                    ::garde::rules::inner::path(__garde_inner, #path, __garde_index),
+                    __garde_error,
+                ))
+            },
+            OnError::Key(path) => quote! {
+                return ::core::result::Result::Err((
+                    ::garde::rules::keys::path(#path, __garde_key),
+                    __garde_error,
+                ))
+            },
+            OnError::Message => quote! {
+                if __garde_message_error.is_none() {
+                    __garde_message_error = ::core::option::Option::Some(__garde_error);
+                }
+            },
+        }
+        .to_tokens(tokens)
+    }
+}
 
 #[derive(Clone, Copy)]
 enum Binding<'a> {
     Ident(&'a Ident),
     Index(usize),
+    /// The current key in a `keys(...)` loop.
+    Key,
+    /// The current item of an `inner(...)` rule.
+    Item,
 }
 
 impl<'a> ToTokens for Binding<'a> {
@@ -173,6 +861,8 @@ impl<'a> ToTokens for Binding<'a> {
         match self {
             Binding::Ident(v) => v.to_tokens(tokens),
             Binding::Index(v) => format_ident!("_{v}").to_tokens(tokens),
+            Binding::Key => format_ident!("__garde_key").to_tokens(tokens),
+            Binding::Item => format_ident!("__garde_item").to_tokens(tokens),
         }
     }
 }
@@ -180,11 +870,23 @@ impl<'a> ToTokens for Binding<'a> {
 impl<'a> ToTokens for Rules<'a> {
     fn to_tokens(&self, tokens: &mut TokenStream2) {
         let binding = &self.0;
-        let ty = &self.1.ty;
+        // with a custom message, the field reports a single error, which keeps the code of the first rule that failed
+        let on_error = &match &self.1.message {
+            Some(_) => OnError::Message,
+            None => self.2.clone(),
+        };
+        let ctx = &self.3;
         let custom_rules = self.1.custom_rules.iter().map(|func| {
             quote! {
-                if let Err(__garde_error) = (#func)(&*#binding, &__garde_user_ctx) {
-                    __garde_errors.push(__garde_error)
+                if let Err(__garde_error) = (#func)(&*#binding, #ctx) {
+                    #on_error
+                }
+            }
+        });
+        let custom_async_rules = self.1.custom_async_rules.iter().map(|func| {
+            quote! {
+                if let Err(__garde_error) = (#func)(&*#binding, &__garde_user_ctx).await {
+                    #on_error
                 }
             }
         });
@@ -193,54 +895,176 @@ impl<'a> ToTokens for Rules<'a> {
             let name = format_ident!("{}", rule.name());
             let args = match &rule.kind {
                 model::ValidateRuleKind::Ascii
-                | model::ValidateRuleKind::Alphanumeric
+                | model::ValidateRuleKind::AsciiBytes
+                | model::ValidateRuleKind::Trimmed
+                | model::ValidateRuleKind::Lowercase
+                | model::ValidateRuleKind::Uppercase
                 | model::ValidateRuleKind::Email
-                | model::ValidateRuleKind::Url
-                | model::ValidateRuleKind::CreditCard
-                | model::ValidateRuleKind::PhoneNumber => quote!(()),
-                model::ValidateRuleKind::Ip => {
-                    quote!((::garde::rules::ip::IpKind::Any,))
+                | model::ValidateRuleKind::Luhn
+                | model::ValidateRuleKind::Iban
+                | model::ValidateRuleKind::Json => quote!(()),
+                model::ValidateRuleKind::Url(schemes, hosts) => {
+                    quote!((&[#(#schemes),*], &[#(#hosts),*]))
                 }
-                model::ValidateRuleKind::IpV4 => {
-                    quote!((::garde::rules::ip::IpKind::V4,))
-                }
-                model::ValidateRuleKind::IpV6 => {
-                    quote!((::garde::rules::ip::IpKind::V6,))
+                model::ValidateRuleKind::Isbn(version) | model::ValidateRuleKind::Uuid(version) => {
+                    match version {
+                        Some(version) => quote!((::core::option::Option::Some(#version),)),
+                        None => quote!((::core::option::Option::None,)),
+                    }
                 }
-                model::ValidateRuleKind::Length(range)
-                | model::ValidateRuleKind::ByteLength(range) => match range {
-                    model::ValidateRange::GreaterThan(min) => quote!((#min, usize::MAX)),
-                    model::ValidateRange::LowerThan(max) => quote!((0, #max)),
-                    model::ValidateRange::Between(min, max) => quote!((#min, #max)),
+                model::ValidateRuleKind::Alphanumeric(kind) => match kind {
+                    model::AlphanumericKind::Unicode => {
+                        quote!((::garde::rules::alphanumeric::AlphanumericKind::Unicode,))
+                    }
+                    model::AlphanumericKind::Ascii => {
+                        quote!((::garde::rules::alphanumeric::AlphanumericKind::Ascii,))
+                    }
+                },
+                model::ValidateRuleKind::HexColor(alpha) => quote!((#alpha,)),
+                model::ValidateRuleKind::Port(privileged) => quote!((#privileged,)),
+                model::ValidateRuleKind::Semver(kind) => match kind {
+                    model::SemverKind::Version => {
+                        quote!((::garde::rules::semver::SemverKind::Version,))
+                    }
+                    model::SemverKind::Req => quote!((::garde::rules::semver::SemverKind::Req,)),
                 },
-                model::ValidateRuleKind::Range(range) => match range {
-                    model::ValidateRange::GreaterThan(min) => {
-                        quote!((&#min, &(<#ty as ::garde::rules::range::Bounds>::MAX)))
+                model::ValidateRuleKind::Base64(kind) => match kind {
+                    model::Base64Kind::Standard => {
+                        quote!((::garde::rules::base64::Base64Kind::Standard,))
                     }
-                    model::ValidateRange::LowerThan(max) => {
-                        quote!((&(<#ty as ::garde::rules::range::Bounds>::MIN), &#max))
+                    model::Base64Kind::UrlSafe => {
+                        quote!((::garde::rules::base64::Base64Kind::UrlSafe,))
                     }
-                    model::ValidateRange::Between(min, max) => quote!((&#min, &#max)),
                 },
-                model::ValidateRuleKind::Contains(s)
-                | model::ValidateRuleKind::Prefix(s)
-                | model::ValidateRuleKind::Suffix(s) => quote!((#s,)),
-                model::ValidateRuleKind::Pattern(s) => quote!({
+                model::ValidateRuleKind::PhoneNumber(region) => match region {
+                    Some(region) => quote!((::core::option::Option::Some(
+                        ::garde::rules::phone_number::Region::#region
+                    ),)),
+                    None => quote!((::core::option::Option::None,)),
+                },
+                model::ValidateRuleKind::Date(format) => {
+                    quote!((::garde::rules::datetime::Format::Date(#format),))
+                }
+                model::ValidateRuleKind::DateTime(format) => match format {
+                    Some(format) => quote!((::garde::rules::datetime::Format::DateTime(#format),)),
+                    None => quote!((::garde::rules::datetime::Format::Rfc3339,)),
+                },
+                model::ValidateRuleKind::CreditCard(types) => {
+                    quote!((&[#(#types),*],))
+                }
+                model::ValidateRuleKind::Ip(scope)
+                | model::ValidateRuleKind::IpV4(scope)
+                | model::ValidateRuleKind::IpV6(scope) => {
+                    let kind = match &rule.kind {
+                        model::ValidateRuleKind::IpV4(_) => quote!(V4),
+                        model::ValidateRuleKind::IpV6(_) => quote!(V6),
+                        _ => quote!(Any),
+                    };
+                    let scope = match scope {
+                        model::IpScope::Any => quote!(Any),
+                        model::IpScope::Public => quote!(Public),
+                        model::IpScope::Private => quote!(Private),
+                        model::IpScope::Loopback => quote!(Loopback),
+                    };
+                    quote!((
+                        ::garde::rules::ip::IpKind::#kind,
+                        ::garde::rules::ip::IpScope::#scope,
+                    ))
+                }
+                model::ValidateRuleKind::Length(range)
+                | model::ValidateRuleKind::ByteLength(range)
+                | model::ValidateRuleKind::GraphemeLength(range) => match range {
+                    model::ValidateRange::GreaterThan(min) => quote!((#min, usize::MAX, false)),
+                    model::ValidateRange::LowerThan(max) => quote!((0, #max, false)),
+                    model::ValidateRange::Between(min, max) => quote!((#min, #max, false)),
+                    model::ValidateRange::Equal(len) => quote!((#len, #len, true)),
+                },
+                model::ValidateRuleKind::Range(model::ValidateBounds { min, max }) => {
+                    let bound = |bound: &Option<model::ValidateBound>| match bound {
+                        Some(model::ValidateBound::Inclusive(v)) => {
+                            quote!(::core::ops::Bound::Included(&#v))
+                        }
+                        Some(model::ValidateBound::Exclusive(v)) => {
+                            quote!(::core::ops::Bound::Excluded(&#v))
+                        }
+                        None => quote!(::core::ops::Bound::Unbounded),
+                    };
+                    let min = bound(min);
+                    let max = bound(max);
+                    quote!((#min, #max))
+                }
+                model::ValidateRuleKind::MultipleOf(divisor) => quote!((&#divisor,)),
+                model::ValidateRuleKind::GreaterThan(model::Compared { field, name }) => {
+                    quote!((&*#field, #name, ::core::cmp::Ordering::Greater))
+                }
+                model::ValidateRuleKind::LessThan(model::Compared { field, name }) => {
+                    quote!((&*#field, #name, ::core::cmp::Ordering::Less))
+                }
+                model::ValidateRuleKind::Contains(s, insensitive) => quote!((#s, #insensitive)),
+                model::ValidateRuleKind::NotContains(s, insensitive) => {
+                    quote!((&[#(#s),*], #insensitive))
+                }
+                model::ValidateRuleKind::Required(non_empty, message) => {
+                    let mode = if *non_empty {
+                        quote!(::garde::rules::required::NonEmpty)
+                    } else {
+                        quote!(::garde::rules::required::Present)
+                    };
+                    let message = match message {
+                        Some(message) => quote!(::core::option::Option::Some(#message)),
+                        None => quote!(::core::option::Option::None),
+                    };
+                    quote!((#mode, #message))
+                }
+                model::ValidateRuleKind::NonControl(allow_whitespace) => {
+                    quote!((#allow_whitespace,))
+                }
+                model::ValidateRuleKind::OneOf(model::ValidateOneOf::Str(s, insensitive)) => {
+                    quote!((&[#(#s),*], #insensitive))
+                }
+                model::ValidateRuleKind::OneOf(model::ValidateOneOf::Int(v)) => {
+                    quote!((&[#(#v),*], false))
+                }
+                model::ValidateRuleKind::Prefix(s) | model::ValidateRuleKind::Suffix(s) => {
+                    quote!((&[#(#s),*],))
+                }
+                // The regex is compiled once, on first use. Statics are not monomorphized,
+                // so this is also true for generic types.
+                model::ValidateRuleKind::Pattern(model::ValidatePattern::Lit(s)) => quote!({
                     static PATTERN: ::garde::rules::pattern::StaticPattern =
                         ::garde::rules::pattern::init_pattern!(#s);
                     (&PATTERN,)
                 }),
+                model::ValidateRuleKind::Pattern(model::ValidatePattern::Func(func)) => {
+                    quote!((#func(),))
+                }
             };
             quote! {
                 if let Err(__garde_error) = (::garde::rules::#name::apply)(&*#binding, #args) {
-                    __garde_errors.push(__garde_error)
+                    #on_error
                 }
             }
         });
 
-        quote! {
+        let rules = quote! {
             #(#custom_rules)*
+            #(#custom_async_rules)*
             #(#rules)*
+        };
+        match &self.1.message {
+            Some(message) => {
+                let on_error = &self.2;
+                quote! {{
+                    let mut __garde_message_error =
+                        ::core::option::Option::<::garde::error::Error>::None;
+                    #rules
+                    if let ::core::option::Option::Some(mut __garde_error) = __garde_message_error {
+                        __garde_error.message = ::std::borrow::Cow::Borrowed(#message);
+                        #on_error
+                    }
+                }}
+            }
+            None => rules,
         }
         .to_tokens(tokens)
     }
@@ -252,9 +1076,14 @@ impl<'a> ToTokens for Bindings<'a> {
     fn to_tokens(&self, tokens: &mut TokenStream2) {
         match &self.0 {
             model::ValidateVariant::Struct(fields) => {
+                // skipped fields are still bound if another field refers to them
+                let referenced = fields
+                    .iter()
+                    .flat_map(|field| field.1.bound_fields())
+                    .collect::<Vec<_>>();
                 let names = fields
                     .iter()
-                    .filter(|field| !field.1.skip.value)
+                    .filter(|field| !field.1.skip.value || referenced.contains(&&field.0))
                     .map(|field| &field.0)
                     .collect::<Vec<_>>();
                 let rest = if names.len() != fields.len() {
@@ -266,19 +1095,16 @@ impl<'a> ToTokens for Bindings<'a> {
                 quote!( { #(#names,)* #rest } )
             }
             model::ValidateVariant::Tuple(fields) => {
-                let indices = fields
-                    .iter()
-                    .enumerate()
-                    .filter(|(_, field)| !field.skip.value)
-                    .map(|(i, _)| IndexBinding(i))
-                    .collect::<Vec<_>>();
-                let rest = if indices.len() != fields.len() {
-                    Some(quote!(..))
-                } else {
-                    None
-                };
+                // skipped fields are bound to `_` so that each binding stays at its position
+                let indices = fields.iter().enumerate().map(|(i, field)| {
+                    if field.skip.value {
+                        quote!(_)
+                    } else {
+                        IndexBinding(i).to_token_stream()
+                    }
+                });
 
-                quote!( ( #(#indices,)* #rest ) )
+                quote!( ( #(#indices,)* ) )
             }
         }
         .to_tokens(tokens)
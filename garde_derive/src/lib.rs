@@ -18,5 +18,45 @@ pub fn derive_validate(input: TokenStream) -> TokenStream {
         Ok(v) => v,
         Err(e) => return e.into_compile_error().into(),
     };
+    if let Err(e) = check::check_sync(&input) {
+        return e.into_compile_error().into();
+    }
     emit::emit(input).into()
 }
+
+#[proc_macro_derive(AsyncValidate, attributes(garde))]
+pub fn derive_async_validate(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as DeriveInput);
+    let input = match syntax::parse(input) {
+        Ok(v) => v,
+        Err(e) => return e.into_compile_error().into(),
+    };
+    let input = match check::check(input) {
+        Ok(v) => v,
+        Err(e) => return e.into_compile_error().into(),
+    };
+    if let Err(e) = check::check_sequential(&input) {
+        return e.into_compile_error().into();
+    }
+    emit::emit_async(input).into()
+}
+
+#[proc_macro_derive(ValidateMut, attributes(garde))]
+pub fn derive_validate_mut(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as DeriveInput);
+    let input = match syntax::parse(input) {
+        Ok(v) => v,
+        Err(e) => return e.into_compile_error().into(),
+    };
+    let input = match check::check(input) {
+        Ok(v) => v,
+        Err(e) => return e.into_compile_error().into(),
+    };
+    if let Err(e) = check::check_sync(&input) {
+        return e.into_compile_error().into();
+    }
+    if let Err(e) = check::check_sequential(&input) {
+        return e.into_compile_error().into();
+    }
+    emit::emit_mut(input).into()
+}
@@ -11,7 +11,10 @@ pub struct Input {
 }
 
 pub enum Attr {
-    Context(Box<Type>),
+    /// The context type, and the function which creates its default value, if any.
+    Context(Box<Type>, Option<ExprPath>),
+    Transparent,
+    Validate(Func),
 }
 
 pub enum InputKind {
@@ -55,6 +58,14 @@ impl Func {
     }
 }
 
+/// A path to a sibling field, or to a field nested within it, such as `a.b.c`.
+pub struct FieldPath {
+    pub span: Span,
+    pub root: Ident,
+    pub rest: Vec<Ident>,
+}
+
+#[allow(dead_code)]
 pub enum Message {
     Fmt(Str),
     Func(Func),
@@ -65,46 +76,214 @@ pub struct RawRule {
     pub kind: RawRuleKind,
 }
 
+#[allow(clippy::large_enum_variant)]
 pub enum RawRuleKind {
     Skip,
+    SkipIf(Func),
+    When(FieldPath),
+    Unless(Func),
     Rename(Str),
     Message(Message),
     Code(Str),
-    Dive,
-    Ascii,
-    Alphanumeric,
+    Dive(Dive),
+    Required(Required),
+    Ascii(Ascii),
+    Alphanumeric(Alphanumeric),
+    Trimmed,
+    NonControl(NonControl),
+    Lowercase,
+    Uppercase,
     Email,
-    Url,
-    Ip,
-    IpV4,
-    IpV6,
-    CreditCard,
-    PhoneNumber,
-    Length(Range<usize>),
+    Url(Url),
+    Ip(Ip),
+    IpV4(Ip),
+    IpV6(Ip),
+    CreditCard(CreditCard),
+    Luhn,
+    Iban,
+    Isbn(Isbn),
+    Json,
+    Uuid(Uuid),
+    Semver(Semver),
+    Base64(Base64),
+    HexColor(HexColor),
+    Port(Port),
+    PhoneNumber(PhoneNumber),
+    Date(DateTime),
+    DateTime(DateTime),
+    Length(Length),
     ByteLength(Range<usize>),
     Range(Range<Expr>),
-    Contains(Str),
-    Prefix(Str),
-    Suffix(Str),
-    Pattern(Str),
+    MultipleOf(Expr),
+    GreaterThan(Ident),
+    LessThan(Ident),
+    OneOf(OneOf),
+    Contains(Contains),
+    NotContains(NotContains),
+    Prefix(List<Str>),
+    Suffix(List<Str>),
+    Pattern(Pattern),
     Custom(Func),
-    Inner(Box<RawRule>),
+    CustomAsync(Func),
+    CustomErrors(Func),
+    Inner(List<RawRule>),
+    Keys(List<RawRule>),
+    Split(Split),
+}
+
+pub struct List<T> {
+    pub span: Span,
+    pub contents: Vec<T>,
 }
 
 pub struct Str {
+    pub span: Span,
     pub value: String,
 }
 
+pub struct Split {
+    pub delimiter: Str,
+    /// `skip_empty` or `deny_empty`, if either was specified.
+    pub empty: Option<Ident>,
+    pub inner: Option<List<RawRule>>,
+}
+
+/// The span of each list is that of the name of its argument, e.g. `host`.
+pub struct Url {
+    pub schemes: Option<List<Str>>,
+    pub hosts: Option<List<Str>>,
+}
+
+pub struct CreditCard {
+    pub types: Vec<Str>,
+}
+
+pub struct Isbn {
+    pub version: Option<syn::LitInt>,
+}
+
+pub struct Uuid {
+    pub version: Option<syn::LitInt>,
+}
+
+pub enum Pattern {
+    Lit(Str),
+    Func(ExprPath),
+}
+
+pub struct Dive {
+    pub mode: Option<Ident>,
+}
+
+pub struct Ascii {
+    pub kind: Option<Ident>,
+}
+
+pub struct Ip {
+    pub scope: Option<Ident>,
+}
+
+pub struct Alphanumeric {
+    pub kind: Option<Ident>,
+}
+
+pub struct HexColor {
+    pub alpha: Option<syn::LitBool>,
+}
+
+pub struct Port {
+    pub privileged: Option<syn::LitBool>,
+}
+
+pub struct Semver {
+    pub kind: Option<Ident>,
+}
+
+pub struct Base64 {
+    pub kind: Option<Ident>,
+}
+
+pub struct PhoneNumber {
+    pub region: Option<Str>,
+}
+
+pub struct DateTime {
+    pub format: Option<Str>,
+}
+
+pub struct Length {
+    pub unit: LengthUnit,
+    pub range: Range<usize>,
+}
+
+/// The unit a `length` is counted in.
+pub enum LengthUnit {
+    /// The default, as returned by `HasLength`, which is the number of characters for strings
+    /// and the number of items for other containers.
+    Chars,
+    /// Bytes, the same as `byte_length`.
+    Bytes,
+    /// Extended grapheme clusters.
+    Graphemes,
+}
+
+pub struct Required {
+    /// Whether `non_empty` was specified.
+    pub non_empty: bool,
+    pub message: Option<Str>,
+}
+
+pub struct NonControl {
+    /// Whether `allow_whitespace` was specified.
+    pub allow_whitespace: bool,
+}
+
+pub struct OneOf {
+    pub span: Span,
+    pub values: Vec<OneOfValue>,
+    /// The span of `insensitive`, if it was specified.
+    pub insensitive: Option<Span>,
+}
+
+pub enum OneOfValue {
+    Str(Str),
+    /// An integer literal, which may be negated.
+    Int {
+        negative: bool,
+        lit: syn::LitInt,
+    },
+}
+
+pub struct Contains {
+    pub pattern: Str,
+    pub insensitive: bool,
+}
+
+pub struct NotContains {
+    pub patterns: List<Str>,
+    pub insensitive: bool,
+}
+
 pub struct Range<T> {
     pub span: Span,
     pub min: Option<T>,
     pub max: Option<T>,
+    pub exclusive_min: Option<T>,
+    pub exclusive_max: Option<T>,
+    /// Only supported by the length rules.
+    pub equal: Option<T>,
 }
 
 pub struct Validate {
     pub ident: Ident,
     pub generics: Generics,
     pub context: Type,
+    /// Creates the context used by `ValidateDefault`, instead of `Default::default()`.
+    pub default_context: Option<ExprPath>,
+    /// Validates the only field as if it were the whole value.
+    pub transparent: bool,
+    /// Functions which validate the whole value after its fields, with errors reported at its root.
+    pub validators: Vec<Expr>,
     pub kind: ValidateKind,
 }
 
@@ -117,13 +296,45 @@ pub struct ValidateField {
     pub ty: Type,
 
     pub skip: Skip,
-    pub alias: Option<String>,
-    pub message: Option<Message>,
+    /// Skips all validation of the field if this returns `true` for the context.
+    pub skip_if: Option<Expr>,
+    /// Skips all validation of the field unless this boolean field is `true`.
+    pub when: Option<FieldPath>,
+    /// Runs the rules of a field marked `skip` if this returns `true` for the context.
+    pub unless: Option<Expr>,
+    /// Replaces the name of the field in error paths.
+    pub alias: Option<Str>,
+    /// Replaces the message of errors produced by the field's rules.
+    pub message: Option<String>,
     pub code: Option<String>,
 
     pub dive: bool,
+    /// The span of `par` in `dive(par)`, which validates the items of a slice in parallel.
+    pub par: Option<Span>,
     pub rules: BTreeSet<ValidateRule>,
     pub custom_rules: Vec<Expr>,
+    /// Only allowed in `#[derive(AsyncValidate)]`.
+    pub custom_async_rules: Vec<Expr>,
+    /// Custom rules which return `Errors`, nested under the field.
+    pub custom_errors_rules: Vec<Expr>,
+    /// Rules applied to each key of a map.
+    pub keys: Option<Box<ValidateField>>,
+    /// Rules applied to each item of a container.
+    pub inner: Option<Box<ValidateField>>,
+    /// Rules applied to each segment of a delimited string.
+    pub split: Option<Box<ValidateSplit>>,
+}
+
+pub struct ValidateSplit {
+    pub delimiter: String,
+    pub empty: EmptySegments,
+    pub inner: ValidateField,
+}
+
+pub enum EmptySegments {
+    Validate,
+    Skip,
+    Deny,
 }
 
 pub struct Skip {
@@ -133,11 +344,47 @@ pub struct Skip {
 
 impl ValidateField {
     pub fn is_empty(&self) -> bool {
-        !self.dive && self.rules.is_empty() && self.custom_rules.is_empty()
+        !self.dive
+            && self.inner.is_none()
+            && self.split.is_none()
+            && self.keys.is_none()
+            && self.custom_errors_rules.is_empty()
+            && !self.has_top_level_rules()
     }
 
     pub fn has_top_level_rules(&self) -> bool {
-        !self.rules.is_empty() || !self.custom_rules.is_empty()
+        !self.rules.is_empty()
+            || !self.custom_rules.is_empty()
+            || !self.custom_async_rules.is_empty()
+    }
+
+    /// Sibling fields compared to by rules such as `greater_than`, including those in `keys(...)` and `inner(...)`.
+    pub fn referenced_fields(&self) -> Vec<&Ident> {
+        let mut fields = self
+            .rules
+            .iter()
+            .filter_map(|rule| match &rule.kind {
+                ValidateRuleKind::GreaterThan(v) | ValidateRuleKind::LessThan(v) => Some(&v.field),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        if let Some(keys) = &self.keys {
+            fields.extend(keys.referenced_fields());
+        }
+        if let Some(inner) = &self.inner {
+            fields.extend(inner.referenced_fields());
+        }
+        if let Some(split) = &self.split {
+            fields.extend(split.inner.referenced_fields());
+        }
+        fields
+    }
+
+    /// Sibling fields which must be bound for this field to be validated.
+    pub fn bound_fields(&self) -> Vec<&Ident> {
+        let mut fields = self.referenced_fields();
+        fields.extend(self.when.as_ref().map(|when| &when.root));
+        fields
     }
 }
 
@@ -147,42 +394,107 @@ pub struct ValidateRule {
     pub depth: usize,
 }
 
+/// A sibling field compared to by `greater_than` or `less_than`.
+pub struct Compared {
+    pub field: Ident,
+    /// The name of the field in error messages, which is its alias if it was renamed.
+    pub name: String,
+}
+
 #[repr(u8)]
+#[allow(clippy::large_enum_variant)]
 pub enum ValidateRuleKind {
+    /// Whether empty values are treated as missing, and the custom message, if any.
+    ///
+    /// This is the first variant, so that a missing value is reported before any other error.
+    Required(bool, Option<String>),
     Ascii,
-    Alphanumeric,
+    AsciiBytes,
+    Alphanumeric(AlphanumericKind),
+    Trimmed,
+    /// Whether whitespace control characters are allowed.
+    NonControl(bool),
+    Lowercase,
+    Uppercase,
     Email,
-    Url,
-    Ip,
-    IpV4,
-    IpV6,
-    CreditCard,
-    PhoneNumber,
+    Url(Vec<String>, Vec<String>),
+    Ip(IpScope),
+    IpV4(IpScope),
+    IpV6(IpScope),
+    CreditCard(Vec<String>),
+    Luhn,
+    Iban,
+    Isbn(Option<usize>),
+    Json,
+    Uuid(Option<usize>),
+    Semver(SemverKind),
+    Base64(Base64Kind),
+    /// Whether the variants with an alpha channel are allowed.
+    HexColor(bool),
+    /// Whether ports below 1024 are allowed.
+    Port(bool),
+    /// The optional region, as an identifier spanned at the string literal.
+    PhoneNumber(Option<Ident>),
+    /// The format, which defaults to `%Y-%m-%d`.
+    Date(String),
+    /// The format, or `None` for RFC 3339.
+    DateTime(Option<String>),
     Length(ValidateRange<usize>),
     ByteLength(ValidateRange<usize>),
-    Range(ValidateRange<Expr>),
-    Contains(String),
-    Prefix(String),
-    Suffix(String),
-    Pattern(String),
+    GraphemeLength(ValidateRange<usize>),
+    Range(ValidateBounds),
+    MultipleOf(Expr),
+    GreaterThan(Compared),
+    LessThan(Compared),
+    OneOf(ValidateOneOf),
+    /// The pattern, and whether the match is case-insensitive.
+    Contains(String, bool),
+    /// The forbidden patterns, and whether the match is case-insensitive.
+    NotContains(Vec<String>, bool),
+    Prefix(Vec<String>),
+    Suffix(Vec<String>),
+    Pattern(ValidatePattern),
 }
 
 impl ValidateRule {
     pub fn name(&self) -> &'static str {
         match &self.kind {
             ValidateRuleKind::Ascii => "ascii",
-            ValidateRuleKind::Alphanumeric => "alphanumeric",
+            ValidateRuleKind::AsciiBytes => "ascii_bytes",
+            ValidateRuleKind::Alphanumeric(_) => "alphanumeric",
+            ValidateRuleKind::Trimmed => "trimmed",
+            ValidateRuleKind::Required(..) => "required",
+            ValidateRuleKind::NonControl(_) => "non_control",
+            ValidateRuleKind::Lowercase => "lowercase",
+            ValidateRuleKind::Uppercase => "uppercase",
             ValidateRuleKind::Email => "email",
-            ValidateRuleKind::Url => "url",
-            ValidateRuleKind::Ip => "ip",
-            ValidateRuleKind::IpV4 => "ip",
-            ValidateRuleKind::IpV6 => "ip",
-            ValidateRuleKind::CreditCard => "credit_card",
-            ValidateRuleKind::PhoneNumber => "phone_number",
+            ValidateRuleKind::Url(..) => "url",
+            ValidateRuleKind::Ip(_) => "ip",
+            ValidateRuleKind::IpV4(_) => "ip",
+            ValidateRuleKind::IpV6(_) => "ip",
+            ValidateRuleKind::CreditCard(_) => "credit_card",
+            ValidateRuleKind::Luhn => "luhn",
+            ValidateRuleKind::Iban => "iban",
+            ValidateRuleKind::Isbn(_) => "isbn",
+            ValidateRuleKind::Json => "json",
+            ValidateRuleKind::Uuid(_) => "uuid",
+            ValidateRuleKind::Semver(_) => "semver",
+            ValidateRuleKind::Base64(_) => "base64",
+            ValidateRuleKind::HexColor(_) => "hex_color",
+            ValidateRuleKind::Port(_) => "port",
+            ValidateRuleKind::PhoneNumber(_) => "phone_number",
+            ValidateRuleKind::Date(_) => "datetime",
+            ValidateRuleKind::DateTime(_) => "datetime",
             ValidateRuleKind::Length { .. } => "length",
             ValidateRuleKind::ByteLength { .. } => "byte_length",
+            ValidateRuleKind::GraphemeLength { .. } => "grapheme_length",
             ValidateRuleKind::Range { .. } => "range",
-            ValidateRuleKind::Contains(_) => "contains",
+            ValidateRuleKind::MultipleOf(_) => "multiple_of",
+            ValidateRuleKind::GreaterThan(_) => "compare",
+            ValidateRuleKind::LessThan(_) => "compare",
+            ValidateRuleKind::OneOf(_) => "one_of",
+            ValidateRuleKind::Contains(..) => "contains",
+            ValidateRuleKind::NotContains(..) => "not_contains",
             ValidateRuleKind::Prefix(_) => "prefix",
             ValidateRuleKind::Suffix(_) => "suffix",
             ValidateRuleKind::Pattern(_) => "pattern",
@@ -190,10 +502,90 @@ impl ValidateRule {
     }
 }
 
+pub enum ValidatePattern {
+    /// A regex which is compiled on first use.
+    Lit(String),
+    /// A function which returns a compiled regex.
+    Func(ExprPath),
+}
+
+pub enum ValidateOneOf {
+    /// The allowed strings, and whether the match is case-insensitive.
+    Str(Vec<String>, bool),
+    Int(Vec<Expr>),
+}
+
+pub enum IpScope {
+    Any,
+    Public,
+    Private,
+    Loopback,
+}
+
+pub enum AlphanumericKind {
+    Unicode,
+    Ascii,
+}
+
+pub enum SemverKind {
+    Version,
+    Req,
+}
+
+pub enum Base64Kind {
+    Standard,
+    UrlSafe,
+}
+
 pub enum ValidateRange<T> {
     GreaterThan(T),
     LowerThan(T),
     Between(T, T),
+    Equal(T),
+}
+
+/// Bounds of the `range` rule, at least one of which is present.
+pub struct ValidateBounds {
+    pub min: Option<ValidateBound>,
+    pub max: Option<ValidateBound>,
+}
+
+pub enum ValidateBound {
+    Inclusive(Expr),
+    Exclusive(Expr),
+}
+
+impl ValidateBound {
+    pub fn expr(&self) -> &Expr {
+        match self {
+            ValidateBound::Inclusive(v) | ValidateBound::Exclusive(v) => v,
+        }
+    }
+
+    /// The value of the bound, if it is a (possibly negated) integer or float literal.
+    pub fn literal_value(&self) -> Option<f64> {
+        fn value(expr: &Expr) -> Option<f64> {
+            match expr {
+                Expr::Lit(syn::ExprLit {
+                    lit: syn::Lit::Int(v),
+                    ..
+                }) => v.base10_parse().ok(),
+                Expr::Lit(syn::ExprLit {
+                    lit: syn::Lit::Float(v),
+                    ..
+                }) => v.base10_parse().ok(),
+                Expr::Unary(syn::ExprUnary {
+                    op: syn::UnOp::Neg(_),
+                    expr,
+                    ..
+                }) => value(expr).map(|v| -v),
+                Expr::Paren(syn::ExprParen { expr, .. }) => value(expr),
+                _ => None,
+            }
+        }
+
+        value(self.expr())
+    }
 }
 
 pub enum ValidateVariant {
@@ -227,7 +619,7 @@ impl ValidateRuleKind {
 
 impl PartialOrd for ValidateRuleKind {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        self.discriminant().partial_cmp(&other.discriminant())
+        Some(self.cmp(other))
     }
 }
 
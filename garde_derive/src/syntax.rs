@@ -54,7 +54,7 @@ fn parse_input_attr_list(attrs: &[syn::Attribute]) -> syn::Result<Vec<(Span, mod
     for attr in attrs.iter() {
         if attr.path().is_ident("garde") {
             match parse_input_attr(attr) {
-                Ok(v) => out.push((attr.span(), v)),
+                Ok(list) => out.extend(list.into_iter().map(|v| (attr.span(), v))),
                 Err(e) => error.maybe_fold(e),
             }
         }
@@ -67,7 +67,7 @@ fn parse_input_attr_list(attrs: &[syn::Attribute]) -> syn::Result<Vec<(Span, mod
     Ok(out)
 }
 
-fn parse_input_attr(attr: &syn::Attribute) -> syn::Result<model::Attr> {
+fn parse_input_attr(attr: &syn::Attribute) -> syn::Result<Punctuated<model::Attr, Token![,]>> {
     let meta_list = match attr.meta.require_list() {
         Ok(v) => v,
         Err(_) => {
@@ -78,7 +78,7 @@ fn parse_input_attr(attr: &syn::Attribute) -> syn::Result<model::Attr> {
         }
     };
 
-    syn::parse2::<model::Attr>(meta_list.tokens.clone())
+    meta_list.parse_args_with(Punctuated::<model::Attr, Token![,]>::parse_terminated)
 }
 
 impl Parse for model::Attr {
@@ -89,7 +89,22 @@ impl Parse for model::Attr {
                 let content;
                 syn::parenthesized!(content in input);
                 let ty = content.parse::<Type>()?;
-                Ok(model::Attr::Context(Box::new(ty)))
+                let mut default = None;
+                if !content.is_empty() {
+                    <Token![,]>::parse(&content)?;
+                    let ident = Ident::parse_any(&content)?;
+                    if ident != "default" {
+                        return Err(syn::Error::new(ident.span(), "unexpected argument"));
+                    }
+                    <Token![=]>::parse(&content)?;
+                    default = Some(content.parse()?);
+                }
+                Ok(model::Attr::Context(Box::new(ty), default))
+            }
+            "transparent" => Ok(model::Attr::Transparent),
+            "validate" => {
+                <Token![=]>::parse(input)?;
+                Ok(model::Attr::Validate(input.parse()?))
             }
             _ => Err(syn::Error::new(ident.span(), "unrecognized attribute")),
         }
@@ -240,9 +255,34 @@ impl Parse for model::RawRule {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
         let ident = Ident::parse_any(input)?;
 
+        if ident == "when" {
+            <Token![=]>::parse(input)?;
+            return Ok(model::RawRule {
+                span: ident.span(),
+                kind: model::RawRuleKind::When(input.parse()?),
+            });
+        }
+
+        // `rename = "..."` is also accepted, for consistency with serde
+        if ident == "rename" && input.peek(Token![=]) {
+            <Token![=]>::parse(input)?;
+            return Ok(model::RawRule {
+                span: ident.span(),
+                kind: model::RawRuleKind::Rename(input.parse()?),
+            });
+        }
+
+        if ident == "unless" {
+            <Token![=]>::parse(input)?;
+            return Ok(model::RawRule {
+                span: ident.span(),
+                kind: model::RawRuleKind::Unless(input.parse()?),
+            });
+        }
+
         macro_rules! rules {
             (($input:ident, $ident:ident) {
-                $($name:literal => $rule:ident $(($content:ident))?,)*
+                $($name:literal => $rule:ident $(($content:ident))? $([$opt_content:ident])?,)*
             }) => {
                 match $ident.to_string().as_str() {
                     $(
@@ -251,9 +291,19 @@ impl Parse for model::RawRule {
                                 let $content;
                                 syn::parenthesized!($content in $input);
                             )?
+                            $(
+                                // optional arguments, parsed from an empty stream if omitted
+                                let $opt_content = if $input.peek(syn::token::Paren) {
+                                    let $opt_content;
+                                    syn::parenthesized!($opt_content in $input);
+                                    $opt_content.parse()?
+                                } else {
+                                    syn::parse2(proc_macro2::TokenStream::new())?
+                                };
+                            )?
                             Ok(model::RawRule {
                                 span: $ident.span(),
-                                kind: model::RawRuleKind::$rule $(($content.parse()?))?
+                                kind: model::RawRuleKind::$rule $(($content.parse()?))? $(($opt_content))?
                             })
                         }
                     )*
@@ -265,37 +315,526 @@ impl Parse for model::RawRule {
         rules! {
             (input, ident) {
                 "skip" => Skip,
+                "skip_if" => SkipIf(content),
                 "rename" => Rename(content),
                 "message" => Message(content),
                 "code" => Code(content),
-                "dive" => Dive,
-                "ascii" => Ascii,
-                "alphanumeric" => Alphanumeric,
+                "dive" => Dive[content],
+                "required" => Required[content],
+                "ascii" => Ascii[content],
+                "alphanumeric" => Alphanumeric[content],
+                "trimmed" => Trimmed,
+                "non_control" => NonControl[content],
+                "lowercase" => Lowercase,
+                "uppercase" => Uppercase,
                 "email" => Email,
-                "url" => Url,
-                "ip" => Ip,
-                "ipv4" => IpV4,
-                "ipv6" => IpV6,
-                "credit_card" => CreditCard,
-                "phone_number" => PhoneNumber,
+                "url" => Url[content],
+                "ip" => Ip[content],
+                "ipv4" => IpV4[content],
+                "ipv6" => IpV6[content],
+                "credit_card" => CreditCard[content],
+                "luhn" => Luhn,
+                "iban" => Iban,
+                "isbn" => Isbn[content],
+                "json" => Json,
+                "uuid" => Uuid[content],
+                "semver" => Semver[content],
+                "base64" => Base64[content],
+                "hex_color" => HexColor[content],
+                "port" => Port[content],
+                "phone_number" => PhoneNumber[content],
+                "date" => Date[content],
+                "datetime" => DateTime[content],
                 "length" => Length(content),
                 "byte_length" => ByteLength(context),
                 "range" => Range(context),
+                "multiple_of" => MultipleOf(context),
+                "greater_than" => GreaterThan(content),
+                "less_than" => LessThan(content),
+                "one_of" => OneOf(content),
                 "contains" => Contains(context),
+                "not_contains" => NotContains(content),
                 "prefix" => Prefix(context),
                 "suffix" => Suffix(context),
                 "pattern" => Pattern(context),
                 "custom" => Custom(context),
-                "inner" => Inner(context),
+                "custom_async" => CustomAsync(context),
+                "custom_errors" => CustomErrors(context),
+                "inner" => Inner(content),
+                "keys" => Keys(content),
+                "split" => Split(content),
             }
         }
     }
 }
 
+impl Parse for model::FieldPath {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let span = input.span();
+        let mut path =
+            Punctuated::<Ident, Token![.]>::parse_separated_nonempty_with(input, Ident::parse_any)?
+                .into_iter();
+        let root = path.next().expect("path is not empty");
+        Ok(model::FieldPath {
+            span,
+            root,
+            rest: path.collect(),
+        })
+    }
+}
+
 impl Parse for model::Str {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let lit = <syn::LitStr as Parse>::parse(input)?;
         Ok(model::Str {
-            value: <syn::LitStr as Parse>::parse(input)?.value(),
+            span: lit.span(),
+            value: lit.value(),
+        })
+    }
+}
+
+impl<T: Parse> Parse for model::List<T> {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let span = input.span();
+        let contents = Punctuated::<T, Token![,]>::parse_terminated(input)?;
+        Ok(model::List {
+            span,
+            contents: contents.into_iter().collect(),
+        })
+    }
+}
+
+impl Parse for model::Split {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let delimiter = input.parse()?;
+        let mut empty = None;
+        let mut inner = None;
+
+        while !input.is_empty() {
+            <Token![,]>::parse(input)?;
+            if input.is_empty() {
+                break;
+            }
+            let ident = Ident::parse_any(input)?;
+            match ident.to_string().as_str() {
+                "inner" if inner.is_none() => {
+                    let content;
+                    syn::parenthesized!(content in input);
+                    inner = Some(content.parse()?);
+                }
+                "skip_empty" | "deny_empty" if empty.is_none() => empty = Some(ident),
+                "inner" | "skip_empty" | "deny_empty" => {
+                    return Err(syn::Error::new(ident.span(), "duplicate argument"));
+                }
+                _ => return Err(syn::Error::new(ident.span(), "unexpected argument")),
+            }
+        }
+
+        Ok(model::Split {
+            delimiter,
+            empty,
+            inner,
+        })
+    }
+}
+
+impl Parse for model::Url {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let mut error = None;
+
+        let mut schemes = None::<model::List<model::Str>>;
+        let mut hosts = None::<model::List<model::Str>>;
+
+        while !input.is_empty() {
+            let ident = Ident::parse_any(input)?;
+            let content;
+            syn::parenthesized!(content in input);
+            let list = Punctuated::<model::Str, Token![,]>::parse_terminated(&content)?;
+            if !input.is_empty() {
+                <Token![,]>::parse(input)?;
+            }
+
+            let slot = if ident == "schemes" {
+                &mut schemes
+            } else if ident == "host" {
+                &mut hosts
+            } else {
+                error.maybe_fold(syn::Error::new(ident.span(), "unexpected argument"));
+                continue;
+            };
+            if slot.is_some() {
+                error.maybe_fold(syn::Error::new(ident.span(), "duplicate argument"));
+                continue;
+            }
+            *slot = Some(model::List {
+                span: ident.span(),
+                contents: list.into_iter().collect(),
+            });
+        }
+
+        if let Some(error) = error {
+            return Err(error);
+        }
+
+        Ok(model::Url { schemes, hosts })
+    }
+}
+
+impl Parse for model::CreditCard {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let mut types = Vec::new();
+
+        if !input.is_empty() {
+            let ident = Ident::parse_any(input)?;
+            if ident != "types" {
+                return Err(syn::Error::new(ident.span(), "unexpected argument"));
+            }
+            let content;
+            syn::parenthesized!(content in input);
+            let list = Punctuated::<model::Str, Token![,]>::parse_terminated(&content)?;
+            types.extend(list);
+        }
+
+        Ok(model::CreditCard { types })
+    }
+}
+
+impl Parse for model::Isbn {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let mut version = None;
+
+        if !input.is_empty() {
+            let ident = Ident::parse_any(input)?;
+            if ident != "version" {
+                return Err(syn::Error::new(ident.span(), "unexpected argument"));
+            }
+            <Token![=]>::parse(input)?;
+            version = Some(input.parse()?);
+        }
+
+        Ok(model::Isbn { version })
+    }
+}
+
+impl Parse for model::Uuid {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let mut version = None;
+
+        if !input.is_empty() {
+            let ident = Ident::parse_any(input)?;
+            if ident != "version" {
+                return Err(syn::Error::new(ident.span(), "unexpected argument"));
+            }
+            <Token![=]>::parse(input)?;
+            version = Some(input.parse()?);
+        }
+
+        Ok(model::Uuid { version })
+    }
+}
+
+impl Parse for model::Pattern {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        if input.peek(syn::LitStr) {
+            Ok(model::Pattern::Lit(input.parse()?))
+        } else {
+            Ok(model::Pattern::Func(input.parse()?))
+        }
+    }
+}
+
+impl Parse for model::Dive {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let mode = if input.is_empty() {
+            None
+        } else {
+            Some(Ident::parse_any(input)?)
+        };
+
+        Ok(model::Dive { mode })
+    }
+}
+
+impl Parse for model::Ascii {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let kind = if input.is_empty() {
+            None
+        } else {
+            Some(Ident::parse_any(input)?)
+        };
+
+        Ok(model::Ascii { kind })
+    }
+}
+
+impl Parse for model::Ip {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let scope = if input.is_empty() {
+            None
+        } else {
+            Some(Ident::parse_any(input)?)
+        };
+
+        Ok(model::Ip { scope })
+    }
+}
+
+impl Parse for model::Alphanumeric {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let kind = if input.is_empty() {
+            None
+        } else {
+            Some(Ident::parse_any(input)?)
+        };
+
+        Ok(model::Alphanumeric { kind })
+    }
+}
+
+impl Parse for model::HexColor {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let mut alpha = None;
+
+        if !input.is_empty() {
+            let ident = Ident::parse_any(input)?;
+            if ident != "alpha" {
+                return Err(syn::Error::new(ident.span(), "unexpected argument"));
+            }
+            <Token![=]>::parse(input)?;
+            alpha = Some(input.parse()?);
+        }
+
+        Ok(model::HexColor { alpha })
+    }
+}
+
+impl Parse for model::Port {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let mut privileged = None;
+
+        if !input.is_empty() {
+            let ident = Ident::parse_any(input)?;
+            if ident != "privileged" {
+                return Err(syn::Error::new(ident.span(), "unexpected argument"));
+            }
+            <Token![=]>::parse(input)?;
+            privileged = Some(input.parse()?);
+        }
+
+        Ok(model::Port { privileged })
+    }
+}
+
+impl Parse for model::Semver {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let kind = if input.is_empty() {
+            None
+        } else {
+            Some(Ident::parse_any(input)?)
+        };
+
+        Ok(model::Semver { kind })
+    }
+}
+
+impl Parse for model::Base64 {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let kind = if input.is_empty() {
+            None
+        } else {
+            Some(Ident::parse_any(input)?)
+        };
+
+        Ok(model::Base64 { kind })
+    }
+}
+
+impl Parse for model::DateTime {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let mut format = None;
+
+        if !input.is_empty() {
+            let ident = Ident::parse_any(input)?;
+            if ident != "format" {
+                return Err(syn::Error::new(ident.span(), "unexpected argument"));
+            }
+            <Token![=]>::parse(input)?;
+            format = Some(input.parse()?);
+        }
+
+        Ok(model::DateTime { format })
+    }
+}
+
+impl Parse for model::PhoneNumber {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let mut region = None;
+
+        if !input.is_empty() {
+            let ident = Ident::parse_any(input)?;
+            if ident != "region" {
+                return Err(syn::Error::new(ident.span(), "unexpected argument"));
+            }
+            <Token![=]>::parse(input)?;
+            region = Some(input.parse()?);
+        }
+
+        Ok(model::PhoneNumber { region })
+    }
+}
+
+impl Parse for model::Length {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let mut unit = model::LengthUnit::Chars;
+        if input.peek(syn::Ident) && !input.peek2(Token![=]) {
+            let ident = Ident::parse_any(input)?;
+            unit = match ident.to_string().as_str() {
+                "chars" => model::LengthUnit::Chars,
+                "bytes" => model::LengthUnit::Bytes,
+                "graphemes" => model::LengthUnit::Graphemes,
+                _ => return Err(syn::Error::new(ident.span(), "unexpected argument")),
+            };
+            if !input.is_empty() {
+                <Token![,]>::parse(input)?;
+            }
+        }
+
+        Ok(model::Length {
+            unit,
+            range: input.parse()?,
+        })
+    }
+}
+
+impl Parse for model::Required {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let mut non_empty = false;
+        let mut message = None;
+
+        while !input.is_empty() {
+            let ident = Ident::parse_any(input)?;
+            if ident == "non_empty" {
+                if non_empty {
+                    return Err(syn::Error::new(ident.span(), "duplicate argument"));
+                }
+                non_empty = true;
+            } else if ident == "message" {
+                if message.is_some() {
+                    return Err(syn::Error::new(ident.span(), "duplicate argument"));
+                }
+                <Token![=]>::parse(input)?;
+                message = Some(input.parse()?);
+            } else {
+                return Err(syn::Error::new(ident.span(), "unexpected argument"));
+            }
+            if !input.is_empty() {
+                <Token![,]>::parse(input)?;
+            }
+        }
+
+        Ok(model::Required { non_empty, message })
+    }
+}
+
+impl Parse for model::NonControl {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let mut allow_whitespace = false;
+
+        if !input.is_empty() {
+            let ident = Ident::parse_any(input)?;
+            if ident != "allow_whitespace" {
+                return Err(syn::Error::new(ident.span(), "unexpected argument"));
+            }
+            allow_whitespace = true;
+        }
+
+        Ok(model::NonControl { allow_whitespace })
+    }
+}
+
+impl Parse for model::OneOf {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let span = input.span();
+        let mut values = Vec::new();
+        let mut insensitive = None;
+
+        while !input.is_empty() {
+            if input.peek(syn::LitStr) {
+                values.push(model::OneOfValue::Str(input.parse()?));
+            } else if input.peek(syn::LitInt) || input.peek(Token![-]) {
+                let negative = Option::<Token![-]>::parse(input)?.is_some();
+                values.push(model::OneOfValue::Int {
+                    negative,
+                    lit: input.parse()?,
+                });
+            } else {
+                let ident = Ident::parse_any(input)?;
+                if ident != "insensitive" {
+                    return Err(syn::Error::new(
+                        ident.span(),
+                        "expected string literal, integer literal, or `insensitive`",
+                    ));
+                }
+                insensitive = Some(ident.span());
+            }
+            if !input.is_empty() {
+                <Token![,]>::parse(input)?;
+            }
+        }
+
+        Ok(model::OneOf {
+            span,
+            values,
+            insensitive,
+        })
+    }
+}
+
+impl Parse for model::Contains {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let pattern = input.parse()?;
+        let mut insensitive = false;
+
+        if !input.is_empty() {
+            <Token![,]>::parse(input)?;
+            let ident = Ident::parse_any(input)?;
+            if ident != "insensitive" {
+                return Err(syn::Error::new(ident.span(), "unexpected argument"));
+            }
+            insensitive = true;
+        }
+
+        Ok(model::Contains {
+            pattern,
+            insensitive,
+        })
+    }
+}
+
+impl Parse for model::NotContains {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let span = input.span();
+        let mut contents = Vec::new();
+        let mut insensitive = false;
+
+        while !input.is_empty() {
+            if input.peek(syn::LitStr) {
+                contents.push(input.parse()?);
+            } else {
+                let ident = Ident::parse_any(input)?;
+                if ident != "insensitive" {
+                    return Err(syn::Error::new(ident.span(), "unexpected argument"));
+                }
+                insensitive = true;
+            }
+            if !input.is_empty() {
+                <Token![,]>::parse(input)?;
+            }
+        }
+
+        Ok(model::NotContains {
+            patterns: model::List { span, contents },
+            insensitive,
         })
     }
 }
@@ -335,41 +874,51 @@ where
 
         let mut min = None::<T>;
         let mut max = None::<T>;
+        let mut exclusive_min = None::<T>;
+        let mut exclusive_max = None::<T>;
+        let mut equal = None::<T>;
 
         for pair in pairs {
-            if pair.path.is_ident("min") {
-                if min.is_some() {
-                    error.maybe_fold(syn::Error::new(pair.path.span(), "duplicate argument"));
-                    continue;
-                }
-                let value = match <T as FromExpr>::from_expr(pair.value) {
-                    Ok(v) => v,
-                    Err(e) => {
-                        error.maybe_fold(e);
-                        continue;
-                    }
-                };
-                min = Some(value);
+            let slot = if pair.path.is_ident("min") {
+                &mut min
             } else if pair.path.is_ident("max") {
-                if max.is_some() {
-                    error.maybe_fold(syn::Error::new(pair.path.span(), "duplicate argument"));
-                    continue;
-                }
-                let value = match <T as FromExpr>::from_expr(pair.value) {
-                    Ok(v) => v,
-                    Err(e) => {
-                        error.maybe_fold(e);
-                        continue;
-                    }
-                };
-                max = Some(value);
+                &mut max
+            } else if pair.path.is_ident("exclusive_min") {
+                &mut exclusive_min
+            } else if pair.path.is_ident("exclusive_max") {
+                &mut exclusive_max
+            } else if pair.path.is_ident("equal") {
+                &mut equal
             } else {
                 error.maybe_fold(syn::Error::new(pair.path.span(), "unexpected argument"));
                 continue;
+            };
+            if slot.is_some() {
+                error.maybe_fold(syn::Error::new(pair.path.span(), "duplicate argument"));
+                continue;
             }
+            let value = match <T as FromExpr>::from_expr(pair.value) {
+                Ok(v) => v,
+                Err(e) => {
+                    error.maybe_fold(e);
+                    continue;
+                }
+            };
+            *slot = Some(value);
         }
 
-        Ok(model::Range { span, min, max })
+        if let Some(error) = error {
+            return Err(error);
+        }
+
+        Ok(model::Range {
+            span,
+            min,
+            max,
+            exclusive_min,
+            exclusive_max,
+            equal,
+        })
     }
 }
 
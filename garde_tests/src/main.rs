@@ -1,5 +1,6 @@
 use garde::Validate;
 
+#[allow(dead_code)]
 #[derive(Validate)]
 struct Test {
     #[garde(skip)]
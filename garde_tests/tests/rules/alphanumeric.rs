@@ -15,3 +15,63 @@ fn alphanumeric_valid() {
 fn alphanumeric_invalid() {
     util::check_fail!(&[Test { field: "!!!!" }], &())
 }
+
+#[derive(Debug, garde::Validate)]
+struct Ascii<'a> {
+    #[garde(alphanumeric(ascii))]
+    field: &'a str,
+}
+
+#[derive(Debug, garde::Validate)]
+struct Unicode<'a> {
+    #[garde(alphanumeric(unicode))]
+    field: &'a str,
+}
+
+#[test]
+fn alphanumeric_unicode_valid() {
+    util::check_ok(
+        &[
+            Unicode { field: "abcd0123" },
+            Unicode { field: "José2" },
+            Unicode { field: "日本語" },
+            Unicode { field: "١٢٣" },
+            Unicode { field: "१२३" },
+        ],
+        &(),
+    );
+    util::check_ok(&[Test { field: "José2" }, Test { field: "١٢٣" }], &())
+}
+
+#[test]
+fn alphanumeric_unicode_invalid() {
+    util::check_fail!(
+        &[
+            Unicode { field: "José 2" },
+            Unicode {
+                field: "Jose\u{301}"
+            },
+            Unicode {
+                field: "नमस्ते"
+            },
+        ],
+        &()
+    )
+}
+
+#[test]
+fn alphanumeric_ascii_valid() {
+    util::check_ok(&[Ascii { field: "abcd0123" }], &())
+}
+
+#[test]
+fn alphanumeric_ascii_invalid() {
+    util::check_fail!(
+        &[
+            Ascii { field: "José2" },
+            Ascii { field: "١٢٣" },
+            Ascii { field: "!!!!" },
+        ],
+        &()
+    )
+}
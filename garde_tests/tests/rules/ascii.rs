@@ -14,3 +14,79 @@ fn ascii_valid() {
 fn ascii_invalid() {
     util::check_fail!(&[Test { field: "😂" }], &())
 }
+
+#[derive(Debug)]
+struct Name(String);
+
+impl AsRef<str> for Name {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+#[derive(Debug, garde::Validate)]
+struct Newtype {
+    #[garde(ascii)]
+    name: Name,
+}
+
+#[test]
+fn ascii_newtype_valid() {
+    util::check_ok(
+        &[Newtype {
+            name: Name("a!0_~".into()),
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn ascii_newtype_invalid() {
+    util::check_fail!(
+        &[Newtype {
+            name: Name("😂".into()),
+        }],
+        &()
+    )
+}
+
+#[derive(Debug, garde::Validate)]
+struct Bytes<'a> {
+    #[garde(ascii(bytes))]
+    vec: Vec<u8>,
+    #[garde(ascii(bytes))]
+    slice: &'a [u8],
+    #[garde(ascii(bytes))]
+    array: [u8; 3],
+}
+
+#[test]
+fn ascii_bytes_valid() {
+    util::check_ok(
+        &[
+            Bytes {
+                vec: b"a!0_~".to_vec(),
+                slice: b"",
+                array: [0x00, 0x41, 0x7F],
+            },
+            Bytes {
+                vec: vec![],
+                slice: b"\t\r\n",
+                array: *b"abc",
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn ascii_bytes_invalid() {
+    util::check_fail!(
+        &[Bytes {
+            vec: vec![b'a', b'b', 0x80],
+            slice: "😂".as_bytes(),
+            array: [0x41, 0xFF, 0x42],
+        }],
+        &()
+    )
+}
@@ -0,0 +1,64 @@
+use super::util;
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(base64)]
+    field: &'a str,
+}
+
+#[test]
+fn base64_valid() {
+    util::check_ok(
+        &[
+            Test { field: "" },
+            Test { field: "Zg==" },
+            Test { field: "Zm8=" },
+            Test { field: "Zm9v" },
+            Test { field: "+/+/" },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn base64_empty_valid() {
+    util::check_ok(&[Test { field: "" }], &());
+    util::check_ok(&[UrlSafe { field: "" }], &());
+}
+
+#[test]
+fn base64_invalid() {
+    util::check_fail!(
+        &[
+            Test { field: "Zg" },
+            Test { field: "Zg=" },
+            Test { field: "Zm9v$" },
+            Test { field: "-_-_" },
+            Test { field: "Zm9vä" },
+            Test { field: "Zh==" },
+        ],
+        &()
+    )
+}
+
+#[derive(Debug, garde::Validate)]
+struct UrlSafe<'a> {
+    #[garde(base64(url_safe))]
+    field: &'a str,
+}
+
+#[test]
+fn base64_url_safe_valid() {
+    util::check_ok(
+        &[
+            UrlSafe { field: "-_-_" },
+            UrlSafe { field: "Zg==" },
+            UrlSafe { field: "Zg" },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn base64_url_safe_invalid() {
+    util::check_fail!(&[UrlSafe { field: "+/+/" }, UrlSafe { field: "Z" },], &())
+}
@@ -42,3 +42,53 @@ fn byte_length_invalid() {
         },
     ], &())
 }
+
+#[derive(Debug, garde::Validate)]
+struct Spellings<'a> {
+    #[garde(byte_length(min = 2, max = 4))]
+    byte_length: &'a str,
+    #[garde(length(bytes, min = 2, max = 4))]
+    length_bytes: &'a str,
+    #[garde(length(min = 2, max = 4))]
+    length: &'a str,
+    #[garde(length(chars, min = 2, max = 4))]
+    length_chars: &'a str,
+}
+
+/// Returns the error messages of each field, in declaration order.
+fn messages(value: &str) -> Vec<Option<String>> {
+    use garde::Validate;
+
+    let errors = Spellings {
+        byte_length: value,
+        length_bytes: value,
+        length: value,
+        length_chars: value,
+    }
+    .validate(&())
+    .err()
+    .map(|e| e.to_field_map())
+    .unwrap_or_default();
+    ["byte_length", "length_bytes", "length", "length_chars"]
+        .iter()
+        .map(|field| errors.get(&format!("value.{field}")).map(|m| m.join(", ")))
+        .collect()
+}
+
+#[test]
+fn length_unit_spellings_agree() {
+    for value in ["a", "ab", "abcde", "😂", "😂😂"] {
+        let messages = messages(value);
+        assert_eq!(messages[0], messages[1], "bytes of {value:?}");
+        assert_eq!(messages[2], messages[3], "chars of {value:?}");
+    }
+    assert_eq!(
+        messages("😂😂"),
+        [
+            Some("byte length is greater than 4".into()),
+            Some("byte length is greater than 4".into()),
+            None,
+            None,
+        ]
+    );
+}
@@ -0,0 +1,64 @@
+use garde::Validate;
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(length(min = 2))]
+    short: &'a str,
+    #[garde(length(max = 2))]
+    long: &'a str,
+    #[garde(range(min = 1), message("must be positive"))]
+    positive: i32,
+    #[garde(keys(ascii))]
+    map: std::collections::BTreeMap<&'a str, ()>,
+    #[garde(custom(untagged))]
+    custom: &'a str,
+}
+
+fn untagged(_: &str, _: &()) -> garde::Result {
+    Err(garde::Error::new("always fails"))
+}
+
+#[test]
+fn code_is_set_by_rules() {
+    let value = Test {
+        short: "a",
+        long: "abc",
+        positive: 0,
+        map: std::collections::BTreeMap::from([("😂", ())]),
+        custom: "",
+    };
+    let errors = value.validate(&()).unwrap_err().flatten();
+    let codes = errors
+        .iter()
+        .map(|(path, error)| (path.as_str(), error.code(), error.to_string()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        codes,
+        [
+            ("value.custom", None, "always fails".to_string()),
+            (
+                "value.long",
+                Some("length.max"),
+                "length is greater than 2".to_string()
+            ),
+            ("value.map[😂]", Some("ascii"), "not ascii".to_string()),
+            (
+                "value.positive",
+                Some("range.min"),
+                "must be positive".to_string()
+            ),
+            (
+                "value.short",
+                Some("length.min"),
+                "length is lower than 2".to_string()
+            ),
+        ]
+    );
+}
+
+#[test]
+fn code_with_custom_error() {
+    let error = garde::Error::with_code("password.weak", "password is not strong enough");
+    assert_eq!(error.code(), Some("password.weak"));
+    assert_eq!(error.to_string(), "password is not strong enough");
+}
@@ -0,0 +1,152 @@
+use chrono::NaiveDate;
+
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Test {
+    #[garde(skip)]
+    start: u64,
+    #[garde(greater_than(start))]
+    end: u64,
+    #[garde(less_than(end))]
+    middle: u64,
+}
+
+#[test]
+fn compare_valid() {
+    util::check_ok(
+        &[
+            Test {
+                start: 0,
+                end: 10,
+                middle: 5,
+            },
+            Test {
+                start: 0,
+                end: 1,
+                middle: 0,
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn compare_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                start: 10,
+                end: 10,
+                middle: 10,
+            },
+            Test {
+                start: 10,
+                end: 5,
+                middle: 20,
+            },
+        ],
+        &()
+    )
+}
+
+#[derive(Debug, garde::Validate)]
+struct Optional {
+    #[garde(skip)]
+    start: Option<NaiveDate>,
+    #[garde(greater_than(start))]
+    end: Option<NaiveDate>,
+}
+
+fn date(y: i32, m: u32, d: u32) -> Option<NaiveDate> {
+    NaiveDate::from_ymd_opt(y, m, d)
+}
+
+#[test]
+fn compare_optional_valid() {
+    util::check_ok(
+        &[
+            Optional {
+                start: date(2023, 1, 1),
+                end: date(2023, 1, 2),
+            },
+            Optional {
+                start: None,
+                end: date(2023, 1, 1),
+            },
+            Optional {
+                start: date(2023, 1, 1),
+                end: None,
+            },
+            Optional {
+                start: None,
+                end: None,
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn compare_optional_invalid() {
+    util::check_fail!(
+        &[
+            Optional {
+                start: date(2023, 1, 2),
+                end: date(2023, 1, 1),
+            },
+            Optional {
+                start: date(2023, 1, 1),
+                end: date(2023, 1, 1),
+            },
+        ],
+        &()
+    )
+}
+
+#[derive(Debug, garde::Validate)]
+struct Float {
+    #[garde(skip)]
+    min: f64,
+    #[garde(greater_than(min))]
+    value: f64,
+}
+
+#[test]
+fn compare_nan_invalid() {
+    util::check_fail!(
+        &[
+            Float {
+                min: 0.0,
+                value: f64::NAN,
+            },
+            Float {
+                min: f64::NAN,
+                value: 0.0,
+            },
+        ],
+        &()
+    )
+}
+
+#[derive(Debug, garde::Validate)]
+struct Renamed {
+    #[garde(skip, rename = "startDate")]
+    start: u64,
+    #[garde(greater_than(start), rename = "endDate")]
+    end: u64,
+    #[garde(inner(less_than(end)))]
+    checkpoints: Vec<u64>,
+}
+
+#[test]
+fn compare_renamed_invalid() {
+    util::check_fail!(
+        &[Renamed {
+            start: 10,
+            end: 5,
+            checkpoints: vec![1, 7],
+        }],
+        &()
+    )
+}
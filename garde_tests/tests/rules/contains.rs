@@ -14,3 +14,77 @@ fn contains_valid() {
 fn contains_invalid() {
     util::check_fail!(&[Test { field: "_____" }], &())
 }
+
+#[derive(Debug, garde::Validate)]
+struct Insensitive<'a> {
+    #[garde(contains("TeSt", insensitive))]
+    field: &'a str,
+    #[garde(contains("straße", insensitive))]
+    unicode: &'a str,
+}
+
+#[test]
+fn contains_insensitive_valid() {
+    util::check_ok(
+        &[
+            Insensitive {
+                field: "_test_",
+                unicode: "hauptstraße",
+            },
+            Insensitive {
+                field: "_TEST_",
+                unicode: "HAUPTSTRAßE",
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn contains_insensitive_invalid() {
+    util::check_fail!(
+        &[
+            Insensitive {
+                field: "_tset_",
+                unicode: "HAUPTSTRASSE",
+            },
+            Insensitive {
+                field: "t e s t",
+                unicode: "strase",
+            },
+        ],
+        &()
+    )
+}
+
+#[derive(Debug)]
+struct Tags(Vec<&'static str>);
+
+// implementations written before `insensitive` existed only provide the case-sensitive check
+impl garde::rules::contains::Contains for Tags {
+    fn validate_contains(&self, pat: &str) -> bool {
+        self.0.contains(&pat)
+    }
+}
+
+#[derive(Debug, garde::Validate)]
+struct Custom {
+    #[garde(contains("test", insensitive))]
+    field: Tags,
+}
+
+#[test]
+fn contains_insensitive_custom_impl() {
+    util::check_ok(
+        &[Custom {
+            field: Tags(vec!["test"]),
+        }],
+        &(),
+    );
+    util::check_fail!(
+        &[Custom {
+            field: Tags(vec!["TEST"]),
+        }],
+        &()
+    )
+}
@@ -34,3 +34,54 @@ fn credit_card_invalid() {
         &()
     )
 }
+
+#[derive(Debug, garde::Validate)]
+struct Restricted<'a> {
+    #[garde(credit_card(types("visa", "mastercard")))]
+    field: &'a str,
+}
+
+#[test]
+fn credit_card_restricted_valid() {
+    util::check_ok(
+        &[
+            Restricted {
+                field: "4539571147647251",
+            },
+            Restricted {
+                field: "5236313877109142",
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn credit_card_restricted_invalid() {
+    util::check_fail!(
+        &[
+            Restricted {
+                field: "343380440754432"
+            },
+            Restricted {
+                field: "5236313877109141"
+            },
+        ],
+        &()
+    )
+}
+
+#[test]
+fn credit_card_detect_brand() {
+    use garde::rules::credit_card::{CardBrand, CreditCard};
+
+    assert_eq!(
+        "4539571147647251".detect_credit_card().ok(),
+        Some(CardBrand::Visa)
+    );
+    assert_eq!(
+        "343380440754432".detect_credit_card().ok(),
+        Some(CardBrand::Amex)
+    );
+    assert!("5236313877109141".detect_credit_card().is_err());
+}
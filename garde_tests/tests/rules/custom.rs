@@ -74,3 +74,104 @@ fn multi_custom_invalid() {
     };
     util::check_fail!(&[Multi { field: "asdf" }], &ctx)
 }
+
+#[derive(Debug)]
+struct Range {
+    start: u32,
+    end: u32,
+}
+
+#[derive(Debug, garde::Validate)]
+struct Schedule {
+    #[garde(length(min = 1), custom_errors(ordered_ranges))]
+    ranges: Vec<Range>,
+    #[garde(dive, custom_errors(unique_names))]
+    people: Vec<Person>,
+}
+
+#[derive(Debug, garde::Validate)]
+struct Person {
+    #[garde(length(min = 1))]
+    name: String,
+}
+
+/// Reports errors at the `end` of each range which ends before it starts.
+fn ordered_ranges(value: &[Range], _: &()) -> Result<(), garde::Errors> {
+    garde::Errors::list(|errors| {
+        for range in value {
+            errors.push(garde::Errors::fields(|errors| {
+                if range.end < range.start {
+                    errors.insert(
+                        "end",
+                        garde::Errors::Simple(vec![garde::Error::new("ends before it starts")]),
+                    );
+                }
+            }));
+        }
+    })
+    .finish()
+}
+
+/// Reports errors at the `name` of each person whose name was already seen.
+fn unique_names(value: &[Person], _: &()) -> Result<(), garde::Errors> {
+    let mut seen = std::collections::HashSet::new();
+    garde::Errors::list(|errors| {
+        for person in value {
+            let duplicate = !seen.insert(person.name.as_str());
+            errors.push(garde::Errors::fields(|errors| {
+                if duplicate {
+                    errors.insert(
+                        "name",
+                        garde::Errors::Simple(vec![garde::Error::new("duplicate name")]),
+                    );
+                }
+            }));
+        }
+    })
+    .finish()
+}
+
+#[test]
+fn custom_errors_valid() {
+    util::check_ok(
+        &[Schedule {
+            ranges: vec![Range { start: 0, end: 1 }, Range { start: 1, end: 1 }],
+            people: vec![Person { name: "a".into() }, Person { name: "b".into() }],
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn custom_errors_invalid() {
+    util::check_fail!(
+        &[
+            Schedule {
+                ranges: vec![Range { start: 0, end: 1 }, Range { start: 2, end: 1 }],
+                people: vec![
+                    Person { name: "a".into() },
+                    Person { name: "".into() },
+                    Person { name: "".into() },
+                ],
+            },
+            Schedule {
+                ranges: vec![],
+                people: vec![],
+            },
+        ],
+        &()
+    )
+}
+
+#[test]
+fn custom_errors_first_error() {
+    use garde::Validate;
+
+    let value = Schedule {
+        ranges: vec![Range { start: 0, end: 1 }, Range { start: 2, end: 1 }],
+        people: vec![],
+    };
+    let (path, error) = value.validate_first_error(&()).unwrap_err();
+    assert_eq!(path, "value.ranges[1].end");
+    assert_eq!(error.to_string(), "ends before it starts");
+}
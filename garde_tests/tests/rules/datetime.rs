@@ -0,0 +1,82 @@
+use super::util;
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(date)]
+    date: &'a str,
+    #[garde(datetime)]
+    datetime: &'a str,
+}
+
+#[test]
+fn datetime_valid() {
+    util::check_ok(
+        &[
+            Test {
+                date: "2023-08-21",
+                datetime: "2023-08-21T12:30:00+02:00",
+            },
+            Test {
+                date: "2024-02-29",
+                datetime: "2023-08-21T12:30:00.123Z",
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn datetime_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                date: "",
+                datetime: "",
+            },
+            Test {
+                date: "2023-02-29",
+                datetime: "2023-08-21T12:30:00",
+            },
+            Test {
+                date: "2023/08/21",
+                datetime: "2023-08-21 12:30",
+            },
+        ],
+        &()
+    )
+}
+
+#[derive(Debug, garde::Validate)]
+struct Format<'a> {
+    #[garde(date(format = "%d.%m.%Y"))]
+    date: &'a str,
+    #[garde(datetime(format = "%Y/%m/%d %H:%M"))]
+    datetime: &'a str,
+}
+
+#[test]
+fn datetime_format_valid() {
+    util::check_ok(
+        &[Format {
+            date: "21.08.2023",
+            datetime: "2023/08/21 12:30",
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn datetime_format_invalid() {
+    util::check_fail!(
+        &[
+            Format {
+                date: "2023-08-21",
+                datetime: "2023/08/21",
+            },
+            Format {
+                date: "32.08.2023",
+                datetime: "2023/08/21 25:00",
+            },
+        ],
+        &()
+    )
+}
@@ -0,0 +1,98 @@
+use garde::validate::with_max_depth;
+use garde::Validate;
+
+#[derive(garde::Validate)]
+struct Node {
+    #[garde(length(min = 1))]
+    name: String,
+    #[garde(dive)]
+    children: Vec<Node>,
+}
+
+impl Node {
+    fn leaf() -> Self {
+        Node {
+            name: String::from("leaf"),
+            children: vec![],
+        }
+    }
+
+    /// Builds a chain of `depth` nodes below a root node.
+    fn chain(depth: usize) -> Self {
+        let mut node = Node::leaf();
+        for _ in 0..depth {
+            node = Node {
+                name: String::from("node"),
+                children: vec![node],
+            };
+        }
+        node
+    }
+}
+
+// The default drop glue is recursive, so it would overflow the stack on its own.
+impl Drop for Node {
+    fn drop(&mut self) {
+        let mut stack = std::mem::take(&mut self.children);
+        while let Some(mut node) = stack.pop() {
+            stack.append(&mut node.children);
+        }
+    }
+}
+
+const DEEP: usize = 1_000_000;
+
+fn expected_path(max_depth: usize) -> String {
+    format!("value{}.children", ".children[0]".repeat(max_depth))
+}
+
+#[test]
+fn depth_limit_exceeded() {
+    let node = Node::chain(DEEP);
+    let errors = with_max_depth(64, || node.validate(&())).unwrap_err();
+    let errors = errors.flatten();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].0, expected_path(64));
+    assert_eq!(
+        errors[0].1.message.as_ref(),
+        "maximum validation depth exceeded"
+    );
+}
+
+#[test]
+fn depth_limit_exceeded_first_error() {
+    let node = Node::chain(DEEP);
+    let (path, error) = with_max_depth(64, || node.validate_first_error(&())).unwrap_err();
+    assert_eq!(path, expected_path(64));
+    assert_eq!(error.message.as_ref(), "maximum validation depth exceeded");
+}
+
+#[test]
+fn depth_limit_not_exceeded() {
+    // the leaf also dives into its (empty) children
+    let node = Node::chain(8);
+    with_max_depth(9, || node.validate(&())).unwrap();
+    with_max_depth(9, || node.validate_first_error(&())).unwrap();
+    assert!(with_max_depth(8, || node.validate(&())).is_err());
+}
+
+#[test]
+fn depth_limit_keeps_other_errors() {
+    let mut node = Node::chain(4);
+    node.name = String::new();
+    let errors = with_max_depth(2, || node.validate(&())).unwrap_err();
+    assert_eq!(
+        errors.to_string(),
+        format!(
+            "{}: maximum validation depth exceeded\nvalue.name: length is lower than 1",
+            expected_path(2)
+        )
+    );
+}
+
+#[test]
+fn depth_limit_is_restored() {
+    let node = Node::chain(8);
+    assert!(with_max_depth(2, || node.validate(&())).is_err());
+    node.validate(&()).unwrap();
+}
@@ -0,0 +1,82 @@
+use garde::{Unvalidated, Valid};
+
+#[derive(Debug, serde::Deserialize, garde::Validate)]
+struct User {
+    #[garde(length(min = 3))]
+    name: String,
+    #[garde(dive)]
+    emails: Vec<Email>,
+}
+
+#[derive(Debug, serde::Deserialize, garde::Validate)]
+struct Email {
+    #[garde(contains("@"))]
+    address: String,
+}
+
+#[test]
+fn deserialize_valid() {
+    let user: Valid<User> =
+        serde_json::from_str(r#"{ "name": "Alice", "emails": [{ "address": "a@b.c" }] }"#).unwrap();
+    assert_eq!(user.name, "Alice");
+    assert_eq!(user.into_inner().emails[0].address, "a@b.c");
+}
+
+#[test]
+fn deserialize_invalid() {
+    let error = serde_json::from_str::<Valid<User>>(
+        r#"{ "name": "Al", "emails": [{ "address": "a@b.c" }, { "address": "b" }] }"#,
+    )
+    .unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "value.emails[1].address: does not contain \"@\"\nvalue.name: length is lower than 3"
+    );
+}
+
+#[test]
+fn deserialize_malformed() {
+    let error = serde_json::from_str::<Valid<User>>(r#"{ "name": "Alice" }"#).unwrap_err();
+    assert!(error.is_data());
+    assert_eq!(
+        error.to_string(),
+        "missing field `emails` at line 1 column 19"
+    );
+}
+
+struct Context {
+    min: usize,
+}
+
+#[derive(Debug, serde::Deserialize, garde::Validate)]
+#[garde(context(Context))]
+struct Tag {
+    #[garde(custom(min_length))]
+    name: String,
+}
+
+fn min_length(value: &str, ctx: &Context) -> garde::Result {
+    if value.len() < ctx.min {
+        return Err(garde::Error::new(format!("shorter than {}", ctx.min)));
+    }
+    Ok(())
+}
+
+#[test]
+fn from_validated_with_context() {
+    let ctx = Context { min: 4 };
+    let tag = Valid::from_validated(
+        Tag {
+            name: "rust".into(),
+        },
+        &ctx,
+    )
+    .unwrap();
+    assert_eq!(tag.name, "rust");
+
+    let errors = Valid::from_validated(Tag { name: "go".into() }, &ctx).unwrap_err();
+    assert_eq!(errors.to_string(), "value.name: shorter than 4");
+
+    let tag: Unvalidated<Tag> = serde_json::from_str(r#"{ "name": "go" }"#).unwrap();
+    assert!(tag.validate(&Context { min: 2 }).is_ok());
+}
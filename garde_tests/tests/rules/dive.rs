@@ -68,3 +68,102 @@ fn email_invalid() {
         &()
     )
 }
+
+#[derive(Debug, garde::Validate)]
+struct Nested<'a> {
+    #[garde(dive)]
+    #[allow(clippy::box_collection)]
+    boxed_list: Box<Vec<Arc<Inner<'a>>>>,
+    #[garde(dive)]
+    shared_slice: Arc<[Rc<Inner<'a>>]>,
+}
+
+#[derive(Debug, garde::Validate)]
+struct Direct<'a> {
+    #[garde(dive)]
+    boxed_list: Vec<Inner<'a>>,
+    #[garde(dive)]
+    shared_slice: Vec<Inner<'a>>,
+}
+
+#[test]
+fn smart_pointers_valid() {
+    let inner = Inner { field: "asdf" };
+    util::check_ok(
+        &[Nested {
+            boxed_list: Box::new(vec![Arc::new(inner), Arc::new(inner)]),
+            shared_slice: Arc::from([Rc::new(inner)]),
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn smart_pointers_invalid() {
+    let valid = Inner { field: "asdf" };
+    let invalid = Inner { field: "" };
+    util::check_fail!(
+        &[Nested {
+            boxed_list: Box::new(vec![Arc::new(invalid), Arc::new(valid)]),
+            shared_slice: Arc::from([Rc::new(invalid)]),
+        }],
+        &()
+    )
+}
+
+#[test]
+fn smart_pointers_same_paths_as_direct() {
+    use garde::Validate;
+
+    let valid = Inner { field: "asdf" };
+    let invalid = Inner { field: "" };
+    let nested = Nested {
+        boxed_list: Box::new(vec![Arc::new(invalid), Arc::new(valid)]),
+        shared_slice: Arc::from([Rc::new(invalid)]),
+    };
+    let direct = Direct {
+        boxed_list: vec![invalid, valid],
+        shared_slice: vec![invalid],
+    };
+
+    let nested_errors = nested.validate(&()).unwrap_err().flatten();
+    let direct_errors = direct.validate(&()).unwrap_err().flatten();
+    assert_eq!(format!("{nested_errors:?}"), format!("{direct_errors:?}"));
+    assert_eq!(
+        nested.validate_first_error(&()).unwrap_err().0,
+        direct.validate_first_error(&()).unwrap_err().0,
+    );
+}
+
+#[derive(Debug, garde::Validate)]
+struct Arrays<'a> {
+    #[garde(dive)]
+    points: [Inner<'a>; 4],
+    #[garde(dive)]
+    empty: [Inner<'a>; 0],
+}
+
+#[test]
+fn arrays_valid() {
+    let inner = Inner { field: "asdf" };
+    util::check_ok(
+        &[Arrays {
+            points: [inner; 4],
+            empty: [],
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn arrays_invalid() {
+    let valid = Inner { field: "asdf" };
+    let invalid = Inner { field: "" };
+    util::check_fail!(
+        &[Arrays {
+            points: [valid, invalid, valid, invalid],
+            empty: [],
+        }],
+        &()
+    )
+}
@@ -18,9 +18,21 @@ fn email_valid() {
 #[test]
 fn email_invalid() {
     util::check_fail!(
-        &[Test {
-            field: "invalid.com"
-        }],
+        &[
+            Test {
+                field: "invalid.com"
+            },
+            Test {
+                field: "@example.com"
+            },
+            Test { field: "user@" },
+            Test {
+                field: "user@exam_ple.com"
+            },
+            Test {
+                field: "us er@example.com"
+            },
+        ],
         &()
     )
 }
@@ -0,0 +1,123 @@
+use garde::{Errors, Validate};
+
+#[derive(Debug, garde::Validate)]
+struct Address<'a> {
+    #[garde(length(min = 1))]
+    street: &'a str,
+    #[garde(dive)]
+    lines: Vec<Line<'a>>,
+}
+
+#[derive(Clone, Debug, garde::Validate)]
+struct Line<'a> {
+    #[garde(ascii)]
+    text: &'a str,
+}
+
+#[derive(Debug, garde::Validate)]
+struct Account<'a> {
+    #[garde(length(min = 3))]
+    name: &'a str,
+}
+
+fn flatten(errors: &Errors) -> Vec<(String, String)> {
+    errors
+        .flatten()
+        .into_iter()
+        .map(|(path, error)| (path, error.to_string()))
+        .collect()
+}
+
+#[test]
+fn dedup_removes_identical_errors_at_the_same_path() {
+    let first = Account { name: "" }.validate(&()).unwrap_err();
+    let taken = Errors::fields(|errors| {
+        errors.insert(
+            "name",
+            Errors::simple(|errors| errors.push(garde::Error::new("already taken"))),
+        )
+    });
+
+    let mut errors = Errors::empty()
+        .merge_at("accounts", first.clone())
+        .merge_at("accounts", taken)
+        .merge_at("accounts", first.clone())
+        .merge_at("other", first);
+    errors.dedup();
+
+    assert_eq!(
+        flatten(&errors),
+        [
+            ("value.accounts.name", "length is lower than 3"),
+            ("value.accounts.name", "already taken"),
+            ("value.other.name", "length is lower than 3"),
+        ]
+        .map(|(path, message)| (path.to_string(), message.to_string()))
+    );
+}
+
+#[test]
+fn dedup_keeps_errors_with_different_codes() {
+    let mut errors = Errors::simple(|errors| {
+        errors.push(garde::Error::with_code("a", "invalid"));
+        errors.push(garde::Error::with_code("b", "invalid"));
+        errors.push(garde::Error::with_code("a", "invalid"));
+    });
+    errors.dedup();
+
+    let codes = errors
+        .flatten()
+        .into_iter()
+        .map(|(_, error)| error.code())
+        .collect::<Vec<_>>();
+    assert_eq!(codes, [Some("a"), Some("b")]);
+}
+
+#[test]
+fn iter_matches_flatten() {
+    let billing = Address {
+        street: "",
+        lines: vec![Line { text: "😂" }, Line { text: "😂" }],
+    };
+    let errors = billing.validate(&()).unwrap_err();
+
+    assert_eq!(errors.len(), 3);
+    assert_eq!(
+        errors
+            .iter()
+            .map(|(path, error)| (path, error.clone()))
+            .collect::<Vec<_>>(),
+        errors.flatten()
+    );
+    assert!(Errors::empty().iter().next().is_none());
+    assert_eq!(Errors::empty().len(), 0);
+}
+
+#[test]
+fn sort_by_path_orders_by_path_string() {
+    let mut lines = vec![Line { text: "ok" }; 11];
+    lines[2].text = "😂";
+    lines[10].text = "😂";
+    let billing = Address { street: "", lines };
+    let errors = billing.validate(&()).unwrap_err();
+
+    let paths = |errors: Vec<(String, garde::Error)>| {
+        errors.into_iter().map(|(path, _)| path).collect::<Vec<_>>()
+    };
+    assert_eq!(
+        paths(errors.flatten()),
+        [
+            "value.lines[2].text",
+            "value.lines[10].text",
+            "value.street"
+        ]
+    );
+    assert_eq!(
+        paths(errors.sort_by_path()),
+        [
+            "value.lines[10].text",
+            "value.lines[2].text",
+            "value.street"
+        ]
+    );
+}
@@ -0,0 +1,47 @@
+use std::collections::HashMap;
+
+use garde::Validate;
+
+#[derive(Debug, garde::Validate)]
+struct Inner<'a> {
+    #[garde(length(min = 1))]
+    field: &'a str,
+}
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(ascii, length(min = 3))]
+    a: &'a str,
+    #[garde(dive, length(max = 1))]
+    b: Vec<Inner<'a>>,
+}
+
+#[test]
+fn field_map() {
+    let value = Test {
+        a: "😂",
+        b: vec![Inner { field: "" }, Inner { field: "" }],
+    };
+    let errors = value.validate(&()).unwrap_err();
+
+    let expected = [
+        ("value.a", &["not ascii", "length is lower than 3"][..]),
+        ("value.b", &["length is greater than 1"]),
+        ("value.b[0].field", &["length is lower than 1"]),
+        ("value.b[1].field", &["length is lower than 1"]),
+    ]
+    .into_iter()
+    .map(|(path, messages)| {
+        (
+            path.to_string(),
+            messages.iter().map(|m| m.to_string()).collect::<Vec<_>>(),
+        )
+    })
+    .collect::<HashMap<_, _>>();
+    assert_eq!(errors.to_field_map(), expected);
+}
+
+#[test]
+fn field_map_empty() {
+    assert!(garde::Errors::empty().to_field_map().is_empty());
+}
@@ -0,0 +1,127 @@
+use garde::Validate;
+
+#[derive(Debug, garde::Validate)]
+struct Inner<'a> {
+    #[garde(length(min = 1))]
+    field: &'a str,
+}
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(ascii, length(min = 3))]
+    a: &'a str,
+    #[garde(dive)]
+    b: Vec<Inner<'a>>,
+    #[garde(dive, length(min = 1))]
+    c: Vec<Inner<'a>>,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, garde::Validate)]
+struct Tuple<'a>(#[garde(skip)] &'a str, #[garde(dive)] Inner<'a>);
+
+#[derive(Debug, garde::Validate)]
+enum Enum<'a> {
+    Struct {
+        #[garde(dive)]
+        inner: (Inner<'a>, Inner<'a>),
+    },
+}
+
+#[derive(Debug, garde::Validate)]
+struct Unordered<'a> {
+    #[garde(length(min = 1))]
+    b: &'a str,
+    #[garde(length(min = 1))]
+    a: &'a str,
+}
+
+fn first_error<T: Validate>(value: &T, ctx: &T::Context) -> Option<(String, String)> {
+    value
+        .validate_first_error(ctx)
+        .err()
+        .map(|(path, error)| (path, error.to_string()))
+}
+
+#[test]
+fn first_error_valid() {
+    let value = Test {
+        a: "asdf",
+        b: vec![Inner { field: "a" }],
+        c: vec![Inner { field: "a" }],
+    };
+    assert_eq!(first_error(&value, &()), None);
+}
+
+#[test]
+fn first_error_invalid() {
+    let value = Test {
+        a: "😂",
+        b: vec![],
+        c: vec![],
+    };
+    assert_eq!(
+        first_error(&value, &()),
+        Some(("value.a".into(), "not ascii".into()))
+    );
+
+    let value = Test {
+        a: "asdf",
+        b: vec![Inner { field: "a" }, Inner { field: "" }],
+        c: vec![],
+    };
+    assert_eq!(
+        first_error(&value, &()),
+        Some(("value.b[1].field".into(), "length is lower than 1".into()))
+    );
+
+    let value = Test {
+        a: "asdf",
+        b: vec![],
+        c: vec![],
+    };
+    assert_eq!(
+        first_error(&value, &()),
+        Some(("value.c".into(), "length is lower than 1".into()))
+    );
+
+    let value = Tuple("", Inner { field: "" });
+    assert_eq!(
+        first_error(&value, &()),
+        Some(("value[1].field".into(), "length is lower than 1".into()))
+    );
+
+    let value = Enum::Struct {
+        inner: (Inner { field: "a" }, Inner { field: "" }),
+    };
+    assert_eq!(
+        first_error(&value, &()),
+        Some((
+            "value.inner[1].field".into(),
+            "length is lower than 1".into()
+        ))
+    );
+}
+
+#[test]
+fn first_error_matches_validate() {
+    let value = Test {
+        a: "😂",
+        b: vec![Inner { field: "" }],
+        c: vec![],
+    };
+    let errors = value.validate(&()).unwrap_err().flatten();
+    let (path, error) = value.validate_first_error(&()).unwrap_err();
+    assert_eq!(errors[0].0, path);
+    assert_eq!(errors[0].1.to_string(), error.to_string());
+}
+
+#[test]
+fn first_error_matches_validate_out_of_order() {
+    let value = Unordered { b: "", a: "" };
+    let errors = value.validate(&()).unwrap_err().flatten();
+    let (path, error) = value.validate_first_error(&()).unwrap_err();
+    assert_eq!(path, "value.a");
+    assert_eq!(errors[0].0, path);
+    assert_eq!(errors[0].1.to_string(), error.to_string());
+}
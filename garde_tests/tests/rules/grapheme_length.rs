@@ -0,0 +1,63 @@
+use super::util;
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(length(graphemes, min = 2, max = 3))]
+    field: &'a str,
+}
+
+#[test]
+fn grapheme_length_valid() {
+    util::check_ok(
+        &[
+            Test { field: "ab" },
+            Test { field: "abc" },
+            Test {
+                // each family is a single grapheme made of 7 chars joined by ZWJ
+                field: "👨‍👩‍👧‍👦👨‍👩‍👧‍👦",
+            },
+            Test {
+                // "e" followed by a combining acute accent is a single grapheme
+                field: "e\u{301}e\u{301}e\u{301}",
+            },
+            Test {
+                // flags are made of two regional indicators
+                field: "🇫🇷🇩🇪",
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn grapheme_length_invalid() {
+    util::check_fail!(
+        &[
+            Test { field: "a" },
+            Test { field: "abcd" },
+            Test {
+                field: "👨‍👩‍👧‍👦"
+            },
+            Test {
+                field: "👨‍👩‍👧‍👦👨‍👩‍👧‍👦👨‍👩‍👧‍👦👨‍👩‍👧‍👦"
+            },
+        ],
+        &()
+    )
+}
+
+#[derive(Debug, garde::Validate)]
+struct Chars<'a> {
+    #[garde(length(min = 2, max = 3))]
+    field: &'a str,
+}
+
+#[test]
+fn char_length_unchanged() {
+    util::check_ok(&[Chars { field: "e\u{301}e" }], &());
+    util::check_fail!(
+        &[Chars {
+            field: "👨‍👩‍👧‍👦"
+        }],
+        &()
+    )
+}
@@ -0,0 +1,62 @@
+use super::util;
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(hex_color)]
+    field: &'a str,
+    #[garde(hex_color(alpha = false))]
+    opaque: &'a str,
+}
+
+#[test]
+fn hex_color_valid() {
+    util::check_ok(
+        &[
+            Test {
+                field: "#fff",
+                opaque: "#000",
+            },
+            Test {
+                field: "#FfF8",
+                opaque: "#a1B2c3",
+            },
+            Test {
+                field: "#0a0b0c",
+                opaque: "#ABCDEF",
+            },
+            Test {
+                field: "#0A0B0C80",
+                opaque: "#123",
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn hex_color_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                field: "fff",
+                opaque: "",
+            },
+            Test {
+                field: "#ffg",
+                opaque: "#ff ",
+            },
+            Test {
+                field: "#",
+                opaque: "#fff8",
+            },
+            Test {
+                field: "#12345",
+                opaque: "#0a0b0c80",
+            },
+            Test {
+                field: "#😂",
+                opaque: "##fff",
+            },
+        ],
+        &()
+    )
+}
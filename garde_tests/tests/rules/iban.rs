@@ -0,0 +1,49 @@
+use super::util;
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(iban)]
+    field: &'a str,
+}
+
+#[test]
+fn iban_valid() {
+    util::check_ok(
+        &[
+            Test {
+                field: "DE89370400440532013000",
+            },
+            Test {
+                field: "GB82 WEST 1234 5698 7654 32",
+            },
+            Test {
+                field: "fr1420041010050500013m02606",
+            },
+            Test {
+                field: "NO9386011117947",
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn iban_invalid() {
+    util::check_fail!(
+        &[
+            Test { field: "" },
+            Test {
+                field: "DE89-3704-0044-0532-0130-00"
+            },
+            Test {
+                field: "ZZ89370400440532013000"
+            },
+            Test {
+                field: "DE8937040044053201300"
+            },
+            Test {
+                field: "DE88370400440532013000"
+            },
+        ],
+        &()
+    )
+}
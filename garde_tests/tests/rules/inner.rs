@@ -0,0 +1,116 @@
+use garde::Validate;
+
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Child<'a> {
+    #[garde(length(min = 1))]
+    field: &'a str,
+}
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(inner(length(min = 1)))]
+    name: Option<String>,
+    #[garde(inner(ascii, length(max = 4)), length(max = 2))]
+    tags: Vec<&'a str>,
+    #[garde(inner(range(min = 1)))]
+    ids: &'a [u32],
+    #[garde(dive)]
+    child: Option<Child<'a>>,
+}
+
+#[test]
+fn inner_valid() {
+    util::check_ok(
+        &[
+            Test {
+                name: Some("a".into()),
+                tags: vec!["a", "abcd"],
+                ids: &[1, 2],
+                child: Some(Child { field: "a" }),
+            },
+            Test {
+                name: None,
+                tags: vec![],
+                ids: &[],
+                child: None,
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn inner_invalid() {
+    util::check_fail!(
+        &[Test {
+            name: Some("".into()),
+            tags: vec!["a", "😂", "abcde"],
+            ids: &[1, 0],
+            child: Some(Child { field: "" }),
+        }],
+        &()
+    )
+}
+
+#[test]
+fn inner_first_error() {
+    let value = Test {
+        name: None,
+        tags: vec!["a", "abcde"],
+        ids: &[1],
+        child: None,
+    };
+    let (path, error) = value.validate_first_error(&()).unwrap_err();
+    assert_eq!(path, "value.tags[1]");
+    assert_eq!(error.to_string(), "length is greater than 4");
+
+    let value = Test {
+        name: Some("".into()),
+        ..value
+    };
+    let (path, _) = value.validate_first_error(&()).unwrap_err();
+    assert_eq!(path, "value.name");
+}
+
+#[derive(Debug, garde::Validate)]
+struct Scores {
+    #[garde(length(max = 4), inner(range(max = 100), multiple_of(5)))]
+    scores: Vec<u8>,
+    #[garde(inner(range(min = -1.0, max = 1.0)))]
+    weights: [f32; 2],
+    #[garde(inner(range(min = 1)))]
+    ids: std::collections::BTreeSet<u32>,
+}
+
+#[test]
+fn inner_numbers_valid() {
+    util::check_ok(
+        &[
+            Scores {
+                scores: vec![0, 55, 100],
+                weights: [-1.0, 0.5],
+                ids: [1, 2].into(),
+            },
+            Scores {
+                scores: vec![],
+                weights: [0.0, 1.0],
+                ids: [].into(),
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn inner_numbers_invalid() {
+    util::check_fail!(
+        &[Scores {
+            scores: vec![100, 101, 3, 105, 5],
+            weights: [2.0, 0.0],
+            ids: [0, 1].into(),
+        }],
+        &()
+    )
+}
@@ -140,3 +140,135 @@ fn ip_v6_invalid() {
         &()
     )
 }
+
+#[derive(Debug, garde::Validate)]
+struct TestIpPublic<'a> {
+    #[garde(ip(public))]
+    field: &'a str,
+}
+
+#[derive(Debug, garde::Validate)]
+struct TestIpV4Private<'a> {
+    #[garde(ipv4(private))]
+    field: &'a str,
+}
+
+#[derive(Debug, garde::Validate)]
+struct TestIpV6Loopback<'a> {
+    #[garde(ipv6(loopback))]
+    field: &'a str,
+}
+
+#[test]
+fn ip_public_valid() {
+    util::check_ok(
+        &[
+            TestIpPublic { field: "1.1.1.1" },
+            TestIpPublic { field: "8.8.4.4" },
+            TestIpPublic {
+                field: "2a02::223:6cff:fe8a:2e8a",
+            },
+            TestIpPublic {
+                field: "::ffff:8.8.8.8",
+            },
+            TestIpPublic {
+                field: "64:ff9b::8.8.8.8",
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn ip_public_invalid() {
+    util::check_fail!(
+        &[
+            TestIpPublic { field: "127.0.0.1" },
+            TestIpPublic { field: "10.0.0.1" },
+            TestIpPublic { field: "0.0.0.0" },
+            TestIpPublic {
+                field: "169.254.1.1"
+            },
+            TestIpPublic {
+                field: "100.64.0.1"
+            },
+            TestIpPublic { field: "192.0.2.1" },
+            TestIpPublic { field: "224.0.0.1" },
+            TestIpPublic {
+                field: "255.255.255.255"
+            },
+            TestIpPublic { field: "240.0.0.1" },
+            TestIpPublic { field: "::1" },
+            TestIpPublic { field: "fd00::1" },
+            TestIpPublic { field: "fe80::1" },
+            TestIpPublic {
+                field: "2001:db8::1"
+            },
+            TestIpPublic {
+                field: "::ffff:192.168.0.1"
+            },
+            TestIpPublic {
+                field: "::127.0.0.1"
+            },
+            TestIpPublic { field: "::7f00:1" },
+            TestIpPublic {
+                field: "64:ff9b::10.0.0.1"
+            },
+            TestIpPublic {
+                field: "64:ff9b:1::1"
+            },
+            TestIpPublic { field: "256.1.1.1" },
+        ],
+        &()
+    )
+}
+
+#[test]
+fn ip_v4_private_valid() {
+    util::check_ok(
+        &[
+            TestIpV4Private { field: "10.0.0.1" },
+            TestIpV4Private {
+                field: "172.16.0.1",
+            },
+            TestIpV4Private {
+                field: "192.168.1.1",
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn ip_v4_private_invalid() {
+    util::check_fail!(
+        &[
+            TestIpV4Private { field: "1.1.1.1" },
+            TestIpV4Private {
+                field: "172.32.0.1"
+            },
+            TestIpV4Private { field: "127.0.0.1" },
+            TestIpV4Private { field: "fd00::1" },
+        ],
+        &()
+    )
+}
+
+#[test]
+fn ip_v6_loopback_valid() {
+    util::check_ok(&[TestIpV6Loopback { field: "::1" }], &())
+}
+
+#[test]
+fn ip_v6_loopback_invalid() {
+    util::check_fail!(
+        &[
+            TestIpV6Loopback { field: "::" },
+            TestIpV6Loopback {
+                field: "2a02::223:6cff:fe8a:2e8a"
+            },
+            TestIpV6Loopback { field: "127.0.0.1" },
+        ],
+        &()
+    )
+}
@@ -0,0 +1,95 @@
+use super::util;
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(isbn)]
+    field: &'a str,
+}
+
+#[test]
+fn isbn_valid() {
+    util::check_ok(
+        &[
+            Test {
+                field: "0-306-40615-2",
+            },
+            Test {
+                field: "0306406152",
+            },
+            Test {
+                field: "0-8044-2957-X",
+            },
+            Test {
+                field: "0 8044 2957 x",
+            },
+            Test {
+                field: "978-0-306-40615-7",
+            },
+            Test {
+                field: "9780306406157",
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn isbn_invalid() {
+    util::check_fail!(
+        &[
+            Test { field: "" },
+            Test { field: "03064061" },
+            Test {
+                field: "0-306-40615-3"
+            },
+            Test {
+                field: "978-0-306-40615-8"
+            },
+            Test {
+                field: "0-306-4061X-2"
+            },
+            Test {
+                field: "978-0-306-40615-X"
+            },
+            Test {
+                field: "978_0_306_40615_7"
+            },
+        ],
+        &()
+    )
+}
+
+#[derive(Debug, garde::Validate)]
+struct Version<'a> {
+    #[garde(isbn(version = 10))]
+    isbn10: &'a str,
+    #[garde(isbn(version = 13))]
+    isbn13: &'a str,
+}
+
+#[test]
+fn isbn_version_valid() {
+    util::check_ok(
+        &[Version {
+            isbn10: "0-306-40615-2",
+            isbn13: "978-0-306-40615-7",
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn isbn_version_invalid() {
+    util::check_fail!(
+        &[
+            Version {
+                isbn10: "978-0-306-40615-7",
+                isbn13: "0-306-40615-2",
+            },
+            Version {
+                isbn10: "0-306-40615-3",
+                isbn13: "978-0-306-40615-8",
+            },
+        ],
+        &()
+    )
+}
@@ -0,0 +1,39 @@
+use super::util;
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(json)]
+    field: &'a str,
+}
+
+#[test]
+fn json_valid() {
+    util::check_ok(
+        &[
+            Test {
+                field: r#"{"a": [1, 2, {"b": null}]}"#,
+            },
+            Test { field: "[]" },
+            Test { field: r#""test""# },
+            Test { field: "1.5" },
+            Test { field: " true " },
+            Test { field: "null" },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn json_invalid() {
+    util::check_fail!(
+        &[
+            Test { field: "" },
+            Test { field: "{" },
+            Test {
+                field: "{\n  \"a\": 1,\n}"
+            },
+            Test { field: "test" },
+            Test { field: "1 2" },
+        ],
+        &()
+    )
+}
@@ -0,0 +1,76 @@
+use std::collections::BTreeMap;
+
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Inner<'a> {
+    #[garde(length(min = 1))]
+    field: &'a str,
+}
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(keys(ascii, length(min = 1, max = 4)))]
+    names: BTreeMap<&'a str, &'a str>,
+    #[garde(keys(range(max = 10)), dive)]
+    ids: BTreeMap<u32, Inner<'a>>,
+}
+
+#[test]
+fn keys_valid() {
+    util::check_ok(
+        &[
+            Test {
+                names: BTreeMap::from([("a", "😂"), ("abcd", "")]),
+                ids: BTreeMap::from([(0, Inner { field: "a" }), (10, Inner { field: "b" })]),
+            },
+            Test {
+                names: BTreeMap::new(),
+                ids: BTreeMap::new(),
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn keys_invalid() {
+    util::check_fail!(
+        &[Test {
+            names: BTreeMap::from([("", "a"), ("😂", "b"), ("abcde", "c")]),
+            ids: BTreeMap::from([(1, Inner { field: "" }), (11, Inner { field: "a" })]),
+        }],
+        &()
+    )
+}
+
+#[test]
+fn keys_and_values_invalid() {
+    util::check_fail!(
+        &[Test {
+            names: BTreeMap::new(),
+            ids: BTreeMap::from([(11, Inner { field: "" })]),
+        }],
+        &()
+    )
+}
+
+#[test]
+fn keys_first_error() {
+    use garde::Validate;
+
+    let value = Test {
+        names: BTreeMap::from([("a", "a")]),
+        ids: BTreeMap::from([(11, Inner { field: "a" })]),
+    };
+    let (path, error) = value.validate_first_error(&()).unwrap_err();
+    assert_eq!(path, "value.ids[11]");
+    assert_eq!(error.to_string(), "greater than 10");
+
+    let value = Test {
+        names: BTreeMap::from([("a", "a")]),
+        ids: BTreeMap::from([(1, Inner { field: "" })]),
+    };
+    let (path, _) = value.validate_first_error(&()).unwrap_err();
+    assert_eq!(path, "value.ids[1].field");
+}
@@ -1,3 +1,5 @@
+use garde::Validate;
+
 use super::util;
 #[derive(Debug, garde::Validate)]
 struct Test<'a> {
@@ -32,3 +34,90 @@ fn length_invalid() {
         },
     ], &())
 }
+
+#[derive(Debug, garde::Validate)]
+struct Exact<'a> {
+    #[garde(length(equal = 6))]
+    chars: &'a str,
+    #[garde(byte_length(equal = 6))]
+    bytes: &'a str,
+    #[garde(length(graphemes, equal = 2))]
+    graphemes: &'a str,
+}
+
+#[test]
+fn exact_length_valid() {
+    util::check_ok(
+        &[Exact {
+            chars: "123456",
+            bytes: "123456",
+            graphemes: "👨‍👩‍👧‍👦a",
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn exact_length_invalid() {
+    util::check_fail!(
+        &[
+            Exact {
+                chars: "12345",
+                bytes: "12345😂",
+                graphemes: "a",
+            },
+            Exact {
+                chars: "1234567",
+                bytes: "😂",
+                graphemes: "abc",
+            },
+        ],
+        &()
+    )
+}
+
+#[derive(Debug, garde::Validate)]
+struct Empty<'a> {
+    #[garde(length(max = 0))]
+    chars: &'a str,
+    #[garde(byte_length(max = 0))]
+    bytes: &'a str,
+    #[garde(length(graphemes, max = 0))]
+    graphemes: &'a str,
+}
+
+#[test]
+fn max_zero_is_not_exact() {
+    let errors = Empty {
+        chars: "a",
+        bytes: "a",
+        graphemes: "a",
+    }
+    .validate(&())
+    .unwrap_err()
+    .flatten();
+    let errors = errors
+        .iter()
+        .map(|(path, error)| (path.as_str(), error.code(), error.message.as_ref()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        errors,
+        [
+            (
+                "value.bytes",
+                Some("byte_length.max"),
+                "byte length is greater than 0"
+            ),
+            (
+                "value.chars",
+                Some("length.max"),
+                "length is greater than 0"
+            ),
+            (
+                "value.graphemes",
+                Some("grapheme_length.max"),
+                "length is greater than 0 graphemes"
+            ),
+        ]
+    );
+}
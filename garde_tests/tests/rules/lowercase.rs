@@ -0,0 +1,34 @@
+use super::util;
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(lowercase)]
+    field: &'a str,
+}
+
+#[test]
+fn lowercase_valid() {
+    util::check_ok(
+        &[
+            Test { field: "" },
+            Test {
+                field: "my-slug-123",
+            },
+            Test { field: "straße" },
+            Test { field: "όσος" },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn lowercase_invalid() {
+    util::check_fail!(
+        &[
+            Test { field: "My-Slug" },
+            Test { field: "STRASSE" },
+            Test { field: "İstanbul" },
+            Test { field: "ΌΣΟΣ" },
+        ],
+        &()
+    )
+}
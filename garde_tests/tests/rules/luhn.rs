@@ -0,0 +1,48 @@
+use super::util;
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(luhn)]
+    field: &'a str,
+}
+
+#[test]
+fn luhn_valid() {
+    util::check_ok(
+        &[
+            Test {
+                field: "4539571147647251",
+            },
+            Test {
+                field: "4539 5711 4764 7251",
+            },
+            Test {
+                field: "079927398713",
+            },
+            Test {
+                field: "0799-2739-8713",
+            },
+            Test { field: "0" },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn luhn_invalid() {
+    util::check_fail!(
+        &[
+            Test { field: "" },
+            Test { field: " - " },
+            Test {
+                field: "079927398710"
+            },
+            Test {
+                field: "0799a2739871"
+            },
+            Test {
+                field: "0799_2739_8713"
+            },
+        ],
+        &()
+    )
+}
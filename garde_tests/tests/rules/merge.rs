@@ -0,0 +1,216 @@
+use garde::{Errors, Validate};
+
+#[derive(Debug, garde::Validate)]
+struct Address<'a> {
+    #[garde(length(min = 1))]
+    street: &'a str,
+    #[garde(dive)]
+    lines: Vec<Line<'a>>,
+}
+
+#[derive(Debug, garde::Validate)]
+struct Line<'a> {
+    #[garde(ascii)]
+    text: &'a str,
+}
+
+#[derive(Debug, garde::Validate)]
+struct Account<'a> {
+    #[garde(length(min = 3))]
+    name: &'a str,
+}
+
+fn flatten(errors: &Errors) -> Vec<(String, String)> {
+    errors
+        .flatten()
+        .into_iter()
+        .map(|(path, error)| (path, error.to_string()))
+        .collect()
+}
+
+#[test]
+fn merge_at_nests_paths() {
+    let account = Account { name: "ab" };
+    let billing = Address {
+        street: "",
+        lines: vec![Line { text: "ok" }, Line { text: "😂" }],
+    };
+
+    let errors = Errors::from(account.validate(&()))
+        .merge_at("billing", billing.validate(&()).into())
+        .merge_at("shipping", Errors::empty());
+
+    assert_eq!(
+        flatten(&errors),
+        [
+            ("value.billing.lines[1].text", "not ascii"),
+            ("value.billing.street", "length is lower than 1"),
+            ("value.name", "length is lower than 3"),
+        ]
+        .map(|(path, message)| (path.to_string(), message.to_string()))
+    );
+}
+
+#[test]
+fn merge_at_orders_fields_by_name() {
+    let error = |message: &'static str| {
+        Errors::fields(|errors| {
+            errors.insert(
+                "name",
+                Errors::simple(|errors| errors.push(garde::Error::new(message))),
+            )
+        })
+    };
+
+    let errors = Errors::empty()
+        .merge_at("zeta", error("first"))
+        .merge_at("mu", error("second"))
+        .merge_at("alpha", error("third"));
+
+    assert_eq!(
+        flatten(&errors),
+        [
+            ("value.alpha.name", "third"),
+            ("value.mu.name", "second"),
+            ("value.zeta.name", "first"),
+        ]
+        .map(|(path, message)| (path.to_string(), message.to_string()))
+    );
+}
+
+#[test]
+fn merge_keeps_order_and_duplicates() {
+    let first = Account { name: "" }.validate(&()).unwrap_err();
+    let second = Errors::fields(|errors| {
+        errors.insert(
+            "name",
+            Errors::simple(|errors| errors.push(garde::Error::new("already taken"))),
+        )
+    });
+
+    let errors = Errors::empty()
+        .merge_at("accounts", first.clone())
+        .merge_at("accounts", second)
+        .merge_at("accounts", first);
+
+    assert_eq!(
+        flatten(&errors),
+        [
+            ("value.accounts.name", "length is lower than 3"),
+            ("value.accounts.name", "already taken"),
+            ("value.accounts.name", "length is lower than 3"),
+        ]
+        .map(|(path, message)| (path.to_string(), message.to_string()))
+    );
+}
+
+#[test]
+fn merge_keeps_order_at_each_path() {
+    let error = |message: &'static str| garde::Error::new(message);
+    let first = Errors::fields(|errors| {
+        errors.insert("a", Errors::simple(|errors| errors.push(error("first a"))))
+    });
+    let second = Errors::Nested(
+        vec![error("second root")],
+        Box::new(Errors::fields(|errors| {
+            errors.insert("a", Errors::simple(|errors| errors.push(error("second a"))))
+        })),
+    );
+    let third = Errors::simple(|errors| errors.push(error("third root")));
+
+    let errors = first.merge(second).merge(third);
+
+    // errors of the value itself are yielded before those of its fields
+    assert_eq!(
+        flatten(&errors),
+        [
+            ("value", "second root"),
+            ("value", "third root"),
+            ("value.a", "first a"),
+            ("value.a", "second a"),
+        ]
+        .map(|(path, message)| (path.to_string(), message.to_string()))
+    );
+}
+
+#[test]
+fn merge_list_with_fields() {
+    let list = Errors::list(|errors| {
+        errors.push(Errors::empty());
+        errors.push(Errors::simple(|errors| {
+            errors.push(garde::Error::new("invalid item"))
+        }))
+    });
+    let fields = Errors::fields(|errors| {
+        errors.insert(
+            "a",
+            Errors::simple(|errors| errors.push(garde::Error::new("invalid field"))),
+        )
+    });
+
+    // both are kept, as a map keyed by index and by field name
+    assert_eq!(
+        flatten(&list.merge(fields)),
+        [("value[1]", "invalid item"), ("value[a]", "invalid field")]
+            .map(|(path, message)| (path.to_string(), message.to_string()))
+    );
+}
+
+#[derive(Debug, garde::Validate)]
+struct Lines<'a> {
+    #[garde(dive, custom_errors(check_lines))]
+    lines: Vec<Line<'a>>,
+}
+
+fn check_lines(lines: &[Line<'_>], _: &()) -> Result<(), Errors> {
+    if lines.len() > 1 {
+        return Err(Errors::fields(|errors| {
+            errors.insert(
+                "len",
+                Errors::simple(|errors| errors.push(garde::Error::new("too many lines"))),
+            )
+        }));
+    }
+    Ok(())
+}
+
+#[test]
+fn dive_with_custom_errors_of_another_kind() {
+    let value = Lines {
+        lines: vec![Line { text: "ok" }, Line { text: "😂" }],
+    };
+
+    assert_eq!(
+        flatten(&value.validate(&()).unwrap_err()),
+        [
+            ("value.lines[1].text", "not ascii"),
+            ("value.lines[len]", "too many lines"),
+        ]
+        .map(|(path, message)| (path.to_string(), message.to_string()))
+    );
+}
+
+#[test]
+fn merge_at_list() {
+    let lines = vec![Line { text: "😂" }];
+    let account = Account { name: "ab" };
+
+    let errors =
+        Errors::from(lines.validate(&())).merge_at("account", account.validate(&()).into());
+
+    assert_eq!(
+        flatten(&errors),
+        [
+            ("value[0].text", "not ascii"),
+            ("value[account].name", "length is lower than 3"),
+        ]
+        .map(|(path, message)| (path.to_string(), message.to_string()))
+    );
+}
+
+#[test]
+fn merge_at_into_empty() {
+    let errors = Errors::empty().merge_at("account", Errors::empty());
+    assert!(errors.is_empty());
+    assert!(errors.finish().is_ok());
+}
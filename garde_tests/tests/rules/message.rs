@@ -0,0 +1,45 @@
+use std::collections::BTreeMap;
+
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(length(min = 3), message("name too short"))]
+    name: &'a str,
+    #[garde(ascii, length(min = 3), message("not a valid code"))]
+    code: &'a str,
+    #[garde(length(min = 1))]
+    other: &'a str,
+    #[garde(
+        length(max = 1),
+        keys(length(max = 3), message("key too long")),
+        message("too many entries")
+    )]
+    map: BTreeMap<&'a str, &'a str>,
+}
+
+#[test]
+fn message_valid() {
+    util::check_ok(
+        &[Test {
+            name: "abc",
+            code: "abc",
+            other: "a",
+            map: BTreeMap::from([("abc", "")]),
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn message_invalid() {
+    util::check_fail!(
+        &[Test {
+            name: "ab",
+            code: "😂",
+            other: "",
+            map: BTreeMap::from([("abcd", ""), ("efgh", "")]),
+        }],
+        &()
+    )
+}
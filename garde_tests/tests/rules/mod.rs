@@ -1,21 +1,64 @@
 mod alphanumeric;
 mod ascii;
+mod base64;
 mod byte_length;
+mod code;
+mod compare;
 mod contains;
 mod credit_card;
 mod custom;
+mod datetime;
+mod depth;
+mod deserialize;
 mod dive;
 mod dive_with_rules;
 mod email;
+mod errors;
+mod field_map;
+mod first_error;
+mod grapheme_length;
+mod hex_color;
+mod iban;
+mod inner;
 mod ip;
+mod isbn;
+mod json;
+mod keys;
 mod length;
+mod lowercase;
+mod luhn;
+mod merge;
+mod message;
 mod multi_rule;
+mod multiple_of;
+mod non_control;
+mod not_contains;
+mod one_of;
+mod parallel;
 mod pattern;
 mod phone_number;
+mod port;
 mod prefix;
 mod range;
+mod rename;
+mod required;
+mod semver;
+mod serialize;
 mod skip;
+mod skip_if;
+mod skip_unless;
+mod split;
+mod struct_validate;
 mod suffix;
+mod transparent;
+mod trimmed;
+mod uppercase;
 mod url;
+mod uuid;
+mod validate_async;
+mod validate_default;
+mod validate_each;
+mod validate_mut;
+mod when;
 
 mod util;
@@ -0,0 +1,66 @@
+use super::util;
+#[derive(Debug, garde::Validate)]
+struct Test {
+    #[garde(multiple_of(12))]
+    field: u64,
+    #[garde(multiple_of(-3))]
+    signed: i8,
+}
+
+#[test]
+fn multiple_of_valid() {
+    util::check_ok(
+        &[
+            Test {
+                field: 0,
+                signed: 0,
+            },
+            Test {
+                field: 12,
+                signed: -3,
+            },
+            Test {
+                field: 144,
+                signed: 126,
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn multiple_of_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                field: 1,
+                signed: 1,
+            },
+            Test {
+                field: 13,
+                signed: -127,
+            },
+        ],
+        &()
+    )
+}
+
+const ZERO: u64 = 0;
+
+#[derive(Debug, garde::Validate)]
+struct Zero {
+    #[garde(multiple_of(ZERO))]
+    field: u64,
+}
+
+#[test]
+fn multiple_of_zero_invalid() {
+    util::check_fail!(&[Zero { field: 0 }], &())
+}
+
+#[test]
+fn multiple_of_signed_min() {
+    use garde::rules::multiple_of::MultipleOf;
+
+    assert!(i8::MIN.validate_multiple_of(&-1).is_ok());
+}
@@ -0,0 +1,42 @@
+use super::util;
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(non_control)]
+    field: &'a str,
+    #[garde(non_control(allow_whitespace), trimmed)]
+    text: &'a str,
+}
+
+#[test]
+fn non_control_valid() {
+    util::check_ok(
+        &[
+            Test {
+                field: "",
+                text: "",
+            },
+            Test {
+                field: "John Smith 😂",
+                text: "line one\nline\ttwo\r\nthree",
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn non_control_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                field: "John\u{0}",
+                text: "\u{1b}[31mred",
+            },
+            Test {
+                field: "line\nbreak",
+                text: "😂\u{7f}",
+            },
+        ],
+        &()
+    )
+}
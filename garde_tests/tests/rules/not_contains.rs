@@ -0,0 +1,74 @@
+use super::util;
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(not_contains("admin", "root"))]
+    field: &'a str,
+    #[garde(not_contains("admin", insensitive))]
+    insensitive: &'a str,
+}
+
+#[test]
+fn not_contains_valid() {
+    util::check_ok(
+        &[
+            Test {
+                field: "user",
+                insensitive: "user",
+            },
+            Test {
+                field: "ADMIN",
+                insensitive: "adm_in",
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn not_contains_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                field: "the_admin",
+                insensitive: "Admin",
+            },
+            Test {
+                field: "root_admin",
+                insensitive: "SYSADMIN",
+            },
+        ],
+        &()
+    )
+}
+
+#[derive(Debug)]
+struct Tags(Vec<&'static str>);
+
+// implementations written before `insensitive` existed only provide the case-sensitive check
+impl garde::rules::not_contains::NotContains for Tags {
+    fn validate_not_contains(&self, pat: &str) -> bool {
+        !self.0.contains(&pat)
+    }
+}
+
+#[derive(Debug, garde::Validate)]
+struct Custom {
+    #[garde(not_contains("test", insensitive))]
+    field: Tags,
+}
+
+#[test]
+fn not_contains_insensitive_custom_impl() {
+    util::check_ok(
+        &[Custom {
+            field: Tags(vec!["TEST"]),
+        }],
+        &(),
+    );
+    util::check_fail!(
+        &[Custom {
+            field: Tags(vec!["test"]),
+        }],
+        &()
+    )
+}
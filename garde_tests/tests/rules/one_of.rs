@@ -0,0 +1,54 @@
+use super::util;
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(one_of("draft", "published", "archived"))]
+    field: &'a str,
+    #[garde(one_of("draft", "published", insensitive))]
+    insensitive: String,
+    #[garde(one_of(-1, 0, 1))]
+    number: i32,
+    #[garde(inner(one_of(1, 2, 3)))]
+    inner: &'a [u8],
+}
+
+#[test]
+fn one_of_valid() {
+    util::check_ok(
+        &[
+            Test {
+                field: "draft",
+                insensitive: "Draft".into(),
+                number: -1,
+                inner: &[1, 2],
+            },
+            Test {
+                field: "archived",
+                insensitive: "PUBLISHED".into(),
+                number: 1,
+                inner: &[],
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn one_of_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                field: "Draft",
+                insensitive: "deleted".into(),
+                number: 2,
+                inner: &[0, 3],
+            },
+            Test {
+                field: "",
+                insensitive: "draft ".into(),
+                number: -2,
+                inner: &[4],
+            },
+        ],
+        &()
+    )
+}
@@ -0,0 +1,97 @@
+// `garde_tests` enables the `rayon` feature, which is required for `dive(par)`.
+
+use garde::validate::with_max_depth;
+use garde::Validate;
+
+#[derive(garde::Validate)]
+struct Record {
+    #[garde(range(max = 10))]
+    value: u32,
+}
+
+#[derive(garde::Validate)]
+struct Batch {
+    #[garde(dive(par))]
+    records: Vec<Record>,
+}
+
+const COUNT: u32 = 100_000;
+
+fn batch() -> Batch {
+    Batch {
+        records: (0..COUNT)
+            .map(|i| Record {
+                value: if i % 7919 == 0 { 11 } else { 0 },
+            })
+            .collect(),
+    }
+}
+
+#[test]
+fn parallel_errors_in_index_order() {
+    let errors = batch().validate(&()).unwrap_err();
+    let paths = errors
+        .flatten()
+        .into_iter()
+        .map(|(path, _)| path)
+        .collect::<Vec<_>>();
+    let expected = (0..COUNT)
+        .step_by(7919)
+        .map(|i| format!("value.records[{i}].value"))
+        .collect::<Vec<_>>();
+    assert_eq!(paths, expected);
+}
+
+#[test]
+fn parallel_first_error() {
+    let mut batch = batch();
+    batch.records[0].value = 0;
+    let (path, error) = batch.validate_first_error(&()).unwrap_err();
+    assert_eq!(path, "value.records[7919].value");
+    assert_eq!(error.message.as_ref(), "greater than 10");
+}
+
+#[test]
+fn parallel_valid() {
+    let batch = Batch {
+        records: (0..COUNT).map(|_| Record { value: 1 }).collect(),
+    };
+    batch.validate(&()).unwrap();
+    batch.validate_first_error(&()).unwrap();
+}
+
+#[derive(garde::Validate)]
+struct Node {
+    #[garde(dive(par))]
+    children: Vec<Node>,
+}
+
+#[test]
+fn parallel_keeps_depth_limit() {
+    let node = Node {
+        children: (0..2000)
+            .map(|_| Node {
+                children: vec![Node { children: vec![] }],
+            })
+            .collect(),
+    };
+    let errors = with_max_depth(2, || node.validate(&())).unwrap_err();
+    let errors = errors.flatten();
+    assert_eq!(errors.len(), 2000);
+    assert_eq!(errors[1999].0, "value.children[1999].children[0].children");
+    assert_eq!(
+        errors[1999].1.message.as_ref(),
+        "maximum validation depth exceeded"
+    );
+}
+
+#[test]
+fn parallel_same_as_sequential() {
+    let batch = batch();
+    assert_eq!(
+        garde::validate::par_validate(&batch.records, &())
+            .unwrap_err()
+            .flatten(),
+        batch.records.validate(&()).unwrap_err().flatten()
+    );
+}
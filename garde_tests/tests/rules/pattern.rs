@@ -14,3 +14,92 @@ fn pattern_valid() {
 fn pattern_invalid() {
     util::check_fail!(&[Test { field: "dcba" }, Test { field: "hgfe" }], &())
 }
+
+#[derive(Debug, garde::Validate)]
+struct Generic<T: garde::rules::pattern::Pattern> {
+    #[garde(pattern(r"^abcd|efgh$"))]
+    a: T,
+    #[garde(pattern(r"^abcd|efgh$"))]
+    b: T,
+}
+
+#[test]
+fn pattern_generic_valid() {
+    util::check_ok(
+        &[Generic {
+            a: "abcd",
+            b: "efgh",
+        }],
+        &(),
+    );
+    util::check_ok(
+        &[Generic {
+            a: String::from("efgh"),
+            b: String::from("abcd"),
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn pattern_generic_invalid() {
+    util::check_fail!(
+        &[
+            Generic {
+                a: "dcba",
+                b: "abcd"
+            },
+            Generic {
+                a: "abcd",
+                b: "hgfe"
+            },
+        ],
+        &()
+    )
+}
+
+static SHARED: once_cell::sync::Lazy<regex::Regex> = once_cell::sync::Lazy::new(|| {
+    regex::Regex::new(&format!("^(?:{})$", ["abcd", "efgh"].join("|"))).unwrap()
+});
+
+fn shared() -> &'static regex::Regex {
+    &SHARED
+}
+
+fn lazy() -> &'static once_cell::sync::Lazy<regex::Regex> {
+    &SHARED
+}
+
+#[derive(Debug, garde::Validate)]
+struct Func<'a> {
+    #[garde(pattern(shared))]
+    a: &'a str,
+    #[garde(pattern(self::lazy))]
+    b: &'a str,
+    #[garde(inner(pattern(shared)))]
+    c: Vec<&'a str>,
+}
+
+#[test]
+fn pattern_func_valid() {
+    util::check_ok(
+        &[Func {
+            a: "abcd",
+            b: "efgh",
+            c: vec!["abcd", "efgh"],
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn pattern_func_invalid() {
+    util::check_fail!(
+        &[Func {
+            a: "abcdefgh",
+            b: "dcba",
+            c: vec!["abcd", "hgfe"],
+        }],
+        &()
+    )
+}
@@ -42,3 +42,42 @@ fn phone_number_invalid() {
         &()
     )
 }
+
+#[derive(Debug, garde::Validate)]
+struct Region<'a> {
+    #[garde(phone_number(region = "FR"))]
+    field: &'a str,
+}
+
+#[test]
+fn phone_number_region_valid() {
+    util::check_ok(
+        &[
+            Region {
+                field: "+33642926829",
+            },
+            Region {
+                field: "+33 1 23 45 67 89",
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn phone_number_region_invalid() {
+    util::check_fail!(
+        &[
+            Region {
+                field: "+14152370800"
+            },
+            Region {
+                field: "+442071838750"
+            },
+            Region {
+                field: "0642926829"
+            },
+        ],
+        &()
+    )
+}
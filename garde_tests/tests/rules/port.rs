@@ -0,0 +1,73 @@
+use super::util;
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(port)]
+    field: &'a str,
+    #[garde(port)]
+    number: i32,
+    #[garde(port(privileged = false))]
+    unprivileged: u16,
+}
+
+#[test]
+fn port_valid() {
+    util::check_ok(
+        &[
+            Test {
+                field: "1",
+                number: 80,
+                unprivileged: 1024,
+            },
+            Test {
+                field: "65535",
+                number: 65535,
+                unprivileged: 8080,
+            },
+            Test {
+                field: "08080",
+                number: 443,
+                unprivileged: 65535,
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn port_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                field: "0",
+                number: 0,
+                unprivileged: 0,
+            },
+            Test {
+                field: "65536",
+                number: 65536,
+                unprivileged: 1023,
+            },
+            Test {
+                field: "",
+                number: -1,
+                unprivileged: 22,
+            },
+            Test {
+                field: "+80",
+                number: 1,
+                unprivileged: 1,
+            },
+            Test {
+                field: "99999999999999999999999",
+                number: 1,
+                unprivileged: 2000,
+            },
+            Test {
+                field: " 80",
+                number: 1,
+                unprivileged: 2000,
+            },
+        ],
+        &()
+    )
+}
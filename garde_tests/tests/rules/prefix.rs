@@ -14,3 +14,33 @@ fn prefix_valid() {
 fn prefix_invalid() {
     util::check_fail!(&[Test { field: "a" }, Test { field: "_test" }], &())
 }
+
+#[derive(Debug, garde::Validate)]
+struct Multiple<'a> {
+    #[garde(prefix("tel:", "sms:"))]
+    field: &'a str,
+}
+
+#[test]
+fn prefix_multiple_valid() {
+    util::check_ok(
+        &[
+            Multiple { field: "tel:+1234" },
+            Multiple { field: "sms:+1234" },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn prefix_multiple_invalid() {
+    util::check_fail!(
+        &[
+            Multiple {
+                field: "mailto:a@b.c"
+            },
+            Multiple { field: "+tel:" },
+        ],
+        &()
+    )
+}
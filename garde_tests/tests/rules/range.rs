@@ -1,3 +1,5 @@
+use rust_decimal::Decimal;
+
 use super::util;
 #[derive(Debug, garde::Validate)]
 struct Test {
@@ -14,3 +16,98 @@ fn range_valid() {
 fn range_invalid() {
     util::check_fail!(&[Test { field: 9 }, Test { field: 101 }], &())
 }
+
+#[derive(Debug, garde::Validate)]
+struct Exclusive {
+    #[garde(range(min = 0.0, exclusive_max = 1.0))]
+    half_open: f64,
+    #[garde(range(exclusive_min = -10, max = 10))]
+    int: i32,
+    #[garde(range(exclusive_min = 0.0))]
+    positive: f32,
+}
+
+#[test]
+fn range_exclusive_valid() {
+    util::check_ok(
+        &[
+            Exclusive {
+                half_open: 0.0,
+                int: -9,
+                positive: f32::MIN_POSITIVE,
+            },
+            Exclusive {
+                half_open: 0.999,
+                int: 10,
+                positive: f32::INFINITY,
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn range_exclusive_invalid() {
+    util::check_fail!(
+        &[
+            Exclusive {
+                half_open: -0.1,
+                int: -10,
+                positive: 0.0,
+            },
+            Exclusive {
+                half_open: 1.0,
+                int: 11,
+                positive: -1.0,
+            },
+            Exclusive {
+                half_open: f64::NAN,
+                int: 0,
+                positive: f32::NAN,
+            },
+        ],
+        &()
+    )
+}
+
+#[derive(Debug, garde::Validate)]
+struct Money {
+    #[garde(range(min = Decimal::ONE, max = Decimal::ONE_HUNDRED))]
+    amount: Decimal,
+    #[garde(range(exclusive_min = Decimal::ZERO))]
+    fee: Decimal,
+}
+
+#[test]
+fn range_decimal_valid() {
+    util::check_ok(
+        &[
+            Money {
+                amount: Decimal::ONE,
+                fee: Decimal::new(1, 2),
+            },
+            Money {
+                amount: Decimal::new(10000, 2),
+                fee: Decimal::ONE_THOUSAND,
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn range_decimal_invalid() {
+    util::check_fail!(
+        &[
+            Money {
+                amount: Decimal::new(99, 2),
+                fee: Decimal::ZERO,
+            },
+            Money {
+                amount: Decimal::new(10001, 2),
+                fee: Decimal::new(-1, 2),
+            },
+        ],
+        &()
+    )
+}
@@ -0,0 +1,67 @@
+use garde::Validate;
+
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Inner<'a> {
+    #[garde(length(min = 1), rename = "streetName")]
+    street_name: &'a str,
+}
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(email, rename = "emailAddress")]
+    email_address: &'a str,
+    #[garde(dive, rename("homeAddress"))]
+    home_address: Inner<'a>,
+    #[garde(length(min = 1))]
+    name: &'a str,
+}
+
+#[test]
+fn rename_valid() {
+    util::check_ok(
+        &[Test {
+            email_address: "user@example.com",
+            home_address: Inner { street_name: "a" },
+            name: "a",
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn rename_invalid() {
+    util::check_fail!(
+        &[Test {
+            email_address: "user",
+            home_address: Inner { street_name: "" },
+            name: "",
+        }],
+        &()
+    )
+}
+
+#[test]
+fn rename_first_error() {
+    let value = Test {
+        email_address: "user",
+        home_address: Inner { street_name: "" },
+        name: "",
+    };
+    // errors are ordered by the renamed field names
+    assert_eq!(
+        value.validate_first_error(&()).unwrap_err().0,
+        "value.emailAddress"
+    );
+
+    let value = Test {
+        email_address: "user@example.com",
+        home_address: Inner { street_name: "" },
+        name: "",
+    };
+    assert_eq!(
+        value.validate_first_error(&()).unwrap_err().0,
+        "value.homeAddress.streetName"
+    );
+}
@@ -0,0 +1,96 @@
+use std::collections::HashMap;
+
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(required)]
+    present: Option<&'a str>,
+    #[garde(required(non_empty))]
+    non_empty: Option<&'a str>,
+    #[garde(required(non_empty, message = "this field is mandatory"))]
+    message: Option<Vec<u8>>,
+}
+
+#[test]
+fn required_valid() {
+    util::check_ok(
+        &[
+            Test {
+                present: Some("a"),
+                non_empty: Some("a"),
+                message: Some(vec![0]),
+            },
+            Test {
+                present: Some(""),
+                non_empty: Some(" "),
+                message: Some(vec![0, 1]),
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn required_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                present: None,
+                non_empty: None,
+                message: None,
+            },
+            Test {
+                present: Some(""),
+                non_empty: Some(""),
+                message: Some(vec![]),
+            },
+        ],
+        &()
+    )
+}
+
+#[derive(Debug, garde::Validate)]
+struct Collections {
+    #[garde(required(non_empty))]
+    name: String,
+    #[garde(required(non_empty), inner(length(min = 1)))]
+    tags: Vec<String>,
+    #[garde(required(non_empty, message = "at least one label is required"))]
+    labels: HashMap<String, String>,
+}
+
+#[test]
+fn required_collections_valid() {
+    util::check_ok(
+        &[Collections {
+            name: "a".into(),
+            tags: vec!["b".into()],
+            labels: HashMap::from([("c".into(), "d".into())]),
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn required_collections_invalid() {
+    util::check_fail!(
+        &[Collections {
+            name: String::new(),
+            tags: vec![],
+            labels: HashMap::new(),
+        }],
+        &()
+    )
+}
+
+#[derive(Debug, garde::Validate)]
+struct Message<'a> {
+    #[garde(required(message = "name is missing"), message("name is invalid"))]
+    name: Option<&'a str>,
+}
+
+#[test]
+fn required_field_message_invalid() {
+    util::check_fail!(&[Message { name: None }], &())
+}
@@ -0,0 +1,50 @@
+use super::util;
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(semver)]
+    version: &'a str,
+    #[garde(semver(req))]
+    requirement: &'a str,
+}
+
+#[test]
+fn semver_valid() {
+    util::check_ok(
+        &[
+            Test {
+                version: "1.2.3",
+                requirement: ">=1.2, <2",
+            },
+            Test {
+                version: "1.0.0-alpha.1+build.5",
+                requirement: "^1.4",
+            },
+            Test {
+                version: "0.0.0",
+                requirement: "*",
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn semver_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                version: "1.2",
+                requirement: ">=1.2,, <2",
+            },
+            Test {
+                version: "v1.0.0",
+                requirement: "",
+            },
+            Test {
+                version: "01.2.3",
+                requirement: "1.2.3.4",
+            },
+        ],
+        &()
+    )
+}
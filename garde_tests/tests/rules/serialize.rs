@@ -0,0 +1,39 @@
+use garde::Validate;
+
+#[derive(Debug, garde::Validate)]
+struct Inner<'a> {
+    #[garde(length(min = 1))]
+    field: &'a str,
+}
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(ascii, length(min = 3))]
+    a: &'a str,
+    #[garde(dive, length(max = 1))]
+    b: Vec<Inner<'a>>,
+}
+
+#[test]
+fn serialize_errors() {
+    let value = Test {
+        a: "😂",
+        b: vec![Inner { field: "" }, Inner { field: "" }],
+    };
+    let errors = value.validate(&()).unwrap_err();
+    assert_eq!(
+        serde_json::to_value(&errors).unwrap(),
+        serde_json::json!({
+            "value.a": ["not ascii", "length is lower than 3"],
+            "value.b": ["length is greater than 1"],
+            "value.b[0].field": ["length is lower than 1"],
+            "value.b[1].field": ["length is lower than 1"],
+        })
+    );
+}
+
+#[test]
+fn serialize_error() {
+    let error = garde::Error::new("not ascii");
+    assert_eq!(serde_json::to_string(&error).unwrap(), r#""not ascii""#);
+}
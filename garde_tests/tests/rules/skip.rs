@@ -10,6 +10,10 @@ struct Struct {
 #[derive(Debug, garde::Validate)]
 struct Tuple(#[garde(skip)] u64);
 
+#[allow(dead_code)]
+#[derive(Debug, garde::Validate)]
+struct TupleMixed(#[garde(skip)] &'static str, #[garde(range(min = 10))] u64);
+
 #[allow(dead_code)]
 #[derive(Debug, garde::Validate)]
 enum Enum {
@@ -24,5 +28,11 @@ enum Enum {
 fn skip_valid() {
     util::check_ok(&[Struct { field: 50 }], &());
     util::check_ok(&[Tuple(50)], &());
+    util::check_ok(&[TupleMixed("", 50)], &());
     util::check_ok(&[Enum::Struct { field: 50 }, Enum::Tuple(50)], &());
 }
+
+#[test]
+fn skip_tuple_mixed_invalid() {
+    util::check_fail!(&[TupleMixed("", 5)], &());
+}
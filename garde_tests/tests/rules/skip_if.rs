@@ -0,0 +1,104 @@
+use garde::Validate;
+
+use super::util;
+
+struct Context {
+    update: bool,
+}
+
+fn is_update(ctx: &Context) -> bool {
+    ctx.update
+}
+
+fn not_empty(value: &str, _: &Context) -> garde::Result {
+    if value.is_empty() {
+        return Err(garde::Error::new("is empty"));
+    }
+    Ok(())
+}
+
+#[derive(Debug, garde::Validate)]
+#[garde(context(Context))]
+struct Inner<'a> {
+    #[garde(custom(not_empty))]
+    field: &'a str,
+}
+
+#[derive(Debug, garde::Validate)]
+#[garde(context(Context))]
+struct Test<'a> {
+    #[garde(skip_if(is_update), length(min = 8), custom(not_empty))]
+    password: &'a str,
+    #[garde(skip_if(|ctx: &Context| ctx.update), dive)]
+    inner: Inner<'a>,
+    #[garde(length(min = 1))]
+    name: &'a str,
+}
+
+#[derive(Debug, garde::Validate)]
+#[garde(context(Context))]
+struct Tuple<'a>(
+    #[garde(skip_if(is_update), length(min = 8))] &'a str,
+    #[garde(length(min = 1))] &'a str,
+);
+
+#[test]
+fn skip_if_valid() {
+    util::check_ok(
+        &[Test {
+            password: "",
+            inner: Inner { field: "" },
+            name: "a",
+        }],
+        &Context { update: true },
+    );
+    util::check_ok(
+        &[Test {
+            password: "password",
+            inner: Inner { field: "a" },
+            name: "a",
+        }],
+        &Context { update: false },
+    );
+    util::check_ok(&[Tuple("", "a")], &Context { update: true });
+}
+
+#[test]
+fn skip_if_invalid() {
+    util::check_fail!(
+        &[Test {
+            password: "",
+            inner: Inner { field: "" },
+            name: "",
+        }],
+        &Context { update: false }
+    )
+}
+
+#[test]
+fn skip_if_update_invalid() {
+    util::check_fail!(
+        &[Test {
+            password: "",
+            inner: Inner { field: "" },
+            name: "",
+        }],
+        &Context { update: true }
+    )
+}
+
+#[test]
+fn skip_if_first_error() {
+    let value = Test {
+        password: "",
+        inner: Inner { field: "" },
+        name: "",
+    };
+    assert_eq!(
+        value
+            .validate_first_error(&Context { update: true })
+            .unwrap_err()
+            .0,
+        "value.name"
+    );
+}
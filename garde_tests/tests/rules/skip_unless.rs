@@ -0,0 +1,71 @@
+use super::util;
+
+struct Context {
+    strict: bool,
+}
+
+fn is_strict(ctx: &Context) -> bool {
+    ctx.strict
+}
+
+#[derive(Debug, garde::Validate)]
+#[garde(context(Context))]
+struct Inner<'a> {
+    #[garde(length(min = 1))]
+    field: &'a str,
+}
+
+#[derive(Debug, garde::Validate)]
+#[garde(context(Context))]
+struct Test<'a> {
+    #[garde(skip, unless = is_strict, length(min = 8))]
+    description: &'a str,
+    #[garde(skip, unless = |ctx: &Context| ctx.strict, dive)]
+    inner: Inner<'a>,
+    #[garde(length(min = 1))]
+    name: &'a str,
+}
+
+#[test]
+fn skip_unless_valid() {
+    util::check_ok(
+        &[Test {
+            description: "",
+            inner: Inner { field: "" },
+            name: "a",
+        }],
+        &Context { strict: false },
+    );
+    util::check_ok(
+        &[Test {
+            description: "description",
+            inner: Inner { field: "a" },
+            name: "a",
+        }],
+        &Context { strict: true },
+    );
+}
+
+#[test]
+fn skip_unless_invalid() {
+    util::check_fail!(
+        &[Test {
+            description: "",
+            inner: Inner { field: "" },
+            name: "",
+        }],
+        &Context { strict: false }
+    )
+}
+
+#[test]
+fn skip_unless_strict_invalid() {
+    util::check_fail!(
+        &[Test {
+            description: "",
+            inner: Inner { field: "" },
+            name: "",
+        }],
+        &Context { strict: true }
+    )
+}
@@ -0,0 +1,67 @@
+use garde::Validate;
+
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(split(",", inner(length(max = 5))))]
+    tags: &'a str,
+    #[garde(
+        length(max = 16),
+        split(";", skip_empty, inner(ascii, length(min = 2)))
+    )]
+    skip_empty: String,
+    #[garde(split(",", deny_empty, inner(alphanumeric)))]
+    deny_empty: &'a str,
+}
+
+#[test]
+fn split_valid() {
+    util::check_ok(
+        &[
+            Test {
+                tags: "rust,web,async",
+                skip_empty: "ab;;cd;".into(),
+                deny_empty: "a,b",
+            },
+            Test {
+                tags: "",
+                skip_empty: "".into(),
+                deny_empty: "abc",
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn split_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                tags: "rust,webassembly,,asynchronous",
+                skip_empty: "a;;😂😂;abcdefghijklmn".into(),
+                deny_empty: "a,,b,",
+            },
+            Test {
+                tags: "rust",
+                skip_empty: "ab".into(),
+                deny_empty: "",
+            },
+        ],
+        &()
+    )
+}
+
+#[test]
+fn split_first_error() {
+    let value = Test {
+        tags: "rust,web",
+        skip_empty: "ab".into(),
+        deny_empty: "a,b,c-d",
+    };
+    assert_eq!(
+        value.validate_first_error(&()).unwrap_err().0,
+        "value.deny_empty[2]"
+    );
+}
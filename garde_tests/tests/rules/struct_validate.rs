@@ -0,0 +1,94 @@
+use garde::Validate;
+
+use super::util;
+
+struct Context {
+    max_total: u32,
+}
+
+fn check_range(value: &Range, _: &Context) -> garde::Result {
+    if value.start > value.end {
+        return Err(garde::Error::new("start is after end"));
+    }
+    Ok(())
+}
+
+fn check_total(value: &Range, ctx: &Context) -> garde::Result {
+    if value.end.saturating_sub(value.start) > ctx.max_total {
+        return Err(garde::Error::new("range is too long"));
+    }
+    Ok(())
+}
+
+#[derive(Debug, garde::Validate)]
+#[garde(context(Context), validate = check_range, validate = check_total)]
+struct Range {
+    #[garde(range(max = 100))]
+    start: u32,
+    #[garde(range(max = 100))]
+    end: u32,
+}
+
+#[derive(Debug, garde::Validate)]
+#[garde(context(Context))]
+struct Outer {
+    #[garde(dive)]
+    range: Range,
+}
+
+#[test]
+fn struct_validate_valid() {
+    util::check_ok(
+        &[Range { start: 1, end: 2 }, Range { start: 0, end: 10 }],
+        &Context { max_total: 10 },
+    )
+}
+
+#[test]
+fn struct_validate_invalid() {
+    util::check_fail!(
+        &[
+            Range { start: 5, end: 1 },
+            Range { start: 0, end: 200 },
+            Range { start: 200, end: 0 },
+        ],
+        &Context { max_total: 10 }
+    )
+}
+
+#[test]
+fn struct_validate_nested() {
+    let errors = Outer {
+        range: Range { start: 5, end: 101 },
+    }
+    .validate(&Context { max_total: 10 })
+    .unwrap_err();
+    assert_eq!(
+        errors.to_string(),
+        "value.range: range is too long\nvalue.range.end: greater than 100"
+    );
+}
+
+#[test]
+fn struct_validate_first_error() {
+    let ctx = Context { max_total: 10 };
+    let value = Range { start: 5, end: 101 };
+    // errors at the root are yielded first, so the first error matches `Errors::flatten`
+    assert_eq!(
+        value.validate_first_error(&ctx).unwrap_err(),
+        value
+            .validate(&ctx)
+            .unwrap_err()
+            .flatten()
+            .into_iter()
+            .next()
+            .unwrap()
+    );
+    assert_eq!(
+        Range { start: 0, end: 101 }
+            .validate_first_error(&Context { max_total: 200 })
+            .unwrap_err()
+            .0,
+        "value.end"
+    );
+}
@@ -14,3 +14,39 @@ fn suffix_valid() {
 fn suffix_invalid() {
     util::check_fail!(&[Test { field: "a" }, Test { field: "test_" }], &())
 }
+
+#[derive(Debug, garde::Validate)]
+struct Multiple<'a> {
+    #[garde(suffix(".com", ".org"))]
+    field: &'a str,
+}
+
+#[test]
+fn suffix_multiple_valid() {
+    util::check_ok(
+        &[
+            Multiple {
+                field: "example.com",
+            },
+            Multiple {
+                field: "example.org",
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn suffix_multiple_invalid() {
+    util::check_fail!(
+        &[
+            Multiple {
+                field: "example.net"
+            },
+            Multiple {
+                field: ".com.example"
+            },
+        ],
+        &()
+    )
+}
@@ -0,0 +1,65 @@
+use garde::Validate;
+
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+#[garde(transparent)]
+struct Username<'a>(#[garde(length(min = 3))] &'a str);
+
+#[derive(Debug, garde::Validate)]
+#[garde(transparent)]
+struct Email<'a> {
+    #[garde(ascii, contains("@"))]
+    value: &'a str,
+}
+
+#[derive(Debug, garde::Validate)]
+struct User<'a> {
+    #[garde(dive)]
+    username: Username<'a>,
+    #[garde(dive)]
+    emails: Vec<Email<'a>>,
+}
+
+#[test]
+fn transparent_valid() {
+    util::check_ok(&[Username("test")], &());
+    util::check_ok(&[Email { value: "a@b" }], &());
+    util::check_ok(
+        &[User {
+            username: Username("test"),
+            emails: vec![Email { value: "a@b" }],
+        }],
+        &(),
+    );
+}
+
+#[test]
+fn transparent_invalid() {
+    util::check_fail!(&[Username("a")], &());
+}
+
+#[test]
+fn transparent_nested_invalid() {
+    util::check_fail!(
+        &[User {
+            username: Username("a"),
+            emails: vec![Email { value: "a@b" }, Email { value: "😂" }],
+        }],
+        &()
+    );
+}
+
+#[test]
+fn transparent_first_error() {
+    let user = User {
+        username: Username("test"),
+        emails: vec![Email { value: "a@b" }, Email { value: "😂" }],
+    };
+    let (path, error) = user.validate_first_error(&()).unwrap_err();
+    assert_eq!(path, "value.emails[1]");
+    assert_eq!(error.message, "not ascii");
+
+    let (path, _) = Username("a").validate_first_error(&()).unwrap_err();
+    assert_eq!(path, "value");
+}
@@ -0,0 +1,46 @@
+use super::util;
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(trimmed)]
+    field: &'a str,
+    #[garde(trimmed, length(min = 1, max = 8))]
+    name: &'a str,
+}
+
+#[test]
+fn trimmed_valid() {
+    util::check_ok(
+        &[
+            Test {
+                field: "",
+                name: "John",
+            },
+            Test {
+                field: "John Smith",
+                name: "a b",
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn trimmed_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                field: " John ",
+                name: "   ",
+            },
+            Test {
+                field: "John\n",
+                name: "\u{3000}John",
+            },
+            Test {
+                field: "\t",
+                name: " John Smith ",
+            },
+        ],
+        &()
+    )
+}
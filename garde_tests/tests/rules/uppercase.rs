@@ -0,0 +1,31 @@
+use super::util;
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(uppercase)]
+    field: &'a str,
+}
+
+#[test]
+fn uppercase_valid() {
+    util::check_ok(
+        &[
+            Test { field: "" },
+            Test { field: "USD" },
+            Test { field: "ISO-4217" },
+            Test { field: "İSTANBUL" },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn uppercase_invalid() {
+    util::check_fail!(
+        &[
+            Test { field: "usd" },
+            Test { field: "STRAßE" },
+            Test { field: "ǅ" },
+        ],
+        &()
+    )
+}
@@ -88,6 +88,119 @@ fn url_enum_invalid() {
     )
 }
 
+#[derive(Debug, Validate)]
+struct Restricted<'a> {
+    #[garde(url(schemes("https", "wss")))]
+    field: &'a str,
+}
+
+#[test]
+fn url_restricted_valid() {
+    util::check_ok(
+        &[
+            Restricted {
+                field: "https://www.youtube.com/watch?v=dQw4w9WgXcQ",
+            },
+            Restricted {
+                field: "HTTPS://www.youtube.com/watch?v=dQw4w9WgXcQ",
+            },
+            Restricted {
+                field: "wss://example.com/socket",
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn url_restricted_invalid() {
+    util::check_fail!(
+        &[
+            Restricted {
+                field: "http://info.cern.ch/hypertext/WWW/TheProject.html"
+            },
+            Restricted {
+                field: "ftp://example.com/file.txt"
+            },
+            Restricted { field: "asdf" },
+        ],
+        &()
+    )
+}
+
+#[derive(Debug, Validate)]
+struct Hosts<'a> {
+    #[garde(url(
+        schemes("https"),
+        host("example.com", "API.example.com", "127.0.0.1", "[::1]")
+    ))]
+    field: &'a str,
+}
+
+#[test]
+fn url_hosts_valid() {
+    util::check_ok(
+        &[
+            Hosts {
+                field: "https://example.com/",
+            },
+            Hosts {
+                field: "https://EXAMPLE.com/path",
+            },
+            Hosts {
+                field: "https://api.example.com:8443/v1",
+            },
+            Hosts {
+                field: "https://127.0.0.1/",
+            },
+            Hosts {
+                field: "https://[::1]:8080/",
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn url_hosts_invalid() {
+    util::check_fail!(
+        &[
+            Hosts {
+                field: "https://evil.com/"
+            },
+            Hosts {
+                field: "https://www.example.com/"
+            },
+            Hosts {
+                field: "https://example.com.evil.com/"
+            },
+            Hosts {
+                field: "https://127.0.0.2/"
+            },
+            Hosts {
+                field: "http://example.com/"
+            },
+        ],
+        &()
+    )
+}
+
+#[derive(Debug, Validate)]
+struct AnyScheme<'a> {
+    #[garde(url(host("example.com")))]
+    field: &'a str,
+}
+
+#[test]
+fn url_hosts_without_host_invalid() {
+    util::check_fail!(
+        &[AnyScheme {
+            field: "mailto:user@example.com"
+        }],
+        &()
+    )
+}
+
 #[test]
 fn url_valid_wrapper() {
     let value = Struct {
@@ -0,0 +1,86 @@
+use super::util;
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(uuid)]
+    field: &'a str,
+}
+
+#[test]
+fn uuid_valid() {
+    util::check_ok(
+        &[
+            Test {
+                field: "67e55044-10b1-426f-9247-bb680e5fe0c8",
+            },
+            Test {
+                field: "67E55044-10B1-426F-9247-BB680E5FE0C8",
+            },
+            Test {
+                field: "67e5504410b1426f9247bb680e5fe0c8",
+            },
+            Test {
+                field: "{67e55044-10b1-426f-9247-bb680e5fe0c8}",
+            },
+            Test {
+                field: "urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8",
+            },
+            Test {
+                field: "00000000-0000-0000-0000-000000000000",
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn uuid_invalid() {
+    util::check_fail!(
+        &[
+            Test { field: "" },
+            Test {
+                field: "67e55044-10b1-426f-9247-bb680e5fe0c"
+            },
+            Test {
+                field: "67e55044-10b1-426f-9247-bb680e5fe0cg"
+            },
+            Test {
+                field: "67e55044_10b1_426f_9247_bb680e5fe0c8"
+            },
+        ],
+        &()
+    )
+}
+
+#[derive(Debug, garde::Validate)]
+struct Version<'a> {
+    #[garde(uuid(version = 4))]
+    field: &'a str,
+}
+
+#[test]
+fn uuid_version_valid() {
+    util::check_ok(
+        &[Version {
+            field: "67e55044-10b1-426f-9247-bb680e5fe0c8",
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn uuid_version_invalid() {
+    util::check_fail!(
+        &[
+            Version {
+                field: "c232ab00-9414-11ec-b3c8-9f6bdeced846"
+            },
+            Version {
+                field: "00000000-0000-0000-0000-000000000000"
+            },
+            Version {
+                field: "not a uuid"
+            },
+        ],
+        &()
+    )
+}
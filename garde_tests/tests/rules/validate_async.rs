@@ -0,0 +1,303 @@
+use std::future::Future;
+use std::sync::Arc;
+use std::task::{Context, Poll, Wake, Waker};
+
+use garde::AsyncValidate;
+
+fn block_on<F: Future>(future: F) -> F::Output {
+    struct ThreadWaker(std::thread::Thread);
+
+    impl Wake for ThreadWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    let mut future = Box::pin(future);
+    let waker = Waker::from(Arc::new(ThreadWaker(std::thread::current())));
+    let mut cx = Context::from_waker(&waker);
+    loop {
+        match future.as_mut().poll(&mut cx) {
+            Poll::Ready(output) => return output,
+            Poll::Pending => std::thread::park(),
+        }
+    }
+}
+
+struct Database {
+    taken: Vec<&'static str>,
+}
+
+async fn is_unique(value: &str, db: &Database) -> garde::Result {
+    if db.taken.contains(&value) {
+        return Err(garde::Error::new("is already taken"));
+    }
+    Ok(())
+}
+
+#[derive(Debug, garde::AsyncValidate)]
+#[garde(context(Database))]
+struct Profile<'a> {
+    #[garde(length(min = 1), custom_async(is_unique))]
+    handle: &'a str,
+}
+
+#[derive(Debug, garde::AsyncValidate)]
+#[garde(context(Database))]
+struct User<'a> {
+    #[garde(ascii, length(min = 3), custom_async(is_unique))]
+    username: &'a str,
+    #[garde(dive)]
+    profile: Profile<'a>,
+    #[garde(length(max = 1), dive)]
+    aliases: Vec<Profile<'a>>,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, garde::AsyncValidate)]
+#[garde(context(Database))]
+struct Tuple<'a>(
+    #[garde(custom_async(is_unique))] &'a str,
+    #[garde(skip)] &'a str,
+);
+
+#[derive(Debug, garde::AsyncValidate)]
+#[garde(context(Database))]
+enum Enum<'a> {
+    Struct {
+        #[garde(custom_async(is_unique))]
+        field: &'a str,
+    },
+    Tuple(#[garde(dive)] Profile<'a>),
+}
+
+fn db() -> Database {
+    Database {
+        taken: vec!["admin", "root"],
+    }
+}
+
+#[test]
+fn validate_async_valid() {
+    let value = User {
+        username: "test",
+        profile: Profile { handle: "test" },
+        aliases: vec![Profile { handle: "alias" }],
+    };
+    assert!(block_on(value.validate_async(&db())).is_ok());
+    assert!(block_on(Tuple("test", "admin").validate_async(&db())).is_ok());
+    assert!(block_on(Enum::Struct { field: "test" }.validate_async(&db())).is_ok());
+}
+
+#[test]
+fn validate_async_invalid() {
+    let value = User {
+        username: "admin",
+        profile: Profile { handle: "" },
+        aliases: vec![Profile { handle: "root" }, Profile { handle: "a" }],
+    };
+    let errors = block_on(value.validate_async(&db())).unwrap_err();
+    insta::assert_snapshot!(errors.to_string());
+
+    let errors = block_on(Tuple("root", "").validate_async(&db())).unwrap_err();
+    assert_eq!(errors.to_string(), "value[0]: is already taken");
+
+    let errors =
+        block_on(Enum::Tuple(Profile { handle: "admin" }).validate_async(&db())).unwrap_err();
+    assert_eq!(errors.to_string(), "value[0].handle: is already taken");
+}
+
+#[derive(Debug, garde::AsyncValidate)]
+#[garde(context(Database), transparent)]
+struct Handle<'a>(#[garde(custom_async(is_unique))] &'a str);
+
+#[test]
+fn validate_async_transparent() {
+    assert!(block_on(Handle("test").validate_async(&db())).is_ok());
+
+    let errors = block_on(Handle("root").validate_async(&db())).unwrap_err();
+    assert_eq!(errors.to_string(), "value: is already taken");
+}
+
+#[test]
+fn validate_async_tuple() {
+    let value = (Profile { handle: "test" }, Handle("user"));
+    assert!(block_on(value.validate_async(&db())).is_ok());
+
+    let value = (Profile { handle: "test" }, Handle("root"));
+    let errors = block_on(value.validate_async(&db())).unwrap_err();
+    assert_eq!(errors.to_string(), "value[1]: is already taken");
+
+    let value = (Profile { handle: "" }, Handle("admin"));
+    let errors = block_on(value.validate_async(&db())).unwrap_err();
+    assert_eq!(
+        errors.to_string(),
+        "value[0].handle: length is lower than 1\nvalue[1]: is already taken"
+    );
+}
+
+fn handles_differ(value: &Rename<'_>, _: &Database) -> garde::Result {
+    if value.from == value.to {
+        return Err(garde::Error::new("handles are the same"));
+    }
+    Ok(())
+}
+
+#[derive(Debug, garde::AsyncValidate)]
+#[garde(context(Database), validate = handles_differ)]
+struct Rename<'a> {
+    #[garde(length(min = 1))]
+    from: &'a str,
+    #[garde(custom_async(is_unique))]
+    to: &'a str,
+}
+
+#[test]
+fn validate_async_struct_validate() {
+    let value = Rename { from: "a", to: "b" };
+    assert!(block_on(value.validate_async(&db())).is_ok());
+
+    let value = Rename {
+        from: "root",
+        to: "root",
+    };
+    let errors = block_on(value.validate_async(&db())).unwrap_err();
+    assert_eq!(
+        errors.to_string(),
+        "value: handles are the same\nvalue.to: is already taken"
+    );
+}
+
+/// Returns `Pending` once before completing, so that the validation is suspended in between.
+struct YieldOnce(bool);
+
+impl Future for YieldOnce {
+    type Output = ();
+
+    fn poll(mut self: std::pin::Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if self.0 {
+            return Poll::Ready(());
+        }
+        self.0 = true;
+        cx.waker().wake_by_ref();
+        Poll::Pending
+    }
+}
+
+async fn yield_once(_: &str, _: &()) -> garde::Result {
+    YieldOnce(false).await;
+    Ok(())
+}
+
+#[derive(Debug, garde::AsyncValidate)]
+struct Node {
+    #[garde(custom_async(yield_once))]
+    name: String,
+    #[garde(dive)]
+    children: Vec<Node>,
+}
+
+impl Node {
+    /// Builds a chain of `depth` nodes below a root node.
+    fn chain(depth: usize) -> Self {
+        let mut node = Node {
+            name: String::from("leaf"),
+            children: vec![],
+        };
+        for _ in 0..depth {
+            node = Node {
+                name: String::from("node"),
+                children: vec![node],
+            };
+        }
+        node
+    }
+}
+
+#[test]
+fn validate_async_depth_limit() {
+    use garde::validate_async::with_max_depth;
+
+    // the leaf also dives into its (empty) children
+    let node = Node::chain(8);
+    assert!(block_on(with_max_depth(9, node.validate_async(&()))).is_ok());
+
+    let errors = block_on(with_max_depth(2, node.validate_async(&()))).unwrap_err();
+    assert_eq!(
+        errors.to_string(),
+        "value.children[0].children[0].children: maximum validation depth exceeded"
+    );
+
+    // the limit is only applied to the future it was set for
+    assert!(block_on(node.validate_async(&())).is_ok());
+}
+
+#[test]
+fn validate_async_depth_limit_other_thread() {
+    let node = Node::chain(8);
+    let future = garde::validate_async::with_max_depth(2, node.validate_async(&()));
+    let errors = std::thread::scope(|s| s.spawn(|| block_on(future)).join().unwrap()).unwrap_err();
+    assert_eq!(
+        errors.to_string(),
+        "value.children[0].children[0].children: maximum validation depth exceeded"
+    );
+}
+
+#[derive(Debug, garde::Validate, garde::AsyncValidate)]
+#[garde(context(Database))]
+struct Member<'a> {
+    #[garde(length(min = 1))]
+    handle: &'a str,
+}
+
+#[derive(Debug, garde::Validate, garde::AsyncValidate)]
+#[garde(context(Database))]
+struct Team<'a> {
+    #[garde(dive)]
+    members: std::collections::BTreeMap<&'a str, Member<'a>>,
+    #[garde(dive)]
+    groups: std::collections::HashMap<Group, Member<'a>>,
+}
+
+// distinct keys which have the same `Display` form
+#[derive(Debug, PartialEq, Eq, Hash)]
+struct Group(u8);
+
+impl std::fmt::Display for Group {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "group")
+    }
+}
+
+#[test]
+fn validate_async_map_paths_match_sync() {
+    use garde::Validate;
+
+    let team = Team {
+        members: [("alice", Member { handle: "" })].into_iter().collect(),
+        groups: [
+            (Group(0), Member { handle: "" }),
+            (Group(1), Member { handle: "" }),
+        ]
+        .into_iter()
+        .collect(),
+    };
+
+    let errors = block_on(team.validate_async(&db())).unwrap_err();
+    assert_eq!(
+        errors.flatten(),
+        team.validate(&db()).unwrap_err().flatten()
+    );
+    assert_eq!(
+        errors
+            .flatten()
+            .into_iter()
+            .map(|(path, _)| path)
+            .collect::<Vec<_>>(),
+        [
+            "value.groups[group].handle",
+            "value.groups[group].handle",
+            "value.members[alice].handle",
+        ]
+    );
+}
@@ -0,0 +1,70 @@
+use garde::{Valid, ValidateDefault};
+
+struct Limits {
+    max: usize,
+}
+
+fn default_limits() -> Limits {
+    Limits { max: 4 }
+}
+
+fn max_length(value: &str, limits: &Limits) -> garde::Result {
+    if value.len() > limits.max {
+        return Err(garde::Error::new(format!("longer than {}", limits.max)));
+    }
+    Ok(())
+}
+
+#[derive(Debug, serde::Deserialize, garde::Validate)]
+#[garde(context(Limits, default = default_limits))]
+struct Tag {
+    #[garde(custom(max_length))]
+    name: String,
+}
+
+#[derive(Debug, garde::Validate)]
+struct Plain<'a> {
+    #[garde(length(min = 1))]
+    name: &'a str,
+}
+
+#[test]
+fn validate_default_with_factory() {
+    assert!(Tag {
+        name: "rust".into()
+    }
+    .validate_default()
+    .is_ok());
+
+    let errors = Tag {
+        name: "garde".into(),
+    }
+    .validate_default()
+    .unwrap_err();
+    assert_eq!(errors.to_string(), "value.name: longer than 4");
+}
+
+#[test]
+fn validate_default_with_default_context() {
+    assert!(Plain { name: "a" }.validate_default().is_ok());
+    assert!(Plain { name: "" }.validate_default().is_err());
+}
+
+#[test]
+fn deserialize_with_factory() {
+    let tag: Valid<Tag> = serde_json::from_str(r#"{ "name": "rust" }"#).unwrap();
+    assert_eq!(tag.name, "rust");
+
+    let error = serde_json::from_str::<Valid<Tag>>(r#"{ "name": "garde" }"#).unwrap_err();
+    assert_eq!(error.to_string(), "value.name: longer than 4");
+}
+
+#[test]
+fn validate_default_containers() {
+    assert!(vec![Plain { name: "a" }].validate_default().is_ok());
+    assert!(Some(Tag {
+        name: "garde".into()
+    })
+    .validate_default()
+    .is_err());
+}
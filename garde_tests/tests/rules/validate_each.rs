@@ -0,0 +1,59 @@
+use garde::validate::LengthMismatch;
+use garde::validate_each;
+
+#[derive(Debug, garde::Validate)]
+#[garde(context(Tenant))]
+struct Item<'a> {
+    #[garde(length(min = 1), custom(is_allowed))]
+    name: &'a str,
+}
+
+struct Tenant {
+    allowed: &'static [&'static str],
+}
+
+fn is_allowed(value: &str, ctx: &Tenant) -> garde::Result {
+    if !ctx.allowed.contains(&value) {
+        return Err(garde::Error::new("not allowed for this tenant"));
+    }
+    Ok(())
+}
+
+#[test]
+fn validate_each_uses_context_of_same_index() {
+    let items = [Item { name: "a" }, Item { name: "a" }, Item { name: "" }];
+    let ctxs = [
+        Tenant { allowed: &["a"] },
+        Tenant { allowed: &["b"] },
+        Tenant { allowed: &[""] },
+    ];
+
+    let results = validate_each(&items, &ctxs)
+        .unwrap()
+        .into_iter()
+        .map(|result| result.map_err(|errors| errors.to_string()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        results,
+        [
+            Ok(()),
+            Err(String::from("value.name: not allowed for this tenant")),
+            Err(String::from("value.name: length is lower than 1")),
+        ]
+    );
+}
+
+#[test]
+fn validate_each_empty() {
+    assert!(validate_each::<Item>(&[], &[]).unwrap().is_empty());
+}
+
+#[test]
+fn validate_each_length_mismatch() {
+    let error = validate_each(&[Item { name: "a" }], &[]).unwrap_err();
+    assert_eq!(error, LengthMismatch { items: 1, ctxs: 0 });
+    assert_eq!(
+        error.to_string(),
+        "expected one context per item, got 1 items and 0 contexts"
+    );
+}
@@ -0,0 +1,158 @@
+use std::collections::BTreeMap;
+
+use garde::{Validate, ValidateMut};
+
+#[derive(Debug, Default)]
+struct Seen {
+    names: Vec<String>,
+}
+
+fn record(value: &str, seen: &mut Seen) -> garde::Result {
+    seen.names.push(value.to_string());
+    Ok(())
+}
+
+fn record_optional(value: &Option<String>, seen: &mut Seen) -> garde::Result {
+    if let Some(value) = value {
+        seen.names.push(value.clone());
+    }
+    Ok(())
+}
+
+fn is_known(value: &str, seen: &Seen) -> garde::Result {
+    if !seen.names.iter().any(|name| name == value) {
+        return Err(garde::Error::new("is not known"));
+    }
+    Ok(())
+}
+
+fn count_tags(value: &[String], seen: &mut Seen) -> Result<(), garde::Errors> {
+    seen.names.push(format!("{} tags", value.len()));
+    Ok(())
+}
+
+fn check_root(value: &Profile, seen: &mut Seen) -> garde::Result {
+    seen.names.push(format!("root {}", value.name));
+    Ok(())
+}
+
+#[derive(Debug, garde::ValidateMut)]
+#[garde(context(Seen), validate = check_root)]
+struct Profile {
+    #[garde(length(min = 1), custom(record))]
+    name: String,
+    #[garde(custom(record_optional))]
+    nickname: Option<String>,
+    #[garde(custom_errors(count_tags), inner(custom(record)))]
+    tags: Vec<String>,
+    #[garde(keys(custom(record)), dive)]
+    friends: BTreeMap<String, Friend>,
+}
+
+#[derive(Debug, garde::Validate, garde::ValidateMut)]
+#[garde(context(Seen))]
+struct Friend {
+    #[garde(custom(is_known))]
+    name: String,
+}
+
+fn profile() -> Profile {
+    Profile {
+        name: String::from("alice"),
+        nickname: Some(String::from("al")),
+        tags: vec![String::from("admin")],
+        friends: BTreeMap::from([(
+            String::from("bob"),
+            Friend {
+                name: String::from("bob"),
+            },
+        )]),
+    }
+}
+
+#[test]
+fn validate_mut_accumulates_through_dive() {
+    let mut seen = Seen::default();
+    profile().validate_mut(&mut seen).unwrap();
+    // the key is recorded before the value is validated
+    assert_eq!(
+        seen.names,
+        ["bob", "alice", "al", "admin", "1 tags", "root alice"]
+    );
+}
+
+#[derive(Debug, garde::Validate, garde::ValidateMut)]
+#[garde(context(Seen))]
+struct Account {
+    #[garde(length(min = 1), custom(is_known))]
+    name: String,
+    #[garde(inner(length(max = 3)))]
+    tags: Vec<String>,
+    #[garde(keys(length(min = 2)), dive)]
+    friends: BTreeMap<String, Friend>,
+}
+
+#[test]
+fn validate_mut_same_errors_as_validate() {
+    let value = Account {
+        name: String::new(),
+        tags: vec![String::from("ok"), String::from("admin")],
+        friends: BTreeMap::from([
+            (
+                String::from("b"),
+                Friend {
+                    name: String::from("bob"),
+                },
+            ),
+            (
+                String::from("carol"),
+                Friend {
+                    name: String::from("dave"),
+                },
+            ),
+        ]),
+    };
+
+    let mut seen = Seen {
+        names: vec![String::from("bob")],
+    };
+    let errors = value.validate_mut(&mut seen).unwrap_err();
+    let expected = value.validate(&seen).unwrap_err();
+    assert_eq!(errors.flatten(), expected.flatten());
+    assert_eq!(
+        errors.to_string(),
+        [
+            "value.friends[b]: length is lower than 2",
+            "value.friends[carol].name: is not known",
+            "value.name: is not known",
+            "value.name: length is lower than 1",
+            "value.tags[1]: length is greater than 3",
+        ]
+        .join("\n")
+    );
+}
+
+#[derive(Debug, garde::ValidateMut)]
+#[garde(context(Seen))]
+enum Node {
+    Leaf(#[garde(custom(record))] String),
+    Branch {
+        #[garde(dive)]
+        children: Vec<Node>,
+    },
+}
+
+#[test]
+fn validate_mut_enum() {
+    let node = Node::Branch {
+        children: vec![
+            Node::Leaf(String::from("a")),
+            Node::Branch {
+                children: vec![Node::Leaf(String::from("b"))],
+            },
+        ],
+    };
+    let mut seen = Seen::default();
+    node.validate_mut(&mut seen).unwrap();
+    assert_eq!(seen.names, ["a", "b"]);
+}
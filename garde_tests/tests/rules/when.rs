@@ -0,0 +1,111 @@
+use garde::Validate;
+
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Address<'a> {
+    #[garde(length(min = 1))]
+    street: &'a str,
+}
+
+#[derive(Debug)]
+struct Options {
+    gift: bool,
+}
+
+#[derive(Debug, garde::Validate)]
+struct Order<'a> {
+    #[garde(skip)]
+    needs_shipping: bool,
+    #[garde(dive, when = needs_shipping)]
+    shipping_address: Address<'a>,
+    #[garde(skip)]
+    options: Options,
+    #[garde(length(min = 1), when = options.gift)]
+    gift_message: &'a str,
+    #[garde(length(min = 1))]
+    name: &'a str,
+}
+
+#[test]
+fn when_valid() {
+    util::check_ok(
+        &[
+            Order {
+                needs_shipping: true,
+                shipping_address: Address { street: "Main St." },
+                options: Options { gift: true },
+                gift_message: "Enjoy!",
+                name: "order",
+            },
+            Order {
+                needs_shipping: false,
+                shipping_address: Address { street: "" },
+                options: Options { gift: false },
+                gift_message: "",
+                name: "order",
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn when_invalid() {
+    util::check_fail!(
+        &[
+            Order {
+                needs_shipping: true,
+                shipping_address: Address { street: "" },
+                options: Options { gift: true },
+                gift_message: "",
+                name: "",
+            },
+            Order {
+                needs_shipping: false,
+                shipping_address: Address { street: "" },
+                options: Options { gift: false },
+                gift_message: "",
+                name: "",
+            },
+        ],
+        &()
+    )
+}
+
+#[test]
+fn when_first_error() {
+    let order = Order {
+        needs_shipping: false,
+        shipping_address: Address { street: "" },
+        options: Options { gift: true },
+        gift_message: "",
+        name: "",
+    };
+    let (path, error) = order.validate_first_error(&()).unwrap_err();
+    assert_eq!(path, "value.gift_message");
+    assert_eq!(error.message, "length is lower than 1");
+}
+
+fn is_draft(ctx: &bool) -> bool {
+    *ctx
+}
+
+#[derive(Debug, garde::Validate)]
+#[garde(context(bool))]
+struct Combined<'a> {
+    #[garde(skip)]
+    needs_shipping: bool,
+    #[garde(length(min = 1), when = needs_shipping, skip_if(is_draft))]
+    shipping_address: &'a str,
+}
+
+#[test]
+fn when_with_skip_if() {
+    let order = Combined {
+        needs_shipping: true,
+        shipping_address: "",
+    };
+    assert!(order.validate(&true).is_ok());
+    assert!(order.validate(&false).is_err());
+}
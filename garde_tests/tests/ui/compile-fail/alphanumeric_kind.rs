@@ -0,0 +1,7 @@
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(alphanumeric(latin))]
+    field: &'a str,
+}
+
+fn main() {}
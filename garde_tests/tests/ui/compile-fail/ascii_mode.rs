@@ -0,0 +1,7 @@
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(ascii(utf8))]
+    field: &'a str,
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+#[derive(garde::Validate)]
+struct Test {
+    #[garde(greater_than(start))]
+    end: u64,
+    #[garde(less_than(middle))]
+    middle: u64,
+}
+
+#[derive(garde::Validate)]
+struct Tuple(#[garde(skip)] u64, #[garde(greater_than(start))] u64);
+
+fn main() {}
@@ -0,0 +1,18 @@
+use std::time::Instant;
+
+use garde::ValidateDefault;
+
+fn not_expired(_: &u64, _: &Instant) -> garde::Result {
+    Ok(())
+}
+
+#[derive(garde::Validate)]
+#[garde(context(Instant))]
+struct Session {
+    #[garde(custom(not_expired))]
+    expires_at: u64,
+}
+
+fn main() {
+    let _ = Session { expires_at: 0 }.validate_default();
+}
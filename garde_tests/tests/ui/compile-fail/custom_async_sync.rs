@@ -0,0 +1,11 @@
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(custom_async(check))]
+    field: &'a str,
+}
+
+async fn check(_: &str, _: &()) -> garde::Result {
+    Ok(())
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+#[derive(garde::Validate)]
+struct Test {
+    #[garde(inner(custom_errors(check)))]
+    field: Vec<String>,
+}
+
+fn check(_: &String, _: &()) -> Result<(), garde::Errors> {
+    Ok(())
+}
+
+fn main() {}
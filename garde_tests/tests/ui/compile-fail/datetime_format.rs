@@ -0,0 +1,9 @@
+#[derive(garde::Validate)]
+struct Test {
+    #[garde(date(format = ""))]
+    date: String,
+    #[garde(datetime(format = ""))]
+    datetime: String,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+#[derive(garde::Validate)]
+struct Test {
+    #[garde(dive(parallel))]
+    field: Vec<Inner>,
+}
+
+#[derive(garde::Validate)]
+struct Inner {
+    #[garde(skip)]
+    field: u32,
+}
+
+fn main() {}
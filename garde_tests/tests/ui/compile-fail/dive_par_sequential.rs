@@ -0,0 +1,19 @@
+#[derive(garde::AsyncValidate)]
+struct Async {
+    #[garde(dive(par))]
+    items: Vec<Item>,
+}
+
+#[derive(garde::ValidateMut)]
+struct Mut {
+    #[garde(dive(par))]
+    items: Vec<Item>,
+}
+
+#[derive(garde::Validate, garde::AsyncValidate, garde::ValidateMut)]
+struct Item {
+    #[garde(length(min = 1))]
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+#[derive(garde::Validate)]
+struct Child {
+    #[garde(skip)]
+    field: u32,
+}
+
+#[derive(garde::Validate)]
+struct Test {
+    #[garde(inner())]
+    empty: Option<String>,
+    #[garde(inner(dive))]
+    nested_dive: Vec<Child>,
+    #[garde(dive, inner(length(min = 1)))]
+    with_dive: Option<Child>,
+}
+
+fn main() {}
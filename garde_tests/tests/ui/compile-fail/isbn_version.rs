@@ -0,0 +1,7 @@
+#[derive(garde::Validate)]
+struct Test {
+    #[garde(isbn(version = 12))]
+    field: String,
+}
+
+fn main() {}
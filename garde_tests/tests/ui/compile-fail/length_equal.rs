@@ -0,0 +1,11 @@
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(length(equal = 6, max = 6))]
+    a: &'a str,
+    #[garde(byte_length(min = 1, equal = 6))]
+    b: &'a str,
+    #[garde(range(equal = 6))]
+    c: u64,
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(length(bytes, min = 1), byte_length(max = 10))]
+    duplicate: &'a str,
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(length(min = 1), message(make_message))]
+    field: &'a str,
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[derive(garde::Validate)]
+struct Test {
+    #[garde(multiple_of(0))]
+    field: u64,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+#[derive(garde::Validate)]
+struct Test {
+    #[garde(not_contains(insensitive))]
+    empty: String,
+    #[garde(not_contains("admin", "root", "admin"))]
+    duplicate: String,
+}
+
+fn main() {}
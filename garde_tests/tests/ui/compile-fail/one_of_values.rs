@@ -0,0 +1,13 @@
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(one_of())]
+    empty: &'a str,
+    #[garde(one_of("a", 1))]
+    mixed: &'a str,
+    #[garde(one_of("a", "a"))]
+    duplicate: &'a str,
+    #[garde(one_of(1, 2, insensitive))]
+    insensitive: u8,
+}
+
+fn main() {}
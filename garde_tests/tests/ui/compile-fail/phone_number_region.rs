@@ -0,0 +1,7 @@
+#[derive(garde::Validate)]
+struct Test {
+    #[garde(phone_number(region = "France"))]
+    field: String,
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[derive(garde::Validate)]
+struct Test {
+    #[garde(phone_number(region = "XX"))]
+    field: String,
+}
+
+fn main() {}
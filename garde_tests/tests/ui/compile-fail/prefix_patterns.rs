@@ -0,0 +1,9 @@
+#[derive(garde::Validate)]
+struct Test {
+    #[garde(prefix())]
+    empty: String,
+    #[garde(suffix(".com", ".org", ".com"))]
+    duplicate: String,
+}
+
+fn main() {}
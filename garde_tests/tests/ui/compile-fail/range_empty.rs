@@ -0,0 +1,11 @@
+#[derive(garde::Validate)]
+struct Test {
+    #[garde(range(min = 1, exclusive_max = 1))]
+    a: u64,
+    #[garde(range(min = 10, max = -10))]
+    b: i64,
+    #[garde(range(min = 0, exclusive_min = 0))]
+    c: u64,
+}
+
+fn main() {}
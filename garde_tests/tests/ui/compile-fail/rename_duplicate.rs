@@ -0,0 +1,12 @@
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(ascii, rename = "b")]
+    a: &'a str,
+    #[garde(ascii)]
+    b: &'a str,
+}
+
+#[derive(garde::Validate)]
+struct Tuple<'a>(#[garde(ascii, rename = "a")] &'a str);
+
+fn main() {}
@@ -0,0 +1,9 @@
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(unless = |_: &()| true, length(min = 1))]
+    missing_skip: &'a str,
+    #[garde(skip, unless = |_: &()| true)]
+    no_rules: &'a str,
+}
+
+fn main() {}
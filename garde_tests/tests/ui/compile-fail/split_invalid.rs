@@ -0,0 +1,11 @@
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(split(","))]
+    no_inner: &'a str,
+    #[garde(split("", inner(ascii)))]
+    empty_delimiter: &'a str,
+    #[garde(split(",", inner(dive)))]
+    nested_dive: &'a str,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+#[derive(garde::Validate)]
+#[garde(transparent)]
+struct Tuple(#[garde(ascii)] String, #[garde(ascii)] String);
+
+#[derive(garde::Validate)]
+#[garde(transparent)]
+enum Enum {
+    A(#[garde(ascii)] String),
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(url(host("example.com", "EXAMPLE.com")))]
+    duplicate: &'a str,
+    #[garde(url(host("")))]
+    empty: &'a str,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(url(host()))]
+    host: &'a str,
+    #[garde(url(schemes()))]
+    schemes: &'a str,
+}
+
+fn main() {}
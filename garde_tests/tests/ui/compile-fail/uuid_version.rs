@@ -0,0 +1,7 @@
+#[derive(garde::Validate)]
+struct Test {
+    #[garde(uuid(version = 9))]
+    field: String,
+}
+
+fn main() {}
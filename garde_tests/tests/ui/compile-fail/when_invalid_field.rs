@@ -0,0 +1,12 @@
+#[derive(garde::Validate)]
+struct Test {
+    #[garde(ascii, when = unknown)]
+    a: String,
+    #[garde(ascii, when = b)]
+    b: String,
+}
+
+#[derive(garde::Validate)]
+struct Tuple(#[garde(skip)] bool, #[garde(ascii, when = enabled)] String);
+
+fn main() {}
@@ -4,4 +4,12 @@ struct Test<'a> {
     field: &'a str,
 }
 
+#[derive(garde::Validate)]
+struct Modes<'a> {
+    #[garde(alphanumeric(unicode))]
+    unicode: &'a str,
+    #[garde(alphanumeric(ascii))]
+    ascii: &'a str,
+}
+
 fn main() {}
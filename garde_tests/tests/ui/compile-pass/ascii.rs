@@ -2,6 +2,8 @@
 struct Test<'a> {
     #[garde(ascii)]
     field: &'a str,
+    #[garde(ascii(bytes))]
+    bytes: Vec<u8>,
 }
 
 fn main() {}
@@ -0,0 +1,11 @@
+#[derive(garde::Validate)]
+struct Test {
+    #[garde(base64)]
+    field: String,
+    #[garde(base64(standard))]
+    standard: String,
+    #[garde(base64(url_safe))]
+    url_safe: String,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+#[derive(garde::Validate)]
+struct Test {
+    #[garde(skip)]
+    start: u64,
+    #[garde(greater_than(start))]
+    end: u64,
+    #[garde(less_than(end))]
+    middle: u64,
+}
+
+fn main() {}
@@ -2,6 +2,8 @@
 struct Test<'a> {
     #[garde(contains("test"))]
     field: &'a str,
+    #[garde(contains("test", insensitive))]
+    insensitive: &'a str,
 }
 
 fn main() {}
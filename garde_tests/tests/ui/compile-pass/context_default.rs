@@ -0,0 +1,25 @@
+struct Context {
+    enabled: bool,
+}
+
+fn make_context() -> Context {
+    Context { enabled: true }
+}
+
+fn check(_: &str, ctx: &Context) -> garde::Result {
+    if ctx.enabled {
+        return Ok(());
+    }
+    Err(garde::Error::new("disabled"))
+}
+
+#[derive(garde::Validate)]
+#[garde(context(Context, default = make_context))]
+struct Test<'a, T: garde::Validate<Context = Context>> {
+    #[garde(custom(check))]
+    field: &'a str,
+    #[garde(dive)]
+    inner: T,
+}
+
+fn main() {}
@@ -0,0 +1,57 @@
+use std::sync::Arc;
+use std::time::Instant;
+
+use garde::ValidateDefault;
+
+fn now() -> Instant {
+    Instant::now()
+}
+
+fn not_expired(_: &u64, _: &Instant) -> garde::Result {
+    Ok(())
+}
+
+#[derive(garde::Validate)]
+#[garde(context(Instant, default = now))]
+struct Token {
+    #[garde(custom(not_expired))]
+    expires_at: u64,
+}
+
+// `Instant` does not implement `Default`, and the derive must not require it without `default = ...`
+#[derive(garde::Validate)]
+#[garde(context(Instant))]
+struct Session {
+    #[garde(custom(not_expired))]
+    expires_at: u64,
+}
+
+#[derive(Default)]
+struct Config {
+    strict: bool,
+}
+
+fn make_config() -> Arc<Config> {
+    Arc::new(Config { strict: true })
+}
+
+fn check_strict(_: &str, config: &Arc<Config>) -> garde::Result {
+    if config.strict {
+        return Ok(());
+    }
+    Err(garde::Error::new("not strict"))
+}
+
+// `Arc<Config>` implements `Default`, but the factory is used instead
+#[derive(garde::Validate)]
+#[garde(context(Arc<Config>, default = make_config))]
+struct Settings<'a> {
+    #[garde(custom(check_strict))]
+    name: &'a str,
+}
+
+fn main() {
+    let _ = Token { expires_at: 0 }.validate_default();
+    let _ = Settings { name: "a" }.validate_default();
+    let _ = Session { expires_at: 0 };
+}
@@ -4,4 +4,10 @@ struct Test<'a> {
     field: &'a str,
 }
 
+#[derive(garde::Validate)]
+struct Restricted<'a> {
+    #[garde(credit_card(types("visa", "mastercard")))]
+    field: &'a str,
+}
+
 fn main() {}
@@ -0,0 +1,17 @@
+#[derive(garde::AsyncValidate)]
+struct Test<'a> {
+    #[garde(length(min = 1), custom_async(check))]
+    field: &'a str,
+    #[garde(custom_async(check), custom_errors(check_errors))]
+    errors: &'a str,
+}
+
+async fn check(_: &str, _: &()) -> garde::Result {
+    Ok(())
+}
+
+fn check_errors(_: &str, _: &()) -> Result<(), garde::Errors> {
+    Ok(())
+}
+
+fn main() {}
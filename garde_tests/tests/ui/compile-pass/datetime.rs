@@ -0,0 +1,13 @@
+#[derive(garde::Validate)]
+struct Test {
+    #[garde(date)]
+    date: String,
+    #[garde(date(format = "%d.%m.%Y"))]
+    date_format: String,
+    #[garde(datetime)]
+    datetime: String,
+    #[garde(datetime(format = "%Y/%m/%d %H:%M"))]
+    datetime_format: String,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+#[derive(garde::Validate)]
+struct Test {
+    #[garde(length(graphemes, min = 1, max = 20))]
+    field: String,
+    #[garde(length(graphemes, max = 20), length(min = 1))]
+    combined: String,
+}
+
+fn main() {}
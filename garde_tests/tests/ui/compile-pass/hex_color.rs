@@ -0,0 +1,11 @@
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(hex_color)]
+    field: &'a str,
+    #[garde(hex_color(alpha = false))]
+    opaque: &'a str,
+    #[garde(hex_color(alpha = true))]
+    alpha: String,
+}
+
+fn main() {}
@@ -0,0 +1,23 @@
+#[derive(garde::Validate, garde::AsyncValidate)]
+struct Test<'a> {
+    #[garde(inner(length(min = 1), ascii))]
+    optional: Option<&'a str>,
+    #[garde(inner(length(min = 1)), length(max = 8))]
+    list: Vec<String>,
+    #[garde(inner(range(max = 10)))]
+    array: [u8; 4],
+}
+
+#[derive(garde::AsyncValidate)]
+struct AsyncTest<'a> {
+    #[garde(inner(length(min = 1)), custom_async(check))]
+    optional: Option<&'a str>,
+    #[garde(dive)]
+    child: Option<Test<'a>>,
+}
+
+async fn check(_: &Option<&str>, _: &()) -> garde::Result {
+    Ok(())
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(ip(public))]
+    public: &'a str,
+    #[garde(ipv4(private))]
+    private: String,
+    #[garde(ipv6(loopback))]
+    loopback: String,
+}
+
+fn main() {}
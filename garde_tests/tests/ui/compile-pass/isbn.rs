@@ -0,0 +1,9 @@
+#[derive(garde::Validate)]
+struct Test {
+    #[garde(isbn)]
+    field: String,
+    #[garde(isbn(version = 13))]
+    versioned: String,
+}
+
+fn main() {}
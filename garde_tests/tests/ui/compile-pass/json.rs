@@ -0,0 +1,7 @@
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(json)]
+    field: &'a str,
+}
+
+fn main() {}
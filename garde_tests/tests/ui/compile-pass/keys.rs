@@ -0,0 +1,7 @@
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(keys(length(min = 1), ascii))]
+    field: std::collections::HashMap<&'a str, &'a str>,
+}
+
+fn main() {}
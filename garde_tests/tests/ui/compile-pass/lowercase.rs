@@ -0,0 +1,7 @@
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(lowercase)]
+    field: &'a str,
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(luhn)]
+    field: &'a str,
+}
+
+fn main() {}
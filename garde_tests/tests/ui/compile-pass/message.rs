@@ -0,0 +1,7 @@
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(length(min = 1), message("field is required"))]
+    field: &'a str,
+}
+
+fn main() {}
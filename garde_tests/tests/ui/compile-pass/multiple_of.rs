@@ -0,0 +1,7 @@
+#[derive(garde::Validate)]
+struct Test {
+    #[garde(multiple_of(12))]
+    field: u64,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(non_control)]
+    field: &'a str,
+    #[garde(non_control(allow_whitespace))]
+    text: String,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(not_contains("admin"))]
+    field: &'a str,
+    #[garde(not_contains("admin", "root", insensitive))]
+    owned: String,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(one_of("a", "b"))]
+    field: &'a str,
+    #[garde(one_of("a", insensitive))]
+    owned: String,
+    #[garde(one_of(1, 2, 3))]
+    number: u64,
+    #[garde(one_of(-1, 1))]
+    signed: i8,
+}
+
+fn main() {}
@@ -4,4 +4,17 @@ struct Test<'a> {
     field: &'a str,
 }
 
+static REGEX: once_cell::sync::Lazy<regex::Regex> =
+    once_cell::sync::Lazy::new(|| regex::Regex::new(r"a|b").unwrap());
+
+fn regex() -> &'static regex::Regex {
+    &REGEX
+}
+
+#[derive(garde::Validate)]
+struct Func<'a> {
+    #[garde(pattern(regex))]
+    field: &'a str,
+}
+
 fn main() {}
@@ -2,6 +2,8 @@
 struct Test<'a> {
     #[garde(phone_number)]
     field: &'a str,
+    #[garde(phone_number(region = "FR"))]
+    french: &'a str,
 }
 
 fn main() {}
@@ -0,0 +1,11 @@
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(port)]
+    field: &'a str,
+    #[garde(port(privileged = false))]
+    number: u16,
+    #[garde(port(privileged = true))]
+    owned: String,
+}
+
+fn main() {}
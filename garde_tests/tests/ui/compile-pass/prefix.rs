@@ -2,6 +2,8 @@
 struct Test<'a> {
     #[garde(prefix("test"))]
     field: &'a str,
+    #[garde(prefix("tel:", "sms:"))]
+    multiple: &'a str,
 }
 
 fn main() {}
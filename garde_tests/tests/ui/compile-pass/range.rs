@@ -4,4 +4,10 @@ struct Test {
     field: u64,
 }
 
+#[derive(garde::Validate)]
+struct Exclusive {
+    #[garde(range(min = 0.0, exclusive_max = 1.0))]
+    field: f64,
+}
+
 fn main() {}
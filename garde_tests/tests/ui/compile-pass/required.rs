@@ -0,0 +1,11 @@
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(required)]
+    field: Option<u64>,
+    #[garde(required(non_empty, message = "name is mandatory"))]
+    name: Option<&'a str>,
+    #[garde(required(non_empty))]
+    items: &'a [u8],
+}
+
+fn main() {}
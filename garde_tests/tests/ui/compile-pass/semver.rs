@@ -0,0 +1,11 @@
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(semver)]
+    field: &'a str,
+    #[garde(semver(version))]
+    version: String,
+    #[garde(semver(req))]
+    req: String,
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(skip_if(|_: &()| true), length(min = 1))]
+    field: &'a str,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(split(",", inner(length(max = 20))))]
+    field: &'a str,
+    #[garde(ascii, split(" ", skip_empty, inner(alphanumeric)))]
+    owned: String,
+}
+
+fn main() {}
@@ -0,0 +1,26 @@
+fn check(value: &Test<'_>, _: &()) -> garde::Result {
+    if value.a == value.b {
+        return Err(garde::Error::new("a and b are equal"));
+    }
+    Ok(())
+}
+
+#[derive(garde::Validate)]
+#[garde(validate = check)]
+struct Test<'a> {
+    #[garde(ascii)]
+    a: &'a str,
+    #[garde(ascii)]
+    b: &'a str,
+}
+
+#[derive(garde::Validate)]
+#[garde(validate = |value: &Enum, _: &()| match value {
+    Enum::A(v) if v.is_empty() => Err(garde::Error::new("empty")),
+    _ => Ok(()),
+})]
+enum Enum {
+    A(#[garde(ascii)] String),
+}
+
+fn main() {}
@@ -2,6 +2,8 @@
 struct Test<'a> {
     #[garde(suffix("test"))]
     field: &'a str,
+    #[garde(suffix(".com", ".org"))]
+    multiple: &'a str,
 }
 
 fn main() {}
@@ -0,0 +1,12 @@
+#[derive(garde::Validate)]
+#[garde(transparent)]
+struct Username(#[garde(length(min = 3))] String);
+
+#[derive(garde::Validate)]
+#[garde(transparent)]
+struct Email {
+    #[garde(contains("@"))]
+    value: String,
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(trimmed)]
+    field: &'a str,
+}
+
+fn main() {}
@@ -4,4 +4,16 @@ struct Test<'a> {
     field: &'a str,
 }
 
+#[derive(garde::Validate)]
+struct Restricted<'a> {
+    #[garde(url(schemes("https")))]
+    field: &'a str,
+}
+
+#[derive(garde::Validate)]
+struct Hosts<'a> {
+    #[garde(url(host("example.com"), schemes("https")))]
+    field: &'a str,
+}
+
 fn main() {}
@@ -0,0 +1,9 @@
+#[derive(garde::Validate)]
+struct Test {
+    #[garde(uuid)]
+    field: String,
+    #[garde(uuid(version = 7))]
+    versioned: String,
+}
+
+fn main() {}
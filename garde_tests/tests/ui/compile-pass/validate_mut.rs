@@ -0,0 +1,28 @@
+#[derive(garde::ValidateMut)]
+#[garde(context(Counter))]
+struct Test<'a> {
+    #[garde(length(min = 1), custom(count))]
+    field: &'a str,
+    #[garde(dive)]
+    inner: Inner,
+}
+
+#[derive(garde::ValidateMut)]
+#[garde(context(Counter))]
+struct Inner {
+    #[garde(custom(peek))]
+    field: u32,
+}
+
+struct Counter(usize);
+
+fn count(_: &str, counter: &mut Counter) -> garde::Result {
+    counter.0 += 1;
+    Ok(())
+}
+
+fn peek(_: &u32, _: &Counter) -> garde::Result {
+    Ok(())
+}
+
+fn main() {}
@@ -0,0 +1,27 @@
+struct Options {
+    gift: bool,
+}
+
+#[derive(garde::Validate)]
+struct Test {
+    #[garde(skip)]
+    enabled: bool,
+    #[garde(length(min = 1), when = enabled)]
+    field: String,
+    #[garde(skip)]
+    options: Options,
+    #[garde(length(min = 1), when = options.gift)]
+    message: String,
+}
+
+#[derive(garde::Validate)]
+enum Enum {
+    Struct {
+        #[garde(skip)]
+        enabled: bool,
+        #[garde(ascii, when = enabled)]
+        field: String,
+    },
+}
+
+fn main() {}